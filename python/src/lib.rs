@@ -0,0 +1,238 @@
+//! Python bindings for `rat-trig-rs`.
+//!
+//! Numbers cross the boundary as `fractions.Fraction` (plain `int`s are
+//! accepted on input) and are computed exactly in Rust. Points and vectors
+//! are `(x, y)` tuples. Numerators and denominators must fit in an `i64`,
+//! both on input and in every intermediate result; anything larger raises
+//! `OverflowError`.
+use num_rational::Rational64;
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
+use pyo3::exceptions::{PyOverflowError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+use rat_trig_rs::error::MathError;
+use rat_trig_rs::geometry::{Point2D, Triangle2D};
+use rat_trig_rs::metric::BilinearForm2D;
+use rat_trig_rs::traits::{AffineOps, MetricOps, RationalTrig};
+use rat_trig_rs::trigonom;
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// An exact rational number, converted from and to `fractions.Fraction`.
+///
+/// Arithmetic is checked: a result that doesn't fit in a `Rational64` is
+/// kept as `None` and propagates through later operations, and [`checked`]
+/// turns it into `OverflowError` before it reaches Python. An overflowed
+/// value compares unequal to (and unordered with) everything.
+///
+/// [`checked`]: Frac::checked
+#[derive(Debug, Clone, Copy)]
+struct Frac(Option<Rational64>);
+
+impl Frac {
+    fn new(numer: i64, denom: i64) -> Self {
+        Frac(Some(Rational64::new(numer, denom)))
+    }
+
+    /// The value, or `OverflowError` if some step computing it overflowed.
+    fn checked(self) -> PyResult<Frac> {
+        match self.0 {
+            Some(_) => Ok(self),
+            None => Err(PyOverflowError::new_err(
+                "intermediate result does not fit in a 64-bit fraction",
+            )),
+        }
+    }
+}
+
+impl From<i32> for Frac {
+    fn from(n: i32) -> Self {
+        Frac(Some(Rational64::from(n as i64)))
+    }
+}
+
+impl PartialEq for Frac {
+    fn eq(&self, other: &Frac) -> bool {
+        matches!((self.0, other.0), (Some(a), Some(b)) if a == b)
+    }
+}
+
+impl PartialOrd for Frac {
+    fn partial_cmp(&self, other: &Frac) -> Option<Ordering> {
+        self.0?.partial_cmp(&other.0?)
+    }
+}
+
+macro_rules! forward_binop {
+    ($trait:ident, $method:ident, $checked:ident) => {
+        impl $trait for Frac {
+            type Output = Frac;
+            #[inline]
+            fn $method(self, rhs: Frac) -> Frac {
+                Frac(self.0.zip(rhs.0).and_then(|(a, b)| a.$checked(&b)))
+            }
+        }
+    };
+}
+
+forward_binop!(Add, add, checked_add);
+forward_binop!(Sub, sub, checked_sub);
+forward_binop!(Mul, mul, checked_mul);
+forward_binop!(Div, div, checked_div);
+
+impl Neg for Frac {
+    type Output = Frac;
+    #[inline]
+    fn neg(self) -> Frac {
+        Frac(self.0.and_then(|a| Some(Rational64::new_raw(a.numer().checked_neg()?, *a.denom()))))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Frac {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        // both `int` and `fractions.Fraction` expose these attributes
+        let numer: i64 = ob.getattr("numerator")?.extract()?;
+        let denom: i64 = ob.getattr("denominator")?.extract()?;
+        Ok(Frac::new(numer, denom))
+    }
+}
+
+impl ToPyObject for Frac {
+    /// Converts a value that has already been [`checked`](Frac::checked).
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        let value = self.0.expect("overflow is reported by `Frac::checked`");
+        let fraction = py
+            .import_bound("fractions")
+            .and_then(|m| m.getattr("Fraction"))
+            .expect("the fractions module is part of the standard library");
+        fraction
+            .call1((*value.numer(), *value.denom()))
+            .expect("Fraction accepts a pair of ints")
+            .unbind()
+    }
+}
+
+impl IntoPy<PyObject> for Frac {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        self.to_object(py)
+    }
+}
+
+fn to_py_err(err: MathError) -> PyErr {
+    PyValueError::new_err(err.to_string())
+}
+
+fn point(p: (Frac, Frac)) -> Point2D<Frac> {
+    Point2D::new(p.0, p.1)
+}
+
+/// quadrance(p1, p2) -> Fraction
+///
+/// The quadrance (squared distance) between two points.
+#[pyfunction]
+fn quadrance(p1: (Frac, Frac), p2: (Frac, Frac)) -> PyResult<Frac> {
+    point(p1).quadrance_to(&point(p2)).checked()
+}
+
+/// spread(u, v) -> Fraction
+///
+/// The spread between two vectors. Raises `ValueError` if either is zero.
+#[pyfunction]
+fn spread(u: (Frac, Frac), v: (Frac, Frac)) -> PyResult<Frac> {
+    BilinearForm2D::blue().spread(&u, &v).map_err(to_py_err)?.checked()
+}
+
+/// cross(u, v) -> Fraction
+///
+/// The cross (one minus the spread) between two vectors. Raises
+/// `ValueError` if either is zero.
+#[pyfunction]
+fn cross(u: (Frac, Frac), v: (Frac, Frac)) -> PyResult<Frac> {
+    BilinearForm2D::blue().cross(&u, &v).map_err(to_py_err)?.checked()
+}
+
+/// archimedes(q1, q2, q3) -> Fraction
+///
+/// The quadrea of a triangle with the given quadrances.
+#[pyfunction]
+fn archimedes(q1: Frac, q2: Frac, q3: Frac) -> PyResult<Frac> {
+    trigonom::archimedes(&q1, &q2, &q3).checked()
+}
+
+/// is_collinear(a, b, c) -> bool
+#[pyfunction]
+fn is_collinear(a: (Frac, Frac), b: (Frac, Frac), c: (Frac, Frac)) -> PyResult<bool> {
+    let twist = point(a).twist(&point(b), &point(c)).checked()?;
+    Ok(twist == Frac::from(0))
+}
+
+/// is_perpendicular(u, v) -> bool
+#[pyfunction]
+fn is_perpendicular(u: (Frac, Frac), v: (Frac, Frac)) -> PyResult<bool> {
+    let dot = BilinearForm2D::blue().dot(&u, &v).checked()?;
+    Ok(dot == Frac::from(0))
+}
+
+/// Triangle(p1, p2, p3)
+///
+/// A triangle given by three `(x, y)` vertices.
+#[pyclass]
+#[derive(Clone, Copy)]
+struct Triangle {
+    inner: Triangle2D<Frac>,
+}
+
+#[pymethods]
+impl Triangle {
+    #[new]
+    fn new(p1: (Frac, Frac), p2: (Frac, Frac), p3: (Frac, Frac)) -> Self {
+        Triangle {
+            inner: Triangle2D::new(point(p1), point(p2), point(p3)),
+        }
+    }
+
+    /// The quadrances of the sides opposite each vertex.
+    fn quadrances(&self) -> PyResult<(Frac, Frac, Frac)> {
+        let (q1, q2, q3) = self.inner.quadrances();
+        Ok((q1.checked()?, q2.checked()?, q3.checked()?))
+    }
+
+    /// The quadrea (16 times the squared area).
+    fn quadrea(&self) -> PyResult<Frac> {
+        self.inner.area().checked()
+    }
+
+    /// The spreads at each vertex. Raises `ValueError` if two vertices
+    /// coincide.
+    fn spreads(&self) -> PyResult<(Frac, Frac, Frac)> {
+        let Triangle2D { p1, p2, p3 } = self.inner;
+        Ok((
+            p1.spread_at(&p2, &p3).map_err(to_py_err)?.checked()?,
+            p2.spread_at(&p3, &p1).map_err(to_py_err)?.checked()?,
+            p3.spread_at(&p1, &p2).map_err(to_py_err)?.checked()?,
+        ))
+    }
+
+    /// Whether the vertices are collinear.
+    fn is_degenerate(&self) -> PyResult<bool> {
+        Ok(self.inner.area().checked()? == Frac::from(0))
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        let Triangle2D { p1, p2, p3 } = self.inner;
+        let vertices = PyTuple::new_bound(py, [p1, p2, p3].map(|p| (p.x, p.y).to_object(py)));
+        Ok(format!("Triangle{}", vertices.repr()?))
+    }
+}
+
+#[pymodule]
+fn rat_trig(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(quadrance, m)?)?;
+    m.add_function(wrap_pyfunction!(spread, m)?)?;
+    m.add_function(wrap_pyfunction!(cross, m)?)?;
+    m.add_function(wrap_pyfunction!(archimedes, m)?)?;
+    m.add_function(wrap_pyfunction!(is_collinear, m)?)?;
+    m.add_function(wrap_pyfunction!(is_perpendicular, m)?)?;
+    m.add_class::<Triangle>()?;
+    Ok(())
+}
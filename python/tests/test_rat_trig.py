@@ -0,0 +1,40 @@
+from fractions import Fraction
+
+import pytest
+import rat_trig
+
+
+def test_scalar_functions():
+    assert rat_trig.quadrance((0, 0), (3, 4)) == 25
+    assert rat_trig.spread((1, 0), (1, 1)) == Fraction(1, 2)
+    assert rat_trig.cross((1, 0), (1, 2)) == Fraction(1, 5)
+    assert rat_trig.archimedes(Fraction(1, 2), Fraction(1, 4), Fraction(1, 6)) == Fraction(23, 144)
+    assert isinstance(rat_trig.quadrance((0, 0), (1, 1)), Fraction)
+
+
+def test_predicates():
+    assert rat_trig.is_collinear((0, 0), (1, 1), (Fraction(5, 2), Fraction(5, 2)))
+    assert not rat_trig.is_collinear((0, 0), (1, 1), (1, 0))
+    assert rat_trig.is_perpendicular((1, 2), (-2, 1))
+
+
+def test_triangle():
+    tri = rat_trig.Triangle((0, 0), (4, 0), (0, 3))
+    assert tri.quadrances() == (25, 9, 16)
+    assert tri.quadrea() == 576
+    assert tri.spreads() == (1, Fraction(9, 25), Fraction(16, 25))
+    assert not tri.is_degenerate()
+    assert rat_trig.Triangle((0, 0), (1, 1), (2, 2)).is_degenerate()
+    with pytest.raises(ValueError):
+        rat_trig.Triangle((0, 0), (0, 0), (1, 1)).spreads()
+
+
+def test_intermediate_overflow():
+    # the inputs fit in an i64 but their squares don't
+    with pytest.raises(OverflowError):
+        rat_trig.quadrance((2**32, 0), (0, 0))
+    with pytest.raises(OverflowError):
+        rat_trig.is_perpendicular((2**32, 1), (2**32, 0))
+    with pytest.raises(OverflowError):
+        rat_trig.Triangle((0, 0), (2**32, 0), (0, 1)).quadrances()
+    assert rat_trig.quadrance((2**31 - 1, 0), (0, 0)) == (2**31 - 1) ** 2
@@ -0,0 +1,510 @@
+//! One-pass triangle analysis.
+//!
+//! [`analyze`] computes everything rational trigonometry says about a
+//! triangle (quadrances, spreads, quadrea, orientation, classification) in
+//! one go and checks the main laws against each other. Over the rationals
+//! the law residuals are exactly zero; over floats they measure the
+//! accumulated rounding error.
+//!
+//! [`are_congruent`] and [`are_similar`] compare triangles by their sorted
+//! quadrances alone, which makes them exact keys for deduplication.
+use crate::geometry::{sort3, Point2D, Triangle2D, Winding};
+use crate::logging::Loggable;
+use crate::traits::AffineOps;
+use crate::trigonom::{archimedes, triple_spread};
+use core::convert::From;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// The shape of a triangle, decided by comparing its quadrances.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TriangleClass {
+    /// The vertices are collinear.
+    Degenerate,
+    /// Every spread is less than one: each quadrance is less than the sum of
+    /// the other two.
+    Acute,
+    /// One spread is one: Pythagoras' theorem holds for one vertex.
+    Right,
+    /// One quadrance exceeds the sum of the other two.
+    Obtuse,
+}
+
+impl TriangleClass {
+    /// Classifies a nondegenerate triangle by its quadrances.
+    pub(crate) fn of_proper<T>(q1: &T, q2: &T, q3: &T) -> Self
+    where
+        T: Copy + Add<Output = T> + PartialOrd,
+    {
+        let (q1, q2, q3) = (*q1, *q2, *q3);
+        if q1 == q2 + q3 || q2 == q1 + q3 || q3 == q1 + q2 {
+            TriangleClass::Right
+        } else if q1 > q2 + q3 || q2 > q1 + q3 || q3 > q1 + q2 {
+            TriangleClass::Obtuse
+        } else {
+            TriangleClass::Acute
+        }
+    }
+}
+
+/// How far the computed values are from satisfying the laws of rational
+/// trigonometry. Each residual is zero when the law holds exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LawResiduals<T> {
+    /// Archimedes' formula against the quadrea from the twist,
+    /// `A(q₁, q₂, q₃) − 4·twist²`.
+    pub archimedes: T,
+    /// The spread law, `(s₁·q₂ − s₂·q₁, s₂·q₃ − s₃·q₂)`.
+    pub spread_law: Option<(T, T)>,
+    /// The cross law at `p3`, `(q₁ + q₂ − q₃)² − 4·q₁·q₂·(1 − s₃)`.
+    pub cross_law: Option<T>,
+    /// The triple spread formula,
+    /// `(s₁ + s₂ + s₃)² − 2·(s₁² + s₂² + s₃²) − 4·s₁·s₂·s₃`.
+    pub triple_spread: Option<T>,
+}
+
+impl<T> LawResiduals<T>
+where
+    T: PartialEq + From<i32>,
+{
+    /// Checks whether every available residual is exactly zero.
+    pub fn all_zero(&self) -> bool {
+        let zero = || T::from(0);
+        self.archimedes == zero()
+            && self
+                .spread_law
+                .iter()
+                .all(|(a, b)| *a == zero() && *b == zero())
+            && self.cross_law.iter().all(|c| *c == zero())
+            && self.triple_spread.iter().all(|t| *t == zero())
+    }
+}
+
+/// The result of [`analyze`].
+///
+/// Quadrances and spreads are indexed by the opposite vertex: `q₁` is the
+/// quadrance of the side `p2p3` and `s₁` is the spread at `p1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TriangleReport<T> {
+    /// The analysed triangle.
+    pub triangle: Triangle2D<T>,
+    /// `(q₁, q₂, q₃)`.
+    pub quadrances: (T, T, T),
+    /// `(s₁, s₂, s₃)`, or `None` if two vertices coincide.
+    pub spreads: Option<(T, T, T)>,
+    /// 16 times the squared area, by Archimedes' formula.
+    pub quadrea: T,
+    /// Twice the signed area, positive for counterclockwise vertices.
+    pub twist: T,
+    /// The orientation, or `None` if the triangle is degenerate.
+    pub winding: Option<Winding>,
+    /// Acute, right, obtuse or degenerate, from the quadrances.
+    pub class: TriangleClass,
+    /// How far the computed values are from the laws, see [`LawResiduals`].
+    pub residuals: LawResiduals<T>,
+    /// At least two quadrances are equal.
+    pub is_isosceles: bool,
+    /// All three quadrances are equal and nonzero.
+    pub is_equilateral: bool,
+}
+
+impl<T> TriangleReport<T> {
+    /// Checks whether the triangle has a right spread.
+    #[inline]
+    pub fn is_right(&self) -> bool {
+        self.class == TriangleClass::Right
+    }
+
+    /// Checks whether the vertices are collinear.
+    #[inline]
+    pub fn is_degenerate(&self) -> bool {
+        self.class == TriangleClass::Degenerate
+    }
+}
+
+/// The function `analyze` computes a [`TriangleReport`] for the triangle
+/// `p1, p2, p3`.
+///
+/// Arguments:
+///
+/// * `p1`, `p2`, `p3`: The vertices of the triangle.
+///
+/// Returns:
+///
+/// The quadrances, spreads, quadrea, twist, orientation, classification and
+/// law residuals of the triangle, plus its special-triangle flags. Spreads
+/// use division, so use a field such as the rationals.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::analysis::{analyze, TriangleClass};
+/// use rat_trig_rs::geometry::{Point2D, Winding};
+/// let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+/// let report = analyze(&p(0, 0), &p(4, 0), &p(0, 3));
+/// let r = |n: i32, d: i32| Rational32::new(n, d);
+/// assert_eq!(report.quadrances, (r(25, 1), r(9, 1), r(16, 1)));
+/// assert_eq!(report.spreads, Some((r(1, 1), r(9, 25), r(16, 25))));
+/// assert_eq!(report.quadrea, r(576, 1));
+/// assert_eq!(report.winding, Some(Winding::CounterClockwise));
+/// assert_eq!(report.class, TriangleClass::Right);
+/// assert!(report.residuals.all_zero());
+/// ```
+pub fn analyze<T>(p1: &Point2D<T>, p2: &Point2D<T>, p3: &Point2D<T>) -> TriangleReport<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>,
+{
+    let (zero, one, four) = (T::from(0), T::from(1), T::from(4));
+    let triangle = Triangle2D::new(*p1, *p2, *p3);
+    let (q1, q2, q3) = triangle.quadrances();
+    let quadrea = archimedes(&q1, &q2, &q3);
+    let twist = p1.twist(p2, p3);
+    let twist_sq = twist * twist;
+
+    let winding = if twist > zero {
+        Some(Winding::CounterClockwise)
+    } else if twist < zero {
+        Some(Winding::Clockwise)
+    } else {
+        None
+    };
+    let class = if winding.is_none() {
+        TriangleClass::Degenerate
+    } else {
+        TriangleClass::of_proper(&q1, &q2, &q3)
+    };
+
+    // s₁ = twist² / (q₂·q₃), and cyclically
+    let spreads = if q1 == zero || q2 == zero || q3 == zero {
+        None
+    } else {
+        Some((
+            twist_sq / (q2 * q3),
+            twist_sq / (q1 * q3),
+            twist_sq / (q1 * q2),
+        ))
+    };
+    let residuals = LawResiduals {
+        archimedes: quadrea - four * twist_sq,
+        spread_law: spreads.map(|(s1, s2, s3)| (s1 * q2 - s2 * q1, s2 * q3 - s3 * q2)),
+        cross_law: spreads.map(|(_, _, s3)| {
+            let d = q1 + q2 - q3;
+            d * d - four * q1 * q2 * (one - s3)
+        }),
+        triple_spread: spreads.map(|(s1, s2, s3)| triple_spread(&s1, &s2, &s3)),
+    };
+
+    TriangleReport {
+        triangle,
+        quadrances: (q1, q2, q3),
+        spreads,
+        quadrea,
+        twist,
+        winding,
+        class,
+        residuals,
+        is_isosceles: q1 == q2 || q2 == q3 || q1 == q3,
+        is_equilateral: q1 == q2 && q2 == q3 && q1 != zero,
+    }
+}
+
+/// The function `are_congruent` checks whether two triangles are congruent.
+///
+/// By SSS, two triangles are congruent exactly when their quadrances agree
+/// up to order, so the test compares the sorted quadrance triples and needs
+/// no tolerance over integers and rationals. Mirror images count as
+/// congruent.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::analysis::are_congruent;
+/// use rat_trig_rs::geometry::{Point2D, Triangle2D};
+/// let p = Point2D::new;
+/// let tri = Triangle2D::new(p(0, 0), p(4, 0), p(0, 3));
+/// // rotated by a quarter turn, translated and relabelled
+/// let moved = Triangle2D::new(p(10, 14), p(7, 10), p(10, 10));
+/// assert!(are_congruent(&tri, &moved));
+/// assert!(!are_congruent(&tri, &Triangle2D::new(p(0, 0), p(3, 0), p(0, 5))));
+/// ```
+pub fn are_congruent<T>(tri_a: &Triangle2D<T>, tri_b: &Triangle2D<T>) -> bool
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    tri_a.canonical_quadrances() == tri_b.canonical_quadrances()
+}
+
+/// The function `are_similar_triangles` checks whether two quadrance
+/// triples belong to similar triangles.
+///
+/// The sorted triples must be proportional; the ratios are compared by
+/// cross-multiplying, so no division is needed. A triangle collapsed to a
+/// point is only similar to another such.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::analysis::are_similar_triangles;
+/// assert!(are_similar_triangles((25, 9, 16), (36, 100, 64)));
+/// assert!(!are_similar_triangles((25, 9, 16), (16, 16, 32)));
+/// ```
+pub fn are_similar_triangles<T>(qs_a: (T, T, T), qs_b: (T, T, T)) -> bool
+where
+    T: Copy + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    let zero = T::from(0);
+    let (a, b) = (sort3(qs_a), sort3(qs_b));
+    (a[2] == zero) == (b[2] == zero) && a[0] * b[2] == a[2] * b[0] && a[1] * b[2] == a[2] * b[1]
+}
+
+/// The function `similarity_ratio` calculates the factor by which the
+/// quadrances of similar triangles differ.
+///
+/// Returns:
+///
+/// The ratio `k` with `qs_b = k·qs_a` up to order, which is the square of the
+/// linear scale factor, or `None` if the triangles are not similar or are
+/// collapsed to points.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::analysis::similarity_ratio;
+/// let r = Rational32::from;
+/// let qs = (r(25), r(9), r(16));
+/// assert_eq!(similarity_ratio(qs, (r(36), r(100), r(64))), Some(Rational32::new(4, 1)));
+/// assert_eq!(similarity_ratio((r(36), r(100), r(64)), qs), Some(Rational32::new(1, 4)));
+/// assert_eq!(similarity_ratio(qs, (r(1), r(1), r(2))), None);
+/// ```
+pub fn similarity_ratio<T>(qs_a: (T, T, T), qs_b: (T, T, T)) -> Option<T>
+where
+    T: Copy + Mul<Output = T> + Div<Output = T> + PartialOrd + From<i32>,
+{
+    let (a, b) = (sort3(qs_a)[2], sort3(qs_b)[2]);
+    (a != T::from(0) && are_similar_triangles(qs_a, qs_b)).then(|| b / a)
+}
+
+/// The function `are_similar` checks whether two triangles are similar, by
+/// [`are_similar_triangles`] on their quadrances.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::analysis::are_similar;
+/// use rat_trig_rs::geometry::{Point2D, Triangle2D};
+/// let p = Point2D::new;
+/// let tri = Triangle2D::new(p(0, 0), p(4, 0), p(0, 3));
+/// assert!(are_similar(&tri, &Triangle2D::new(p(1, 1), p(1, 9), p(7, 1))));
+/// assert!(!are_similar(&tri, &Triangle2D::new(p(0, 0), p(4, 0), p(0, 4))));
+/// ```
+pub fn are_similar<T>(tri_a: &Triangle2D<T>, tri_b: &Triangle2D<T>) -> bool
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    are_similar_triangles(tri_a.quadrances(), tri_b.quadrances())
+}
+
+/// The function `are_congruent_points` checks whether an isometry maps
+/// each point of `a` to the point of `b` at the same index.
+///
+/// A map between finite point sets that preserves all pairwise quadrances
+/// extends to an isometry of the plane, so comparing the `n(n − 1)/2`
+/// quadrances decides congruence exactly. The correspondence is fixed by
+/// the order of the points; finding one is left to the caller.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::analysis::are_congruent_points;
+/// use rat_trig_rs::geometry::Point2D;
+/// let square = [(0, 0), (2, 0), (2, 2), (0, 2)].map(|(x, y)| Point2D::new(x, y));
+/// let diamond = [(0, 0), (1, 1), (0, 2), (-1, 1)].map(|(x, y)| Point2D::new(x, y));
+/// let turned = [(5, 5), (5, 7), (3, 7), (3, 5)].map(|(x, y)| Point2D::new(x, y));
+/// assert!(are_congruent_points(&square, &turned));
+/// assert!(!are_congruent_points(&square, &diamond));
+/// assert!(!are_congruent_points(&square, &turned[..3]));
+/// ```
+pub fn are_congruent_points<T>(a: &[Point2D<T>], b: &[Point2D<T>]) -> bool
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq,
+{
+    let quad = |p: &Point2D<T>, q: &Point2D<T>| {
+        let (dx, dy) = (q.x - p.x, q.y - p.y);
+        dx * dx + dy * dy
+    };
+    a.len() == b.len()
+        && (0..a.len()).all(|i| (i + 1..a.len()).all(|j| quad(&a[i], &a[j]) == quad(&b[i], &b[j])))
+}
+
+/// The function `is_on_euler_line` checks whether `point` lies on the Euler
+/// line of `triangle`; see [`Triangle2D::euler_line`].
+///
+/// Over the rationals this demonstrates exactly that the circumcenter,
+/// centroid and orthocenter are collinear. It is `false` whenever the Euler
+/// line is undefined.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::analysis::is_on_euler_line;
+/// use rat_trig_rs::geometry::{Point2D, Triangle2D};
+/// let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+/// let tri = Triangle2D::new(p(0, 0), p(6, 0), p(1, 4));
+/// assert!(is_on_euler_line(&tri.centroid(), &tri));
+/// // the orthocenter
+/// assert!(is_on_euler_line(&Point2D::new(Rational32::from(1), Rational32::new(5, 4)), &tri));
+/// assert!(!is_on_euler_line(&p(0, 0), &tri));
+/// ```
+pub fn is_on_euler_line<T>(point: &Point2D<T>, triangle: &Triangle2D<T>) -> bool
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>
+        + Loggable,
+{
+    triangle
+        .euler_line()
+        .is_ok_and(|l| l.a * point.x + l.b * point.y + l.c == T::from(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+
+    type Q = Ratio<i32>;
+
+    fn p(x: i32, y: i32) -> Point2D<Q> {
+        Point2D::new(Q::from(x), Q::from(y))
+    }
+
+    #[test]
+    fn test_classification() {
+        let acute = analyze(&p(0, 0), &p(4, 0), &p(1, 3));
+        assert_eq!(acute.class, TriangleClass::Acute);
+        assert!(acute.residuals.all_zero());
+
+        let obtuse = analyze(&p(0, 0), &p(4, 0), &p(5, 1));
+        assert_eq!(obtuse.class, TriangleClass::Obtuse);
+        assert_eq!(obtuse.winding, Some(Winding::CounterClockwise));
+        assert!(obtuse.residuals.all_zero());
+
+        let right = analyze(&p(0, 0), &p(0, 3), &p(4, 0));
+        assert!(right.is_right());
+        assert_eq!(right.winding, Some(Winding::Clockwise));
+        assert_eq!(right.twist, Q::from(-12));
+
+        let isosceles = analyze(&p(0, 0), &p(2, 0), &p(1, 5));
+        assert!(isosceles.is_isosceles);
+        assert!(!isosceles.is_equilateral);
+        assert!(isosceles.residuals.all_zero());
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let flat = analyze(&p(0, 0), &p(1, 1), &p(3, 3));
+        assert!(flat.is_degenerate());
+        assert_eq!(flat.winding, None);
+        assert_eq!(flat.quadrea, Q::from(0));
+        assert_eq!(flat.spreads, Some((Q::from(0), Q::from(0), Q::from(0))));
+        assert!(flat.residuals.all_zero());
+
+        let point = analyze(&p(1, 1), &p(1, 1), &p(1, 1));
+        assert_eq!(point.spreads, None);
+        assert_eq!(point.residuals.cross_law, None);
+        assert!(point.is_isosceles);
+        assert!(!point.is_equilateral);
+    }
+
+    #[test]
+    fn test_float_residuals() {
+        let h = 3.0_f64.sqrt();
+        let report = analyze(
+            &Point2D::new(0.0, 0.0),
+            &Point2D::new(2.0, 0.0),
+            &Point2D::new(1.0, h),
+        );
+        let (s1, s2, s3) = report.spreads.unwrap();
+        for s in [s1, s2, s3] {
+            assert!((s - 0.75).abs() < 1e-12);
+        }
+        assert!(report.residuals.archimedes.abs() < 1e-9);
+        assert!(report.residuals.triple_spread.unwrap().abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_congruence_and_similarity() {
+        let tri = Triangle2D::new(p(0, 0), p(4, 0), p(1, 3));
+        let mirrored = Triangle2D::new(p(0, 0), p(-4, 0), p(-1, 3));
+        let doubled = Triangle2D::new(p(0, 0), p(8, 0), p(2, 6));
+        assert!(are_congruent(&tri, &mirrored));
+        assert!(!are_congruent(&tri, &doubled));
+        assert!(are_similar(&tri, &doubled));
+        assert!(are_similar(&mirrored, &tri));
+        assert!(!are_similar(
+            &tri,
+            &Triangle2D::new(p(0, 0), p(4, 0), p(2, 3))
+        ));
+        // collinear triangles are compared like any others
+        let flat = Triangle2D::new(p(0, 0), p(1, 0), p(3, 0));
+        assert!(are_similar(
+            &flat,
+            &Triangle2D::new(p(0, 0), p(0, 2), p(0, 6))
+        ));
+        let point = Triangle2D::new(p(1, 1), p(1, 1), p(1, 1));
+        assert!(are_similar(
+            &point,
+            &Triangle2D::new(p(2, 2), p(2, 2), p(2, 2))
+        ));
+        assert!(!are_similar(&point, &flat));
+        assert!(!are_similar(&flat, &point));
+    }
+
+    #[test]
+    fn test_are_congruent_points() {
+        let a = [p(0, 0), p(3, 0), p(3, 4), p(1, 1)];
+        // a quarter turn about the origin followed by a shift
+        let b = a.map(|q| Point2D::new(Q::from(7) - q.y, q.x + Q::from(2)));
+        assert!(are_congruent_points(&a, &b));
+        let mut swapped = b;
+        swapped.swap(0, 1);
+        assert!(!are_congruent_points(&a, &swapped));
+        assert!(are_congruent_points::<Q>(&[], &[]));
+    }
+
+    #[test]
+    fn test_is_on_euler_line() {
+        let tri = Triangle2D::new(p(-2, 1), p(5, 3), p(0, 7));
+        let o = tri.circumcenter().unwrap();
+        assert!(is_on_euler_line(&o, &tri));
+        assert!(is_on_euler_line(&o.midpoint(&tri.centroid()), &tri));
+        assert!(!is_on_euler_line(&tri.p1, &tri));
+        let flat = Triangle2D::new(p(0, 0), p(1, 1), p(2, 2));
+        assert!(!is_on_euler_line(&p(1, 1), &flat));
+    }
+
+    #[test]
+    fn test_similarity_ratio() {
+        let qs = (Q::from(2), Q::from(5), Q::from(5));
+        let scaled = (Q::new(45, 2), Q::from(9), Q::new(45, 2));
+        assert!(are_similar_triangles(qs, scaled));
+        assert_eq!(similarity_ratio(qs, scaled), Some(Q::new(9, 2)));
+        let zero = (Q::from(0), Q::from(0), Q::from(0));
+        assert!(are_similar_triangles(zero, zero));
+        assert_eq!(similarity_ratio(zero, zero), None);
+        assert_eq!(similarity_ratio(zero, qs), None);
+        assert_eq!(similarity_ratio(qs, zero), None);
+    }
+}
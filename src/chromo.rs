@@ -0,0 +1,256 @@
+//! Chromogeometry: the blue, red and green geometries of the plane.
+//!
+//! Wildberger's three planar metrics are the Euclidean (blue) form
+//! `x² + y²`, the Minkowski (red) form `x² − y²` and the green form `2xy`.
+//! Each has its own quadrance and spread, computed here through the forms
+//! of [`BilinearForm2D`]; the functions take vectors as tuples, like those
+//! of [`trigonom`](crate::trigonom). The three geometries are tied together
+//! by the identities checked by [`satisfies_chromo_pythagoras`] and
+//! [`chromo_spread_sum`].
+use crate::error::MathError;
+use crate::logging::{degenerate, targets, Loggable};
+use crate::metric::BilinearForm2D;
+use core::convert::From;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// The function `quadrance_blue` calculates the blue quadrance `x² + y²`.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::chromo::quadrance_blue;
+/// assert_eq!(quadrance_blue(&(3, 4)), 25);
+/// ```
+#[inline]
+pub fn quadrance_blue<T>(v: &(T, T)) -> T
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    BilinearForm2D::blue().quadrance(v)
+}
+
+/// The function `quadrance_red` calculates the red quadrance `x² − y²`.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::chromo::quadrance_red;
+/// assert_eq!(quadrance_red(&(3, 4)), -7);
+/// ```
+#[inline]
+pub fn quadrance_red<T>(v: &(T, T)) -> T
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    BilinearForm2D::red().quadrance(v)
+}
+
+/// The function `quadrance_green` calculates the green quadrance `2xy`.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::chromo::quadrance_green;
+/// assert_eq!(quadrance_green(&(3, 4)), 24);
+/// ```
+#[inline]
+pub fn quadrance_green<T>(v: &(T, T)) -> T
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    BilinearForm2D::green().quadrance(v)
+}
+
+/// The function `spread_blue` calculates the blue spread between two
+/// vectors; see [`BilinearForm2D::spread`].
+///
+/// Returns `MathError::NullVector` if either vector is zero.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::chromo::spread_blue;
+/// let r = Rational32::from;
+/// assert_eq!(spread_blue(&(r(1), r(2)), &(r(3), r(1))), Ok(Rational32::new(1, 2)));
+/// ```
+#[inline]
+pub fn spread_blue<T>(u: &(T, T), v: &(T, T)) -> Result<T, MathError>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>
+        + Loggable,
+{
+    BilinearForm2D::blue().spread(u, v)
+}
+
+/// The function `spread_red` calculates the red spread between two vectors.
+///
+/// Returns `MathError::NullVector` if either vector is red-null, i.e. lies
+/// along `y = ±x`.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::chromo::spread_red;
+/// let r = Rational32::from;
+/// assert_eq!(spread_red(&(r(1), r(2)), &(r(3), r(1))), Ok(Rational32::new(25, 24)));
+/// ```
+#[inline]
+pub fn spread_red<T>(u: &(T, T), v: &(T, T)) -> Result<T, MathError>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>
+        + Loggable,
+{
+    BilinearForm2D::red().spread(u, v)
+}
+
+/// The function `spread_green` calculates the green spread between two
+/// vectors.
+///
+/// Returns `MathError::NullVector` if either vector is green-null, i.e.
+/// lies along an axis.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::chromo::spread_green;
+/// let r = Rational32::from;
+/// assert_eq!(spread_green(&(r(1), r(2)), &(r(3), r(1))), Ok(Rational32::new(-25, 24)));
+/// ```
+#[inline]
+pub fn spread_green<T>(u: &(T, T), v: &(T, T)) -> Result<T, MathError>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>
+        + Loggable,
+{
+    BilinearForm2D::green().spread(u, v)
+}
+
+/// The function `satisfies_chromo_pythagoras` checks the identity
+/// `Q_b² = Q_r² + Q_g²` relating the three quadrances of a vector.
+///
+/// Returns:
+///
+/// Whether the identity holds exactly, which it does for every vector over
+/// an exact type; it documents the relation and guards custom numeric
+/// types.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::chromo::satisfies_chromo_pythagoras;
+/// assert!(satisfies_chromo_pythagoras(&(2, 7)));
+/// ```
+#[inline]
+pub fn satisfies_chromo_pythagoras<T>(v: &(T, T)) -> bool
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    let (b, r, g) = (quadrance_blue(v), quadrance_red(v), quadrance_green(v));
+    b * b == r * r + g * g
+}
+
+/// The function `chromo_spread_sum` calculates `1/s_b + 1/s_r + 1/s_g` for
+/// the three spreads between two vectors, which by Wildberger's theorem
+/// always equals 2.
+///
+/// Arguments:
+///
+/// * `u`, `v`: The two vectors.
+///
+/// Returns:
+///
+/// The sum, or `MathError::NullVector` if either vector is null in some
+/// colour, and `MathError::DivisionByZero` if the vectors are parallel (all
+/// three spreads vanish).
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::chromo::chromo_spread_sum;
+/// let r = Rational32::from;
+/// assert_eq!(chromo_spread_sum(&(r(1), r(2)), &(r(3), r(1))), Ok(r(2)));
+/// ```
+pub fn chromo_spread_sum<T>(u: &(T, T), v: &(T, T)) -> Result<T, MathError>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>
+        + Loggable,
+{
+    let spreads = [spread_blue(u, v)?, spread_red(u, v)?, spread_green(u, v)?];
+    let (zero, one) = (T::from(0), T::from(1));
+    if spreads.contains(&zero) {
+        return Err(degenerate(
+            targets::METRIC,
+            "chromo_spread_sum",
+            MathError::DivisionByZero,
+            &(u, v),
+        ));
+    }
+    Ok(spreads.iter().fold(zero, |acc, &s| acc + one / s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+
+    type Q = Ratio<i32>;
+
+    #[test]
+    fn test_quadrances() {
+        for v in [(1, 0), (3, 4), (-2, 5), (7, 7), (0, 0)] {
+            assert!(satisfies_chromo_pythagoras(&v));
+        }
+        assert_eq!(quadrance_blue(&(-2, 5)), 29);
+        assert_eq!(quadrance_red(&(-2, 5)), -21);
+        assert_eq!(quadrance_green(&(-2, 5)), -20);
+    }
+
+    #[test]
+    fn test_spreads() {
+        let (u, v) = ((Q::from(1), Q::from(2)), (Q::from(3), Q::from(1)));
+        assert_eq!(spread_blue(&u, &v), Ok(Q::new(1, 2)));
+        assert_eq!(spread_red(&u, &v), Ok(Q::new(25, 24)));
+        assert_eq!(spread_green(&u, &v), Ok(Q::new(-25, 24)));
+        assert_eq!(chromo_spread_sum(&u, &v), Ok(Q::from(2)));
+        let w = (Q::new(-5, 3), Q::new(7, 2));
+        assert_eq!(chromo_spread_sum(&u, &w), Ok(Q::from(2)));
+        // null directions and parallel vectors
+        let diagonal = (Q::from(1), Q::from(1));
+        assert_eq!(spread_red(&u, &diagonal), Err(MathError::NullVector));
+        let axis = (Q::from(0), Q::from(2));
+        assert_eq!(spread_green(&axis, &v), Err(MathError::NullVector));
+        let twice = (Q::from(2), Q::from(4));
+        assert_eq!(
+            chromo_spread_sum(&u, &twice),
+            Err(MathError::DivisionByZero)
+        );
+    }
+}
@@ -0,0 +1,1049 @@
+//! Circles given by a center and a quadrance.
+//!
+//! A circle with center `c` and quadrance `q` is the set of points `p` with
+//! `Q(p, c) = q`. The predicates here decide where a point lies by comparing
+//! quadrances, so they are exact over the rationals and need no square roots.
+use crate::error::MathError;
+use crate::geometry::{Line2D, Point2D, Triangle2D};
+use crate::logging::{degenerate, targets, Loggable};
+use crate::traits::AffineOps;
+use crate::trigonom::{archimedes, quadrance, safe_line_intersection};
+use core::cmp::Ordering;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// The position of a point relative to a circle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CirclePosition {
+    Inside,
+    On,
+    Outside,
+}
+
+/// A circle in the plane, given by its center and quadrance (the square of
+/// its radius).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[repr(C)]
+pub struct Circle2D<T> {
+    pub center: Point2D<T>,
+    pub quadrance: T,
+}
+
+impl<T> Circle2D<T> {
+    /// Creates a new circle from its center and quadrance.
+    #[inline]
+    pub const fn new(center: Point2D<T>, quadrance: T) -> Self {
+        Circle2D { center, quadrance }
+    }
+}
+
+impl<T> Circle2D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Creates the circle with the given center passing through `point`.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::circle::Circle2D;
+    /// use rat_trig_rs::geometry::Point2D;
+    /// let c = Circle2D::from_center_and_point(Point2D::new(1, 1), &Point2D::new(4, 5));
+    /// assert_eq!(c.quadrance, 25);
+    /// ```
+    #[inline]
+    pub fn from_center_and_point(center: Point2D<T>, point: &Point2D<T>) -> Self {
+        let q = quadrance(&(point.x - center.x, point.y - center.y));
+        Circle2D::new(center, q)
+    }
+
+    /// The power of `point` with respect to the circle; see
+    /// [`power_of_point`].
+    #[inline]
+    pub fn power(&self, point: &Point2D<T>) -> T {
+        power_of_point(point, &self.center, self.quadrance)
+    }
+}
+
+impl<T> Circle2D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd,
+{
+    /// Locates `point` relative to the circle; see [`point_vs_circle`].
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::circle::{Circle2D, CirclePosition};
+    /// use rat_trig_rs::geometry::Point2D;
+    /// let c = Circle2D::new(Point2D::new(0, 0), 25);
+    /// assert_eq!(c.point_position(&Point2D::new(3, 4)), CirclePosition::On);
+    /// assert_eq!(c.point_position(&Point2D::new(3, 3)), CirclePosition::Inside);
+    /// assert_eq!(c.point_position(&Point2D::new(4, 4)), CirclePosition::Outside);
+    /// ```
+    #[inline]
+    pub fn point_position(&self, point: &Point2D<T>) -> CirclePosition {
+        point_vs_circle(point, &self.center, self.quadrance)
+    }
+
+    /// Whether `point` lies in the closed disc bounded by the circle, i.e.
+    /// inside or on it.
+    #[inline]
+    pub fn contains(&self, point: &Point2D<T>) -> bool {
+        self.point_position(point) != CirclePosition::Outside
+    }
+}
+
+impl<T> Circle2D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// Creates the circle through three points, centered at the
+    /// [`circumcenter`](Triangle2D::circumcenter) of their triangle.
+    ///
+    /// Returns `MathError::DegenerateTriangle` if the points are collinear
+    /// (including coincident).
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::circle::Circle2D;
+    /// use rat_trig_rs::error::MathError;
+    /// use rat_trig_rs::geometry::Point2D;
+    /// let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+    /// let c = Circle2D::circumscribing(&p(0, 0), &p(4, 0), &p(1, 3));
+    /// assert_eq!(c, Ok(Circle2D::new(p(2, 1), Rational32::from(5))));
+    /// let flat = Circle2D::circumscribing(&p(0, 0), &p(1, 1), &p(3, 3));
+    /// assert_eq!(flat, Err(MathError::DegenerateTriangle));
+    /// ```
+    pub fn circumscribing(
+        p1: &Point2D<T>,
+        p2: &Point2D<T>,
+        p3: &Point2D<T>,
+    ) -> Result<Self, MathError>
+    where
+        T: Loggable,
+    {
+        let center = Triangle2D::new(*p1, *p2, *p3).circumcenter()?;
+        Ok(Circle2D::from_center_and_point(center, p1))
+    }
+}
+
+/// The function `circle_line_intersection_count` counts the points where a
+/// line meets a circle, from the sign of the discriminant
+/// `q·(a² + b²) − (a·h + b·k + c)²` for the line `a·x + b·y + c = 0` and
+/// center `(h, k)`; no square roots are taken.
+///
+/// Arguments:
+///
+/// * `circle`: The circle.
+/// * `line`: The line.
+///
+/// Returns:
+///
+/// `0`, `1` (the line is tangent) or `2`, or `MathError::NullLine` if
+/// `a² + b² = 0`.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::circle::{circle_line_intersection_count, Circle2D};
+/// use rat_trig_rs::geometry::{Line2D, Point2D};
+/// let c = Circle2D::new(Point2D::new(0, 0), 25);
+/// assert_eq!(circle_line_intersection_count(&c, &Line2D::new(0, 1, -3)), Ok(2));
+/// assert_eq!(circle_line_intersection_count(&c, &Line2D::new(3, 4, 25)), Ok(1));
+/// assert_eq!(circle_line_intersection_count(&c, &Line2D::new(1, 1, -8)), Ok(0));
+/// ```
+pub fn circle_line_intersection_count<T>(
+    circle: &Circle2D<T>,
+    line: &Line2D<T>,
+) -> Result<usize, MathError>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + PartialOrd
+        + From<i32>
+        + Loggable,
+{
+    let (_, disc) = chord_terms(circle, line, "circle_line_intersection_count")?;
+    Ok(match disc.partial_cmp(&T::from(0)) {
+        Some(Ordering::Greater) => 2,
+        Some(Ordering::Equal) => 1,
+        _ => 0,
+    })
+}
+
+/// The function `circle_line_intersection` calculates the points where a
+/// line meets a circle, when the square root of the discriminant exists in
+/// `T`. Passing `|d: f64| Some(d.sqrt())` gives the floating-point answer.
+///
+/// Arguments:
+///
+/// * `circle`: The circle.
+/// * `line`: The line.
+/// * `sqrt`: The square root in `T`, or `None` where it does not exist.
+///
+/// Returns:
+///
+/// The two points, ordered along the direction `(b, −a)` of the line and
+/// equal for a tangent. Fails with `MathError::NullLine` if `a² + b² = 0`,
+/// `MathError::NonIntersecting` if the line misses the circle, and
+/// `MathError::NotRepresentable` if `sqrt` fails.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::circle::{circle_line_intersection, Circle2D};
+/// use rat_trig_rs::error::MathError;
+/// use rat_trig_rs::geometry::{Line2D, Point2D};
+/// let r = Rational32::from;
+/// let sqrt = |d: Rational32| (0..=*d.numer()).map(r).find(|s| s * s == d);
+/// let c = Circle2D::new(Point2D::new(r(0), r(0)), r(25));
+/// let y_is_3 = Line2D::new(r(0), r(1), r(-3));
+/// let points = circle_line_intersection(&c, &y_is_3, sqrt);
+/// assert_eq!(points, Ok([Point2D::new(r(-4), r(3)), Point2D::new(r(4), r(3))]));
+/// let y_is_1 = Line2D::new(r(0), r(1), r(-1));
+/// let points = circle_line_intersection(&c, &y_is_1, sqrt);
+/// assert_eq!(points, Err(MathError::NotRepresentable));
+/// ```
+pub fn circle_line_intersection<T, F>(
+    circle: &Circle2D<T>,
+    line: &Line2D<T>,
+    sqrt: F,
+) -> Result<[Point2D<T>; 2], MathError>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>
+        + Loggable,
+    F: FnOnce(T) -> Option<T>,
+{
+    let (e, disc) = chord_terms(circle, line, "circle_line_intersection")?;
+    if disc < T::from(0) {
+        return Err(degenerate(
+            targets::CIRCLE,
+            "circle_line_intersection",
+            MathError::NonIntersecting,
+            &(circle, line),
+        ));
+    }
+    let root = sqrt(disc).ok_or_else(|| {
+        degenerate(
+            targets::CIRCLE,
+            "circle_line_intersection",
+            MathError::NotRepresentable,
+            &disc,
+        )
+    })?;
+    let norm = quadrance(&(line.a, line.b));
+    // the foot of the perpendicular from the center, then along (b, −a)
+    let foot_x = circle.center.x - line.a * e / norm;
+    let foot_y = circle.center.y - line.b * e / norm;
+    let (dx, dy) = (line.b * root / norm, T::from(0) - line.a * root / norm);
+    Ok([
+        Point2D::new(foot_x - dx, foot_y - dy),
+        Point2D::new(foot_x + dx, foot_y + dy),
+    ])
+}
+
+/// The function `radical_axis` calculates the radical axis of two circles,
+/// the line of points with equal power with respect to both, by subtracting
+/// their equations.
+///
+/// Arguments:
+///
+/// * `c1`, `c2`: The two circles.
+///
+/// Returns:
+///
+/// The line `2(h₂ − h₁)·x + 2(k₂ − k₁)·y + P₁(0) − P₂(0) = 0`, where `Pᵢ(0)`
+/// is the power of the origin, with `c2` on its positive side. It passes
+/// through the common points of intersecting circles. Concentric circles
+/// give `a = b = 0`, a null line.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::circle::{radical_axis, Circle2D};
+/// use rat_trig_rs::geometry::{Line2D, Point2D};
+/// let c1 = Circle2D::new(Point2D::new(0, 0), 25);
+/// let c2 = Circle2D::new(Point2D::new(8, 0), 25);
+/// assert_eq!(radical_axis(&c1, &c2), Line2D::new(16, 0, -64));
+/// ```
+pub fn radical_axis<T>(c1: &Circle2D<T>, c2: &Circle2D<T>) -> Line2D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<i32>,
+{
+    let two = T::from(2);
+    let origin = |c: &Circle2D<T>| quadrance(&(c.center.x, c.center.y)) - c.quadrance;
+    Line2D::new(
+        two * (c2.center.x - c1.center.x),
+        two * (c2.center.y - c1.center.y),
+        origin(c1) - origin(c2),
+    )
+}
+
+/// The function `radical_center` calculates the radical center of three
+/// circles, the common point of their three radical axes.
+///
+/// Arguments:
+///
+/// * `c1`, `c2`, `c3`: The three circles.
+///
+/// Returns:
+///
+/// The point with equal power with respect to all three circles, or the
+/// errors of [`safe_line_intersection`] on the axes of `(c1, c2)` and
+/// `(c1, c3)`: `MathError::NullLine` if `c1` is concentric with another
+/// circle, `MathError::NonIntersecting` if the centers are collinear, and
+/// `MathError::InvalidInput` if the axes coincide.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::circle::{radical_center, Circle2D};
+/// use rat_trig_rs::geometry::Point2D;
+/// let r = Rational32::from;
+/// let c = |x: i32, y: i32, q: i32| Circle2D::new(Point2D::new(r(x), r(y)), r(q));
+/// let center = radical_center(&c(0, 0, 4), &c(4, 0, 4), &c(0, 6, 4));
+/// assert_eq!(center, Ok(Point2D::new(r(2), r(3))));
+/// ```
+pub fn radical_center<T>(
+    c1: &Circle2D<T>,
+    c2: &Circle2D<T>,
+    c3: &Circle2D<T>,
+) -> Result<Point2D<T>, MathError>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>
+        + Loggable,
+{
+    let l_12 = radical_axis(c1, c2);
+    let l_13 = radical_axis(c1, c3);
+    safe_line_intersection(&(l_12.a, l_12.b, l_12.c), &(l_13.a, l_13.b, l_13.c))
+        .map(|(x, y)| Point2D::new(x, y))
+}
+
+/// How two tangent circles touch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tangency {
+    /// Each circle lies outside the other.
+    External,
+    /// One circle lies inside the other.
+    Internal,
+}
+
+/// The function `is_tangent` checks whether a line touches a circle in
+/// exactly one point; see [`circle_line_intersection_count`].
+///
+/// Returns:
+///
+/// Whether the line is tangent. A null line (`a = b = 0`) is never tangent.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::circle::{is_tangent, Circle2D};
+/// use rat_trig_rs::geometry::{Line2D, Point2D};
+/// let c = Circle2D::new(Point2D::new(0, 0), 25);
+/// assert!(is_tangent(&Line2D::new(3, 4, 25), &c));
+/// assert!(!is_tangent(&Line2D::new(3, 4, 24), &c));
+/// ```
+#[inline]
+pub fn is_tangent<T>(line: &Line2D<T>, circle: &Circle2D<T>) -> bool
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + PartialOrd
+        + From<i32>
+        + Loggable,
+{
+    circle_line_intersection_count(circle, line) == Ok(1)
+}
+
+/// The function `are_circles_tangent` decides whether two circles touch in
+/// exactly one point. With `d` the quadrance between the centers, that
+/// happens when the "triangle" of the two centers and the touching point is
+/// flat, i.e. [`archimedes`]`(q₁, q₂, d) = 0`; the sign of `d − q₁ − q₂`
+/// tells external from internal contact.
+///
+/// Arguments:
+///
+/// * `c1`, `c2`: The two circles.
+///
+/// Returns:
+///
+/// `Some(Tangency::External)` or `Some(Tangency::Internal)`, or `None` if
+/// the circles are not tangent. Circles of negative quadrance and coincident
+/// circles are never tangent; a point circle on the other circle touches it
+/// externally.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::circle::{are_circles_tangent, Circle2D, Tangency};
+/// use rat_trig_rs::geometry::Point2D;
+/// let c = |x: i32, q: i32| Circle2D::new(Point2D::new(x, 0), q);
+/// assert_eq!(are_circles_tangent(&c(0, 4), &c(5, 9)), Some(Tangency::External));
+/// assert_eq!(are_circles_tangent(&c(0, 4), &c(1, 1)), Some(Tangency::Internal));
+/// assert_eq!(are_circles_tangent(&c(0, 4), &c(4, 9)), None);
+/// ```
+pub fn are_circles_tangent<T>(c1: &Circle2D<T>, c2: &Circle2D<T>) -> Option<Tangency>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    let zero = T::from(0);
+    let (q_1, q_2) = (c1.quadrance, c2.quadrance);
+    if q_1 < zero || q_2 < zero {
+        return None;
+    }
+    let d = quadrance(&(c2.center.x - c1.center.x, c2.center.y - c1.center.y));
+    if d == zero || archimedes(&q_1, &q_2, &d) != zero {
+        return None;
+    }
+    if d - q_1 - q_2 < zero {
+        Some(Tangency::Internal)
+    } else {
+        Some(Tangency::External)
+    }
+}
+
+/// The function `tangent_quadrance` calculates the quadrance of a tangent
+/// segment from a point to a circle, which is the power of the point.
+///
+/// Arguments:
+///
+/// * `point`: The point, outside or on the circle.
+/// * `circle`: The circle.
+///
+/// Returns:
+///
+/// `Q(point, center) − q`, or `MathError::InvalidInput` if the point lies
+/// inside the circle, where there is no tangent.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::circle::{tangent_quadrance, Circle2D};
+/// use rat_trig_rs::error::MathError;
+/// use rat_trig_rs::geometry::Point2D;
+/// let c = Circle2D::new(Point2D::new(0, 0), 9);
+/// assert_eq!(tangent_quadrance(&Point2D::new(5, 0), &c), Ok(16));
+/// assert_eq!(tangent_quadrance(&Point2D::new(1, 1), &c), Err(MathError::InvalidInput));
+/// ```
+pub fn tangent_quadrance<T>(point: &Point2D<T>, circle: &Circle2D<T>) -> Result<T, MathError>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + PartialOrd
+        + From<i32>
+        + Loggable,
+{
+    let power = circle.power(point);
+    if power < T::from(0) {
+        return Err(degenerate(
+            targets::CIRCLE,
+            "tangent_quadrance",
+            MathError::InvalidInput,
+            &(point, circle),
+        ));
+    }
+    Ok(power)
+}
+
+/// The relative position of two circles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CircleRelation {
+    /// Each circle lies strictly outside the other.
+    Disjoint,
+    /// The circles touch in exactly one point, externally or internally.
+    Tangent,
+    /// The circles cross in two points.
+    Intersecting,
+    /// One circle lies strictly inside the other (or they coincide).
+    Contained,
+}
+
+/// The function `circle_circle_relation` decides how two circles lie
+/// relative to each other from the quadrance `d` between their centers and
+/// their quadrances, by the sign of [`archimedes`]`(q₁, q₂, d)`: positive
+/// when the centers and a common point form a true triangle, zero when that
+/// triangle is flat.
+///
+/// Arguments:
+///
+/// * `c1`, `c2`: The two circles.
+///
+/// Returns:
+///
+/// The relation. A circle of negative quadrance has no points, so it is
+/// disjoint from everything.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::circle::{circle_circle_relation, Circle2D, CircleRelation};
+/// use rat_trig_rs::geometry::Point2D;
+/// let c = |x: i32, q: i32| Circle2D::new(Point2D::new(x, 0), q);
+/// assert_eq!(circle_circle_relation(&c(0, 4), &c(3, 4)), CircleRelation::Intersecting);
+/// assert_eq!(circle_circle_relation(&c(0, 4), &c(4, 4)), CircleRelation::Tangent);
+/// assert_eq!(circle_circle_relation(&c(0, 4), &c(5, 4)), CircleRelation::Disjoint);
+/// assert_eq!(circle_circle_relation(&c(0, 9), &c(1, 1)), CircleRelation::Contained);
+/// ```
+pub fn circle_circle_relation<T>(c1: &Circle2D<T>, c2: &Circle2D<T>) -> CircleRelation
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    let zero = T::from(0);
+    let (q_1, q_2) = (c1.quadrance, c2.quadrance);
+    if q_1 < zero || q_2 < zero {
+        return CircleRelation::Disjoint;
+    }
+    let d = quadrance(&(c2.center.x - c1.center.x, c2.center.y - c1.center.y));
+    let apart = d - q_1 - q_2;
+    match archimedes(&q_1, &q_2, &d).partial_cmp(&zero) {
+        Some(Ordering::Greater) => CircleRelation::Intersecting,
+        Some(Ordering::Equal) if d != zero => CircleRelation::Tangent,
+        _ if apart > zero => CircleRelation::Disjoint,
+        _ => CircleRelation::Contained,
+    }
+}
+
+/// The function `circle_circle_intersection` calculates the common points
+/// of two circles, as the meet of the first circle with their
+/// [`radical_axis`]; see [`circle_line_intersection`].
+///
+/// Arguments:
+///
+/// * `c1`, `c2`: The two circles.
+/// * `sqrt`: The square root in `T`, or `None` where it does not exist.
+///
+/// Returns:
+///
+/// The two points, equal for tangent circles. Fails with
+/// `MathError::InvalidInput` if the circles are concentric,
+/// `MathError::NonIntersecting` if they do not meet, and
+/// `MathError::NotRepresentable` if the points are not in `T`.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::circle::{circle_circle_intersection, Circle2D};
+/// use rat_trig_rs::geometry::Point2D;
+/// let r = Rational32::from;
+/// let sqrt = |d: Rational32| (0..=*d.numer()).map(r).find(|s| s * s == d);
+/// let c1 = Circle2D::new(Point2D::new(r(0), r(0)), r(25));
+/// let c2 = Circle2D::new(Point2D::new(r(6), r(0)), r(25));
+/// let points = circle_circle_intersection(&c1, &c2, sqrt);
+/// assert_eq!(points, Ok([Point2D::new(r(3), r(4)), Point2D::new(r(3), r(-4))]));
+/// ```
+pub fn circle_circle_intersection<T, F>(
+    c1: &Circle2D<T>,
+    c2: &Circle2D<T>,
+    sqrt: F,
+) -> Result<[Point2D<T>; 2], MathError>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>
+        + Loggable,
+    F: FnOnce(T) -> Option<T>,
+{
+    if c1.center == c2.center {
+        return Err(degenerate(
+            targets::CIRCLE,
+            "circle_circle_intersection",
+            MathError::InvalidInput,
+            &(c1, c2),
+        ));
+    }
+    circle_line_intersection(c1, &radical_axis(c1, c2), sqrt)
+}
+
+/// The value `e = a·h + b·k + c` of the line at the center and the
+/// discriminant `q·(a² + b²) − e²`, or `MathError::NullLine`.
+fn chord_terms<T>(
+    circle: &Circle2D<T>,
+    line: &Line2D<T>,
+    func: &'static str,
+) -> Result<(T, T), MathError>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + PartialOrd
+        + From<i32>
+        + Loggable,
+{
+    let norm = quadrance(&(line.a, line.b));
+    if norm == T::from(0) {
+        return Err(degenerate(targets::CIRCLE, func, MathError::NullLine, line));
+    }
+    let e = line.a * circle.center.x + line.b * circle.center.y + line.c;
+    Ok((e, circle.quadrance * norm - e * e))
+}
+
+/// The function `power_of_point` calculates the power of a point with
+/// respect to a circle, `Q(p, center) − q`.
+///
+/// Arguments:
+///
+/// * `p`: The point.
+/// * `center`: The center of the circle.
+/// * `q`: The quadrance of the circle (the square of its radius).
+///
+/// Returns:
+///
+/// The power, negative inside the circle, zero on it and positive outside.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::circle::power_of_point;
+/// use rat_trig_rs::geometry::Point2D;
+/// assert_eq!(power_of_point(&Point2D::new(3, 4), &Point2D::new(0, 0), 9), 16);
+/// ```
+#[inline]
+pub fn power_of_point<T>(p: &Point2D<T>, center: &Point2D<T>, q: T) -> T
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    quadrance(&(p.x - center.x, p.y - center.y)) - q
+}
+
+/// The function `point_vs_circle` locates a point relative to a circle.
+///
+/// Arguments:
+///
+/// * `p`: The point.
+/// * `center`: The center of the circle.
+/// * `q`: The quadrance of the circle.
+///
+/// Returns:
+///
+/// `Inside`, `On` or `Outside`. A circle of negative quadrance has no
+/// points, so every point is outside it.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::circle::{point_vs_circle, CirclePosition};
+/// use rat_trig_rs::geometry::Point2D;
+/// let origin = Point2D::new(Rational32::from(0), Rational32::from(0));
+/// let p = Point2D::new(Rational32::new(3, 5), Rational32::new(4, 5));
+/// assert_eq!(point_vs_circle(&p, &origin, Rational32::from(1)), CirclePosition::On);
+/// assert_eq!(point_vs_circle(&p, &origin, Rational32::from(2)), CirclePosition::Inside);
+/// ```
+#[inline]
+pub fn point_vs_circle<T>(p: &Point2D<T>, center: &Point2D<T>, q: T) -> CirclePosition
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd,
+{
+    match quadrance(&(p.x - center.x, p.y - center.y)).partial_cmp(&q) {
+        Some(Ordering::Less) => CirclePosition::Inside,
+        Some(Ordering::Equal) => CirclePosition::On,
+        _ => CirclePosition::Outside,
+    }
+}
+
+/// The function `point_in_annulus` checks whether a point lies in the closed
+/// annulus between two concentric circles.
+///
+/// Arguments:
+///
+/// * `p`: The point.
+/// * `center`: The common center.
+/// * `q_inner`: The quadrance of the inner circle.
+/// * `q_outer`: The quadrance of the outer circle.
+///
+/// Returns:
+///
+/// Whether `q_inner <= Q(p, center) <= q_outer`; both boundary circles are
+/// included, and the annulus is empty if `q_inner > q_outer`.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::circle::point_in_annulus;
+/// use rat_trig_rs::geometry::Point2D;
+/// let c = Point2D::new(0, 0);
+/// assert!(point_in_annulus(&Point2D::new(2, 1), &c, 4, 9));
+/// assert!(point_in_annulus(&Point2D::new(2, 0), &c, 4, 9));
+/// assert!(!point_in_annulus(&Point2D::new(1, 1), &c, 4, 9));
+/// ```
+#[inline]
+pub fn point_in_annulus<T>(p: &Point2D<T>, center: &Point2D<T>, q_inner: T, q_outer: T) -> bool
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd,
+{
+    let q = quadrance(&(p.x - center.x, p.y - center.y));
+    q_inner <= q && q <= q_outer
+}
+
+/// The function `are_concyclic` checks whether four points lie on a common
+/// circle or line.
+///
+/// The test is whether the `4×4` determinant with rows
+/// `(x, y, x² + y², 1)` vanishes, so it is exact over integers and
+/// rationals.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::circle::are_concyclic;
+/// use rat_trig_rs::geometry::Point2D;
+/// let p = |x, y| Point2D::new(x, y);
+/// assert!(are_concyclic(&p(5, 0), &p(3, 4), &p(-4, 3), &p(0, -5)));
+/// assert!(!are_concyclic(&p(5, 0), &p(3, 4), &p(-4, 3), &p(0, -4)));
+/// ```
+pub fn are_concyclic<T>(p1: &Point2D<T>, p2: &Point2D<T>, p3: &Point2D<T>, p4: &Point2D<T>) -> bool
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    // expand along the last column after translating p4 to the origin
+    let row = |p: &Point2D<T>| {
+        let (x, y) = (p.x - p4.x, p.y - p4.y);
+        (x, y, x * x + y * y)
+    };
+    let ((a1, b1, c1), (a2, b2, c2), (a3, b3, c3)) = (row(p1), row(p2), row(p3));
+    let det = a1 * (b2 * c3 - b3 * c2) - b1 * (a2 * c3 - a3 * c2) + c1 * (a2 * b3 - a3 * b2);
+    det == T::from(0)
+}
+
+/// The function `cyclic_quadrea` calculates the quadrea `16·K²` of a
+/// quadrilateral after checking that its vertices are concyclic.
+///
+/// Arguments:
+///
+/// * `p1`, `p2`, `p3`, `p4`: The vertices, in order.
+///
+/// Returns:
+///
+/// The quadrea of the quadrilateral, which agrees with
+/// [`brahmagupta`](crate::trigonom::brahmagupta) on its side quadrances, or
+/// `MathError::DegenerateTriangle` if `p1, p2, p3` are collinear and
+/// `MathError::InvalidInput` if `p4` is not on their circle.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::circle::cyclic_quadrea;
+/// use rat_trig_rs::error::MathError;
+/// use rat_trig_rs::geometry::Point2D;
+/// let p = |x, y| Point2D::new(x, y);
+/// assert_eq!(cyclic_quadrea(&p(5, 0), &p(3, 4), &p(-4, 3), &p(0, -5)), Ok(16 * 45 * 45));
+/// assert_eq!(
+///     cyclic_quadrea(&p(5, 0), &p(3, 4), &p(-4, 3), &p(0, -4)),
+///     Err(MathError::InvalidInput)
+/// );
+/// ```
+pub fn cyclic_quadrea<T>(
+    p1: &Point2D<T>,
+    p2: &Point2D<T>,
+    p3: &Point2D<T>,
+    p4: &Point2D<T>,
+) -> Result<T, MathError>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + PartialEq
+        + From<i32>
+        + Loggable,
+{
+    if p1.is_collinear(p2, p3) {
+        return Err(degenerate(
+            targets::CIRCLE,
+            "cyclic_quadrea",
+            MathError::DegenerateTriangle,
+            &(p1, p2, p3),
+        ));
+    }
+    if !are_concyclic(p1, p2, p3, p4) {
+        return Err(degenerate(
+            targets::CIRCLE,
+            "cyclic_quadrea",
+            MathError::InvalidInput,
+            &(p1, p2, p3, p4),
+        ));
+    }
+    // twice the signed area, split along the diagonal p1p3
+    let twice_area = p1.twist(p2, p3) + p1.twist(p3, p4);
+    Ok(T::from(4) * twice_area * twice_area)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+
+    type Q = Ratio<i32>;
+
+    #[test]
+    fn test_point_vs_circle() {
+        let c = Point2D::new(Q::new(1, 2), Q::from(1));
+        let q = Q::new(25, 4);
+        let on = Point2D::new(Q::from(2), Q::from(3));
+        assert_eq!(point_vs_circle(&on, &c, q), CirclePosition::On);
+        assert_eq!(power_of_point(&on, &c, q), Q::from(0));
+        assert_eq!(point_vs_circle(&c, &c, q), CirclePosition::Inside);
+        let far = Point2D::new(Q::from(5), Q::from(1));
+        assert_eq!(point_vs_circle(&far, &c, q), CirclePosition::Outside);
+        // a point circle and an imaginary one
+        assert_eq!(point_vs_circle(&c, &c, Q::from(0)), CirclePosition::On);
+        assert_eq!(
+            point_vs_circle(&c, &c, Q::from(-1)),
+            CirclePosition::Outside
+        );
+        assert_eq!(
+            point_vs_circle(&Point2D::new(0.0, f64::NAN), &Point2D::new(0.0, 0.0), 1.0),
+            CirclePosition::Outside
+        );
+    }
+
+    #[test]
+    fn test_circle2d() {
+        let c = Circle2D::from_center_and_point(
+            Point2D::new(Q::new(1, 2), Q::from(1)),
+            &Point2D::new(Q::from(2), Q::from(3)),
+        );
+        assert_eq!(c.quadrance, Q::new(25, 4));
+        let on = Point2D::new(Q::from(-1), Q::from(3));
+        assert_eq!(c.point_position(&on), CirclePosition::On);
+        assert!(c.contains(&on) && c.contains(&c.center));
+        assert_eq!(c.power(&on), Q::from(0));
+        let far = Point2D::new(Q::from(3), Q::from(2));
+        assert_eq!(c.point_position(&far), CirclePosition::Outside);
+        assert!(!c.contains(&far));
+        assert_eq!(c.power(&far), Q::from(1));
+    }
+
+    #[test]
+    fn test_circle2d_circumscribing() {
+        let p = |x: i32, y: i32| Point2D::new(Q::from(x), Q::from(y));
+        let (a, b, c) = (p(5, 0), p(3, 4), p(-4, 3));
+        let circle = Circle2D::circumscribing(&a, &b, &c).unwrap();
+        assert_eq!(circle, Circle2D::new(p(0, 0), Q::from(25)));
+        assert_eq!(circle.point_position(&p(0, -5)), CirclePosition::On);
+        let (a, b, c) = (p(1, 2), p(-3, 0), p(2, -1));
+        let circle = Circle2D::circumscribing(&a, &b, &c).unwrap();
+        assert!([a, b, c].iter().all(|v| circle.power(v) == Q::from(0)));
+        assert_eq!(
+            Circle2D::circumscribing(&a, &a, &c),
+            Err(MathError::DegenerateTriangle)
+        );
+    }
+
+    #[test]
+    fn test_circle_line_intersection() {
+        let p = |x: i32, y: i32| Point2D::new(Q::from(x), Q::from(y));
+        let sqrt = |d: Q| (0..=*d.numer()).map(Q::from).find(|s| s * s == d);
+        // the circle through (2, 1) and (-2, -3) about (1, -2), cut by y = x - 1
+        let c = Circle2D::new(p(1, -2), Q::from(10));
+        let line = Line2D::from_points(&p(2, 1), &p(-2, -3)).unwrap();
+        assert_eq!(circle_line_intersection_count(&c, &line), Ok(2));
+        assert_eq!(
+            circle_line_intersection(&c, &line, sqrt),
+            Ok([p(2, 1), p(-2, -3)])
+        );
+        // the tangent at (4, -1)
+        let tangent = Line2D::new(Q::from(3), Q::from(1), Q::from(-11));
+        assert_eq!(circle_line_intersection_count(&c, &tangent), Ok(1));
+        assert_eq!(
+            circle_line_intersection(&c, &tangent, sqrt),
+            Ok([p(4, -1), p(4, -1)])
+        );
+        let miss = Line2D::new(Q::from(0), Q::from(1), Q::from(-5));
+        assert_eq!(circle_line_intersection_count(&c, &miss), Ok(0));
+        assert_eq!(
+            circle_line_intersection(&c, &miss, sqrt),
+            Err(MathError::NonIntersecting)
+        );
+        let null = Line2D::new(Q::from(0), Q::from(0), Q::from(1));
+        assert_eq!(
+            circle_line_intersection_count(&c, &null),
+            Err(MathError::NullLine)
+        );
+        // the floating-point path
+        let unit = Circle2D::new(Point2D::new(0.0, 0.0), 1.0);
+        let diagonal = Line2D::new(1.0, -1.0, 0.0);
+        let [u, v] = circle_line_intersection(&unit, &diagonal, |d: f64| Some(d.sqrt())).unwrap();
+        assert!((u.x - 0.5_f64.sqrt()).abs() < 1e-12 && (v.y + 0.5_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_radical_axis_and_center() {
+        let p = |x: i32, y: i32| Point2D::new(Q::from(x), Q::from(y));
+        let c1 = Circle2D::new(p(1, 2), Q::from(10));
+        let c2 = Circle2D::new(p(-3, 0), Q::from(4));
+        let c3 = Circle2D::new(p(2, -5), Q::from(1));
+        let axis = radical_axis(&c1, &c2);
+        let on_axis = |v: &Point2D<Q>| axis.a * v.x + axis.b * v.y + axis.c == Q::from(0);
+        let center = radical_center(&c1, &c2, &c3).unwrap();
+        assert!(on_axis(&center));
+        assert_eq!(c1.power(&center), c2.power(&center));
+        assert_eq!(c1.power(&center), c3.power(&center));
+        // intersecting circles: the axis is their common chord
+        let d1 = Circle2D::from_center_and_point(p(0, 0), &p(3, 4));
+        let d2 = Circle2D::from_center_and_point(p(6, 0), &p(3, 4));
+        let chord = radical_axis(&d1, &d2);
+        assert_eq!(chord, Line2D::new(Q::from(12), Q::from(0), Q::from(-36)));
+        assert_eq!(
+            radical_center(&d1, &d2, &Circle2D::new(p(9, 0), Q::from(1))),
+            Err(MathError::NonIntersecting)
+        );
+        assert_eq!(
+            radical_center(&d1, &Circle2D::new(p(0, 0), Q::from(1)), &c3),
+            Err(MathError::NullLine)
+        );
+    }
+
+    #[test]
+    fn test_tangency() {
+        let p = |x: i32, y: i32| Point2D::new(Q::from(x), Q::from(y));
+        let c = Circle2D::new(p(1, -2), Q::from(10));
+        assert!(is_tangent(
+            &Line2D::new(Q::from(3), Q::from(1), Q::from(-11)),
+            &c
+        ));
+        assert!(!is_tangent(
+            &Line2D::new(Q::from(3), Q::from(1), Q::from(-10)),
+            &c
+        ));
+        assert!(!is_tangent(
+            &Line2D::new(Q::from(0), Q::from(0), Q::from(1)),
+            &c
+        ));
+        // touching at (4, -1) from outside and inside
+        let outer = Circle2D::from_center_and_point(p(7, 0), &p(4, -1));
+        let inner = Circle2D::from_center_and_point(p(-2, -3), &p(4, -1));
+        assert_eq!(are_circles_tangent(&c, &outer), Some(Tangency::External));
+        assert_eq!(are_circles_tangent(&c, &inner), Some(Tangency::Internal));
+        assert_eq!(are_circles_tangent(&inner, &c), Some(Tangency::Internal));
+        assert_eq!(are_circles_tangent(&c, &c), None);
+        let point = Circle2D::new(p(4, -1), Q::from(0));
+        assert_eq!(are_circles_tangent(&point, &c), Some(Tangency::External));
+        let imaginary = Circle2D::new(p(0, 0), Q::from(-1));
+        assert_eq!(are_circles_tangent(&imaginary, &imaginary), None);
+        assert_eq!(tangent_quadrance(&p(4, -1), &c), Ok(Q::from(0)));
+        assert_eq!(tangent_quadrance(&p(7, 0), &c), Ok(Q::from(30)));
+        assert_eq!(
+            tangent_quadrance(&c.center, &c),
+            Err(MathError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_circle_circle() {
+        let p = |x: i32, y: i32| Point2D::new(Q::from(x), Q::from(y));
+        let sqrt = |d: Q| (0..=*d.numer()).map(Q::from).find(|s| s * s == d);
+        let c = Circle2D::new(p(1, -2), Q::from(10));
+        let relation = |x: i32, y: i32, q: i32| {
+            circle_circle_relation(&c, &Circle2D::new(p(x, y), Q::from(q)))
+        };
+        assert_eq!(relation(7, 0, 10), CircleRelation::Tangent);
+        assert_eq!(relation(-2, -3, 40), CircleRelation::Tangent);
+        assert_eq!(relation(7, 0, 11), CircleRelation::Intersecting);
+        assert_eq!(relation(7, 0, 9), CircleRelation::Disjoint);
+        assert_eq!(relation(1, -1, 1), CircleRelation::Contained);
+        assert_eq!(relation(1, -2, 10), CircleRelation::Contained);
+        assert_eq!(relation(1, -2, -1), CircleRelation::Disjoint);
+        assert_eq!(relation(4, -1, 0), CircleRelation::Tangent);
+        // through (2, 1) and (-2, -3), as in test_circle_line_intersection
+        let other = Circle2D::from_center_and_point(p(-3, 2), &p(2, 1));
+        assert_eq!(
+            circle_circle_relation(&c, &other),
+            CircleRelation::Intersecting
+        );
+        assert_eq!(
+            circle_circle_intersection(&c, &other, sqrt),
+            Ok([p(-2, -3), p(2, 1)])
+        );
+        let touching = Circle2D::new(p(7, 0), Q::from(10));
+        assert_eq!(
+            circle_circle_intersection(&c, &touching, sqrt),
+            Ok([p(4, -1), p(4, -1)])
+        );
+        let far = Circle2D::new(p(7, 0), Q::from(1));
+        assert_eq!(
+            circle_circle_intersection(&c, &far, sqrt),
+            Err(MathError::NonIntersecting)
+        );
+        assert_eq!(
+            circle_circle_intersection(&c, &c, sqrt),
+            Err(MathError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_point_in_annulus() {
+        let c = Point2D::new(1, 1);
+        assert!(point_in_annulus(&Point2D::new(1, 1), &c, 0, 0));
+        assert!(point_in_annulus(&Point2D::new(4, 5), &c, 25, 25));
+        assert!(!point_in_annulus(&Point2D::new(4, 5), &c, 1, 24));
+        assert!(!point_in_annulus(&Point2D::new(4, 5), &c, 26, 30));
+        assert!(!point_in_annulus(&Point2D::new(2, 1), &c, 4, 1));
+    }
+
+    #[test]
+    fn test_cyclic_quadrea() {
+        use crate::trigonom::brahmagupta;
+        let p = |x: i64, y: i64| Point2D::new(x, y);
+        let (a, b, c, d) = (p(5, 0), p(3, 4), p(-4, 3), p(0, -5));
+        let sides =
+            [(a, b), (b, c), (c, d), (d, a)].map(|(u, v)| quadrance(&(v.x - u.x, v.y - u.y)));
+        let quadrea = cyclic_quadrea(&a, &b, &c, &d).unwrap();
+        // P = 20·50·80·50 = 2000², so Brahmagupta's formula is rational here
+        let expected = brahmagupta(&sides[0], &sides[1], &sides[2], &sides[3]);
+        assert_eq!(expected.d, 2000 * 2000);
+        assert_eq!(quadrea, expected.a + expected.b * 2000);
+        // the bowtie takes the conjugate root
+        let bowtie = cyclic_quadrea(&a, &c, &b, &d).unwrap();
+        let sides =
+            [(a, c), (c, b), (b, d), (d, a)].map(|(u, v)| quadrance(&(v.x - u.x, v.y - u.y)));
+        let expected = brahmagupta(&sides[0], &sides[1], &sides[2], &sides[3]);
+        let root = (1..10_000).find(|r| r * r == expected.d).unwrap();
+        assert_eq!(bowtie, expected.a - expected.b * root);
+        assert_eq!(
+            cyclic_quadrea(&a, &p(0, 0), &p(-5, 0), &d),
+            Err(MathError::DegenerateTriangle)
+        );
+        assert!(are_concyclic(&p(0, 0), &p(1, 1), &p(2, 2), &p(3, 3)));
+    }
+}
@@ -0,0 +1,262 @@
+//! Exact time of impact for a point in linear motion.
+//!
+//! A point moving linearly from `a` to `b` is at `a + t·(b − a)` at time
+//! `t ∈ [0, 1]`. Against a segment the first contact time solves a linear
+//! equation, so it is rational whenever the coordinates are. Against a
+//! circle it solves a quadratic, so it is returned as a [`QuadExt`] surd;
+//! the decision whether contact happens at all is still exact.
+use crate::geometry::Point2D;
+use crate::quadext::QuadExt;
+use core::cmp::Ordering;
+use core::convert::From;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// The function `segment_time_of_impact` finds when a moving point first
+/// touches a static segment.
+///
+/// Arguments:
+///
+/// * `a`, `b`: The start and end of the motion.
+/// * `p`, `q`: The endpoints of the static segment.
+///
+/// Returns:
+///
+/// The smallest `t ∈ [0, 1]` at which `a + t·(b − a)` lies on the closed
+/// segment `pq`, or `None` if there is none. When the motion runs along the
+/// segment, this is the time it enters it.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::collision::segment_time_of_impact;
+/// use rat_trig_rs::geometry::Point2D;
+/// let r = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+/// // a wall at x = 2 between y = -1 and y = 1
+/// let hit = segment_time_of_impact(&r(0, 0), &r(3, 1), &r(2, -1), &r(2, 1));
+/// assert_eq!(hit, Some(Rational32::new(2, 3)));
+/// assert_eq!(segment_time_of_impact(&r(0, 0), &r(3, 3), &r(2, -1), &r(2, 1)), None);
+/// ```
+pub fn segment_time_of_impact<T>(
+    a: &Point2D<T>,
+    b: &Point2D<T>,
+    p: &Point2D<T>,
+    q: &Point2D<T>,
+) -> Option<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>,
+{
+    let (zero, one) = (T::from(0), T::from(1));
+    let within = |t: T| zero <= t && t <= one;
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let (ex, ey) = (q.x - p.x, q.y - p.y);
+    let (wx, wy) = (p.x - a.x, p.y - a.y);
+    let denom = dx * ey - dy * ex;
+    if denom != zero {
+        let t = (wx * ey - wy * ex) / denom;
+        let u = (wx * dy - wy * dx) / denom;
+        return (within(t) && within(u)).then_some(t);
+    }
+    // the motion is parallel to the segment, or one of them is a point
+    let q_d = dx * dx + dy * dy;
+    if q_d == zero {
+        // a stationary point touches at t = 0 if it lies on the segment
+        let on = if ex * ex + ey * ey == zero {
+            wx == zero && wy == zero
+        } else {
+            wx * ey - wy * ex == zero
+                && wx * ex + wy * ey <= zero
+                && (a.x - q.x) * ex + (a.y - q.y) * ey <= zero
+        };
+        return on.then_some(zero);
+    }
+    if wx * dy - wy * dx != zero {
+        return None;
+    }
+    // collinear: the segment covers the parameter interval between its ends
+    let tp = (wx * dx + wy * dy) / q_d;
+    let tq = ((q.x - a.x) * dx + (q.y - a.y) * dy) / q_d;
+    let (lo, hi) = if tp <= tq { (tp, tq) } else { (tq, tp) };
+    let t = if lo > zero { lo } else { zero };
+    (t <= one && t <= hi).then_some(t)
+}
+
+/// The sign of `u + v·√d` for `d >= 0`.
+fn surd_sign<T>(u: T, v: T, d: T) -> Ordering
+where
+    T: Copy + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    let zero = T::from(0);
+    let sign = |x: T| x.partial_cmp(&zero).unwrap_or(Ordering::Equal);
+    let (su, sv) = (sign(u), if d == zero { Ordering::Equal } else { sign(v) });
+    if sv == Ordering::Equal || su == sv {
+        return if su == Ordering::Equal { sv } else { su };
+    }
+    if su == Ordering::Equal {
+        return sv;
+    }
+    // opposite signs: the term of larger square wins
+    match (u * u).partial_cmp(&(v * v * d)) {
+        Some(Ordering::Greater) => su,
+        Some(Ordering::Less) => sv,
+        _ => Ordering::Equal,
+    }
+}
+
+/// The function `circle_time_of_impact` finds when a moving point first
+/// lies on a static circle.
+///
+/// Arguments:
+///
+/// * `a`, `b`: The start and end of the motion.
+/// * `center`: The center of the circle.
+/// * `q`: The quadrance of the circle.
+///
+/// Returns:
+///
+/// The smallest `t ∈ [0, 1]` with `Q(a + t·(b − a), center) = q`, as the
+/// surd `t = u + v·√d`, or `None` if the point never meets the circle. A
+/// point starting inside meets the circle when it leaves. The radicand `d`
+/// is the (quarter) discriminant of the contact equation; when it happens to
+/// be a square, the time is rational but is still returned unreduced.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::collision::circle_time_of_impact;
+/// use rat_trig_rs::geometry::Point2D;
+/// let r = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+/// let r32 = Rational32::from;
+/// // heading straight at the unit circle: t = 1/2 − √36/36 = 1/3
+/// let t = circle_time_of_impact(&r(3, 0), &r(-3, 0), &r(0, 0), r32(1)).unwrap();
+/// assert_eq!((t.a, t.b, t.d), (Rational32::new(1, 2), Rational32::new(-1, 36), r32(36)));
+/// // crossing off-center: t = 1/2 − √32/16 = 1/2 − √2/4
+/// let t = circle_time_of_impact(&r(-2, 1), &r(2, 1), &r(0, 0), r32(3)).unwrap();
+/// assert_eq!((t.a, t.b, t.d), (Rational32::new(1, 2), Rational32::new(-1, 16), r32(32)));
+/// assert!(circle_time_of_impact(&r(-2, 2), &r(2, 2), &r(0, 0), r32(2)).is_none());
+/// ```
+pub fn circle_time_of_impact<T>(
+    a: &Point2D<T>,
+    b: &Point2D<T>,
+    center: &Point2D<T>,
+    q: T,
+) -> Option<QuadExt<T>>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>,
+{
+    let (zero, one) = (T::from(0), T::from(1));
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let (wx, wy) = (a.x - center.x, a.y - center.y);
+    // q_d·t² + 2·h·t + c = 0
+    let q_d = dx * dx + dy * dy;
+    let h = wx * dx + wy * dy;
+    let c = wx * wx + wy * wy - q;
+    if q_d == zero {
+        return (c == zero).then_some(QuadExt::rational(zero, zero));
+    }
+    let disc = h * h - q_d * c;
+    if disc < zero {
+        return None;
+    }
+    let u = (zero - h) / q_d;
+    [zero - one / q_d, one / q_d]
+        .into_iter()
+        .map(|v| QuadExt::new(u, v, disc))
+        .find(|t| {
+            surd_sign(t.a, t.b, t.d) != Ordering::Less
+                && surd_sign(one - t.a, zero - t.b, t.d) != Ordering::Less
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+
+    type Q = Ratio<i32>;
+
+    fn r(x: i32, y: i32) -> Point2D<Q> {
+        Point2D::new(Q::from(x), Q::from(y))
+    }
+
+    #[test]
+    fn test_segment_time_of_impact() {
+        let (p, q) = (r(2, -1), r(2, 1));
+        // hitting an endpoint, at the last moment, and stopping short
+        assert_eq!(
+            segment_time_of_impact(&r(0, 1), &r(4, 1), &p, &q),
+            Some(Q::new(1, 2))
+        );
+        assert_eq!(
+            segment_time_of_impact(&r(0, 0), &r(2, 0), &p, &q),
+            Some(Q::from(1))
+        );
+        assert_eq!(segment_time_of_impact(&r(0, 0), &r(1, 0), &p, &q), None);
+        // sliding along the segment enters it at its nearer end
+        assert_eq!(
+            segment_time_of_impact(&r(2, -3), &r(2, 3), &p, &q),
+            Some(Q::new(1, 3))
+        );
+        assert_eq!(
+            segment_time_of_impact(&r(2, 0), &r(2, 5), &p, &q),
+            Some(Q::from(0))
+        );
+        assert_eq!(segment_time_of_impact(&r(2, 2), &r(2, 5), &p, &q), None);
+        assert_eq!(segment_time_of_impact(&r(3, -3), &r(3, 3), &p, &q), None);
+        // stationary points and point segments
+        assert_eq!(
+            segment_time_of_impact(&r(2, 0), &r(2, 0), &p, &q),
+            Some(Q::from(0))
+        );
+        assert_eq!(segment_time_of_impact(&r(2, 2), &r(2, 2), &p, &q), None);
+        assert_eq!(
+            segment_time_of_impact(&r(0, 0), &r(4, 4), &r(1, 1), &r(1, 1)),
+            Some(Q::new(1, 4))
+        );
+        assert_eq!(
+            segment_time_of_impact(&r(1, 1), &r(1, 1), &r(1, 1), &r(1, 1)),
+            Some(Q::from(0))
+        );
+    }
+
+    #[test]
+    fn test_surd_sign() {
+        assert_eq!(surd_sign(1, -1, 2), Ordering::Less);
+        assert_eq!(surd_sign(2, -1, 2), Ordering::Greater);
+        assert_eq!(surd_sign(-3, 2, 2), Ordering::Less);
+        assert_eq!(surd_sign(-2, 1, 4), Ordering::Equal);
+        assert_eq!(surd_sign(0, -1, 3), Ordering::Less);
+        assert_eq!(surd_sign(-1, 5, 0), Ordering::Less);
+        assert_eq!(surd_sign(0, 0, 7), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_circle_time_of_impact() {
+        let o = r(0, 0);
+        // from outside, the entry time; tangent motion touches once
+        let t = circle_time_of_impact(&r(-2, 0), &r(2, 0), &o, Q::from(1)).unwrap();
+        assert_eq!((t.a, t.b, t.d), (Q::new(1, 2), Q::new(-1, 16), Q::from(16)));
+        let t = circle_time_of_impact(&r(-2, 1), &r(2, 1), &o, Q::from(1)).unwrap();
+        assert_eq!((t.a, t.d), (Q::new(1, 2), Q::from(0)));
+        // from inside, the exit time
+        let t = circle_time_of_impact(&o, &r(2, 0), &o, Q::from(1)).unwrap();
+        assert_eq!((t.a, t.b, t.d), (Q::from(0), Q::new(1, 4), Q::from(4)));
+        // stopping short of the circle, and resting on it
+        assert!(circle_time_of_impact(&r(-3, 0), &r(-2, 0), &o, Q::from(1)).is_none());
+        assert!(circle_time_of_impact(&r(1, 0), &r(1, 0), &o, Q::from(1)).is_some());
+        assert!(circle_time_of_impact(&r(2, 0), &r(2, 0), &o, Q::from(1)).is_none());
+    }
+}
@@ -0,0 +1,342 @@
+//! `const fn` versions of the basic formulas for primitive types.
+//!
+//! The generic functions in [`trigonom`](crate::trigonom) go through trait
+//! bounds, which cannot be called in constant contexts. The functions here
+//! are monomorphic, so lookup tables and compile-time checks can use them:
+//!
+//! ```rust
+//! use rat_trig_rs::const_trigonom::{archimedes_i64, quadrance_i64};
+//! const Q: i64 = quadrance_i64((3, 4));
+//! const A: i64 = archimedes_i64(25, 9, 16);
+//! assert_eq!((Q, A), (25, 576));
+//! ```
+//!
+//! # Compile-time validation
+//!
+//! Since the predicates are `const fn`, hard-coded geometric data, such as
+//! the calibration triangles of a firmware image, can be checked when the
+//! crate is compiled, with `const _: () = assert!(...)` at item level or an
+//! inline `const { assert!(...) }` block:
+//!
+//! ```rust
+//! use rat_trig_rs::const_trigonom::{all_nondegenerate_i64, is_right_triangle_i64};
+//! const CALIBRATION: [[(i64, i64); 3]; 2] = [
+//!     [(0, 0), (4, 0), (0, 3)],
+//!     [(0, 0), (5, 0), (0, 12)],
+//! ];
+//! const _: () = assert!(all_nondegenerate_i64(&CALIBRATION));
+//!
+//! fn right_triangle_quadrances() -> (i64, i64, i64) {
+//!     const { assert!(is_right_triangle_i64(9, 16, 25)) };
+//!     (9, 16, 25)
+//! }
+//! # assert_eq!(right_triangle_quadrances().2, 25);
+//! ```
+//!
+//! A table that fails the check does not compile:
+//!
+//! ```compile_fail
+//! use rat_trig_rs::const_trigonom::all_nondegenerate_i64;
+//! const BAD: [[(i64, i64); 3]; 1] = [[(0, 0), (1, 1), (2, 2)]];
+//! const _: () = assert!(all_nondegenerate_i64(&BAD));
+//! ```
+//!
+//! The integer versions use plain arithmetic, so they overflow (a panic in
+//! debug builds and a compile error in constant evaluation) exactly where
+//! the same expression on the integer type would.
+
+/// The function `quadrance_i64` calculates the quadrance `x² + y²` of a
+/// vector.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::const_trigonom::quadrance_i64;
+/// assert_eq!(quadrance_i64((3, -4)), 25);
+/// ```
+#[inline]
+pub const fn quadrance_i64(v: (i64, i64)) -> i64 {
+    v.0 * v.0 + v.1 * v.1
+}
+
+/// The function `quadrance_i32` calculates the quadrance `x² + y²` of a
+/// vector.
+#[inline]
+pub const fn quadrance_i32(v: (i32, i32)) -> i32 {
+    v.0 * v.0 + v.1 * v.1
+}
+
+/// The function `quadrance_f64` calculates the quadrance `x² + y²` of a
+/// vector.
+#[inline]
+pub const fn quadrance_f64(v: (f64, f64)) -> f64 {
+    v.0 * v.0 + v.1 * v.1
+}
+
+/// The function `cross_i64` calculates the cross product `u.x·v.y − u.y·v.x`
+/// of two plane vectors (not the rational trigonometry *cross*, which is a
+/// ratio).
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::const_trigonom::cross_i64;
+/// assert_eq!(cross_i64((1, 0), (0, 1)), 1);
+/// assert_eq!(cross_i64((2, 4), (1, 2)), 0);
+/// ```
+#[inline]
+pub const fn cross_i64(u: (i64, i64), v: (i64, i64)) -> i64 {
+    u.0 * v.1 - u.1 * v.0
+}
+
+/// The function `archimedes_i64` calculates the quadrea of a triangle with
+/// quadrances `q_1`, `q_2` and `q_3` by Archimedes' formula,
+/// `4·q₁·q₂ − (q₁ + q₂ − q₃)²`.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::const_trigonom::archimedes_i64;
+/// assert_eq!(archimedes_i64(25, 9, 16), 576);
+/// assert_eq!(archimedes_i64(1, 4, 9), 0);
+/// ```
+#[inline]
+pub const fn archimedes_i64(q_1: i64, q_2: i64, q_3: i64) -> i64 {
+    let temp = q_1 + q_2 - q_3;
+    4 * q_1 * q_2 - temp * temp
+}
+
+/// The function `archimedes_i32` calculates the quadrea of a triangle with
+/// quadrances `q_1`, `q_2` and `q_3` by Archimedes' formula.
+#[inline]
+pub const fn archimedes_i32(q_1: i32, q_2: i32, q_3: i32) -> i32 {
+    let temp = q_1 + q_2 - q_3;
+    4 * q_1 * q_2 - temp * temp
+}
+
+/// The function `archimedes_f64` calculates the quadrea of a triangle with
+/// quadrances `q_1`, `q_2` and `q_3` by Archimedes' formula.
+#[inline]
+pub const fn archimedes_f64(q_1: f64, q_2: f64, q_3: f64) -> f64 {
+    let temp = q_1 + q_2 - q_3;
+    4.0 * q_1 * q_2 - temp * temp
+}
+
+/// The function `twist_i64` calculates the twist of the triangle `a, b, c`:
+/// twice its signed area, positive when the vertices are counterclockwise.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::const_trigonom::twist_i64;
+/// assert_eq!(twist_i64((0, 0), (4, 0), (0, 3)), 12);
+/// assert_eq!(twist_i64((0, 0), (0, 3), (4, 0)), -12);
+/// ```
+#[inline]
+pub const fn twist_i64(a: (i64, i64), b: (i64, i64), c: (i64, i64)) -> i64 {
+    cross_i64((b.0 - a.0, b.1 - a.1), (c.0 - a.0, c.1 - a.1))
+}
+
+/// The function `are_collinear_i64` checks whether three points lie on a
+/// common line.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::const_trigonom::are_collinear_i64;
+/// const FLAT: bool = are_collinear_i64((0, 0), (1, 2), (3, 6));
+/// assert!(FLAT);
+/// assert!(!are_collinear_i64((0, 0), (1, 2), (3, 5)));
+/// ```
+#[inline]
+pub const fn are_collinear_i64(a: (i64, i64), b: (i64, i64), c: (i64, i64)) -> bool {
+    twist_i64(a, b, c) == 0
+}
+
+/// The function `quadrance3d_i64` calculates the quadrance `x² + y² + z²` of
+/// a vector in space.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::const_trigonom::quadrance3d_i64;
+/// assert_eq!(quadrance3d_i64((1, 2, 2)), 9);
+/// ```
+#[inline]
+pub const fn quadrance3d_i64(v: (i64, i64, i64)) -> i64 {
+    v.0 * v.0 + v.1 * v.1 + v.2 * v.2
+}
+
+/// The function `cross3d_i64` calculates the cross product `u × v` of two
+/// vectors in space.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::const_trigonom::cross3d_i64;
+/// assert_eq!(cross3d_i64((1, 0, 0), (0, 1, 0)), (0, 0, 1));
+/// ```
+#[inline]
+pub const fn cross3d_i64(u: (i64, i64, i64), v: (i64, i64, i64)) -> (i64, i64, i64) {
+    (
+        u.1 * v.2 - u.2 * v.1,
+        u.2 * v.0 - u.0 * v.2,
+        u.0 * v.1 - u.1 * v.0,
+    )
+}
+
+/// The function `triangle_quadrances_i64` calculates the quadrances
+/// `(q₁, q₂, q₃)` of the sides opposite `a`, `b` and `c`.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::const_trigonom::triangle_quadrances_i64;
+/// assert_eq!(triangle_quadrances_i64((0, 0), (4, 0), (0, 3)), (25, 9, 16));
+/// ```
+#[inline]
+pub const fn triangle_quadrances_i64(
+    a: (i64, i64),
+    b: (i64, i64),
+    c: (i64, i64),
+) -> (i64, i64, i64) {
+    (
+        quadrance_i64((c.0 - b.0, c.1 - b.1)),
+        quadrance_i64((c.0 - a.0, c.1 - a.1)),
+        quadrance_i64((b.0 - a.0, b.1 - a.1)),
+    )
+}
+
+/// The function `is_right_triangle_i64` checks whether quadrances `q_1`,
+/// `q_2` and `q_3` form a nondegenerate right triangle, i.e. one of them is
+/// the sum of the other two (Pythagoras' theorem) and the quadrea is
+/// positive.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::const_trigonom::is_right_triangle_i64;
+/// assert!(is_right_triangle_i64(25, 9, 16));
+/// assert!(!is_right_triangle_i64(4, 5, 8));
+/// assert!(!is_right_triangle_i64(0, 1, 1));
+/// ```
+#[inline]
+pub const fn is_right_triangle_i64(q_1: i64, q_2: i64, q_3: i64) -> bool {
+    let pythagoras = q_1 == q_2 + q_3 || q_2 == q_1 + q_3 || q_3 == q_1 + q_2;
+    pythagoras && archimedes_i64(q_1, q_2, q_3) > 0
+}
+
+/// The function `is_nondegenerate_i64` checks whether the points `a`, `b`
+/// and `c` form a proper triangle.
+#[inline]
+pub const fn is_nondegenerate_i64(a: (i64, i64), b: (i64, i64), c: (i64, i64)) -> bool {
+    !are_collinear_i64(a, b, c)
+}
+
+/// The function `all_nondegenerate_i64` checks whether every triangle of a
+/// table, given by its vertices, is nondegenerate.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::const_trigonom::all_nondegenerate_i64;
+/// const TABLE: [[(i64, i64); 3]; 2] = [[(0, 0), (1, 0), (0, 1)], [(0, 0), (2, 1), (1, 2)]];
+/// const OK: bool = all_nondegenerate_i64(&TABLE);
+/// assert!(OK);
+/// ```
+pub const fn all_nondegenerate_i64<const N: usize>(table: &[[(i64, i64); 3]; N]) -> bool {
+    let mut i = 0;
+    while i < N {
+        let [a, b, c] = table[i];
+        if !is_nondegenerate_i64(a, b, c) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// The function `all_right_i64` checks whether every triangle of a table,
+/// given by its vertices, is a nondegenerate right triangle.
+pub const fn all_right_i64<const N: usize>(table: &[[(i64, i64); 3]; N]) -> bool {
+    let mut i = 0;
+    while i < N {
+        let [a, b, c] = table[i];
+        let (q_1, q_2, q_3) = triangle_quadrances_i64(a, b, c);
+        if !is_right_triangle_i64(q_1, q_2, q_3) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trigonom::{archimedes, quadrance, quadrance3d};
+
+    const TABLE: [i64; 4] = [
+        quadrance_i64((1, 0)),
+        quadrance_i64((1, 1)),
+        quadrance_i64((2, 1)),
+        quadrance_i64((2, 2)),
+    ];
+
+    #[test]
+    fn test_matches_generic() {
+        assert_eq!(TABLE, [1, 2, 5, 8]);
+        for (x, y) in [(3, 4), (-7, 2), (0, 0)] {
+            assert_eq!(quadrance_i64((x, y)), quadrance(&(x, y)));
+            assert_eq!(
+                quadrance_i32((x as i32, y as i32)),
+                quadrance(&(x as i32, y as i32))
+            );
+            assert_eq!(
+                quadrance_f64((x as f64, y as f64)),
+                quadrance(&(x as f64, y as f64))
+            );
+        }
+        assert_eq!(archimedes_i64(5, 8, 13), archimedes(&5, &8, &13));
+        assert_eq!(archimedes_i32(5, 8, 13), archimedes(&5, &8, &13));
+        assert_eq!(
+            archimedes_f64(0.5, 0.25, 1.0),
+            archimedes(&0.5, &0.25, &1.0)
+        );
+        assert_eq!(quadrance3d_i64((1, -2, 3)), quadrance3d(&(1, -2, 3)));
+    }
+
+    const RIGHT: [[(i64, i64); 3]; 3] = [
+        [(0, 0), (4, 0), (0, 3)],
+        [(1, 1), (4, 5), (-3, 4)],
+        [(0, 0), (1, 1), (2, 0)],
+    ];
+    const _: () = assert!(all_right_i64(&RIGHT));
+    const _: () = assert!(all_nondegenerate_i64(&RIGHT));
+
+    #[test]
+    fn test_table_checks() {
+        const { assert!(is_right_triangle_i64(2, 1, 1)) };
+        let mut table = RIGHT;
+        table[1][2] = (-2, 4);
+        assert!(all_nondegenerate_i64(&table));
+        assert!(!all_right_i64(&table));
+        table[2][2] = (2, 2);
+        assert!(!all_nondegenerate_i64(&table));
+        assert!(all_nondegenerate_i64::<0>(&[]));
+    }
+
+    #[test]
+    fn test_cross3d() {
+        let (u, v) = ((1, 2, 3), (4, 5, 6));
+        let w = cross3d_i64(u, v);
+        assert_eq!(w, (-3, 6, -3));
+        let dot = |a: (i64, i64, i64), b: (i64, i64, i64)| a.0 * b.0 + a.1 * b.1 + a.2 * b.2;
+        assert_eq!((dot(w, u), dot(w, v)), (0, 0));
+        // Lagrange's identity
+        assert_eq!(
+            quadrance3d_i64(w),
+            quadrance3d_i64(u) * quadrance3d_i64(v) - dot(u, v) * dot(u, v)
+        );
+    }
+}
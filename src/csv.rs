@@ -0,0 +1,491 @@
+//! CSV point import and bulk analysis.
+//!
+//! Each data row holds `x,y` or `x,y,z`. Blank lines and lines starting with
+//! `#` are skipped, and so is a first row that does not parse (a header).
+//! Coordinates are read like in the [`wkt`](crate::wkt) module: `T::from_str`
+//! first, then an exact decimal conversion, so `0.1` becomes `1/10` for
+//! rational types. Float data that should land on a rational grid can be read
+//! with [`parse_points2d_snapped`] instead.
+//!
+//! The analysis routines take the resulting buffers and compute hulls,
+//! pairwise quadrance statistics and triangle quality reports in one call.
+use crate::geometry::{Point2D, Point3D, Triangle2D};
+use crate::hull::convex_hull;
+use crate::trigonom::quadrance;
+use crate::wkt::parse_number;
+use core::convert::From;
+use core::fmt;
+use core::ops::{Add, Div, Mul, Sub};
+use core::str::FromStr;
+use std::path::Path;
+
+/// Errors raised while reading CSV point data.
+#[derive(Debug)]
+pub enum CsvError {
+    /// The file could not be read.
+    Io(std::io::Error),
+    /// A data row is malformed; `line` is 1-based.
+    Parse { line: usize },
+    /// The snapping denominator is not positive.
+    InvalidDenominator(i32),
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Io(err) => write!(f, "i/o error: {}", err),
+            CsvError::Parse { line } => write!(f, "malformed row on line {}", line),
+            CsvError::InvalidDenominator(den) => {
+                write!(f, "snapping denominator {} is not positive", den)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CsvError::Io(err) => Some(err),
+            CsvError::Parse { .. } | CsvError::InvalidDenominator(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for CsvError {
+    fn from(err: std::io::Error) -> Self {
+        CsvError::Io(err)
+    }
+}
+
+/// Splits `text` into rows of `N` fields and converts each with `parse`.
+fn parse_rows<R, const N: usize>(
+    text: &str,
+    mut parse: impl FnMut(&[&str; N]) -> Option<R>,
+) -> Result<Vec<R>, CsvError> {
+    let mut rows = Vec::new();
+    let mut first = true;
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let row = <&[&str; N]>::try_from(fields.as_slice())
+            .ok()
+            .and_then(&mut parse);
+        match row {
+            Some(row) => rows.push(row),
+            None if first => {} // header
+            None => return Err(CsvError::Parse { line: index + 1 }),
+        }
+        first = false;
+    }
+    Ok(rows)
+}
+
+/// The function `parse_points2d` reads `x,y` rows into points.
+///
+/// Returns:
+///
+/// The points in file order, or `CsvError::Parse` for the first malformed
+/// row after the (optional) header.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::csv::parse_points2d;
+/// use rat_trig_rs::geometry::Point2D;
+/// let points = parse_points2d::<Rational32>("x,y\n0.5, 1\n3/4,-2\n").unwrap();
+/// assert_eq!(points[0], Point2D::new(Rational32::new(1, 2), Rational32::from(1)));
+/// assert_eq!(points[1], Point2D::new(Rational32::new(3, 4), Rational32::from(-2)));
+/// ```
+pub fn parse_points2d<T>(text: &str) -> Result<Vec<Point2D<T>>, CsvError>
+where
+    T: Copy
+        + FromStr
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    parse_rows(text, |[x, y]: &[&str; 2]| {
+        Some(Point2D::new(parse_number(x).ok()?, parse_number(y).ok()?))
+    })
+}
+
+/// The function `parse_points3d` reads `x,y,z` rows into points.
+pub fn parse_points3d<T>(text: &str) -> Result<Vec<Point3D<T>>, CsvError>
+where
+    T: Copy
+        + FromStr
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    parse_rows(text, |[x, y, z]: &[&str; 3]| {
+        Some(Point3D::new(
+            parse_number(x).ok()?,
+            parse_number(y).ok()?,
+            parse_number(z).ok()?,
+        ))
+    })
+}
+
+/// The function `parse_points2d_snapped` reads `x,y` rows as floats and
+/// snaps each coordinate to the nearest multiple of `1/denominator`.
+///
+/// Arguments:
+///
+/// * `text`: The CSV text.
+/// * `denominator`: The grid resolution; must be positive.
+///
+/// Returns:
+///
+/// The snapped points, `CsvError::Parse` for a malformed row or a
+/// coordinate whose numerator does not fit in an `i32`, or
+/// `CsvError::InvalidDenominator` if `denominator` is not positive.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::csv::parse_points2d_snapped;
+/// use rat_trig_rs::geometry::Point2D;
+/// let points = parse_points2d_snapped::<Rational32>("0.3333,1.49\n", 6).unwrap();
+/// assert_eq!(points[0], Point2D::new(Rational32::new(1, 3), Rational32::new(3, 2)));
+/// ```
+pub fn parse_points2d_snapped<T>(text: &str, denominator: i32) -> Result<Vec<Point2D<T>>, CsvError>
+where
+    T: Div<Output = T> + From<i32>,
+{
+    if denominator <= 0 {
+        return Err(CsvError::InvalidDenominator(denominator));
+    }
+    let snap = |token: &str| -> Option<T> {
+        let scaled = (token.parse::<f64>().ok()? * f64::from(denominator)).round();
+        if !(f64::from(i32::MIN)..=f64::from(i32::MAX)).contains(&scaled) {
+            return None;
+        }
+        Some(T::from(scaled as i32) / T::from(denominator))
+    };
+    parse_rows(text, |[x, y]: &[&str; 2]| {
+        Some(Point2D::new(snap(x)?, snap(y)?))
+    })
+}
+
+/// The function `read_points2d` loads a CSV file of `x,y` rows.
+///
+/// See [`parse_points2d`] for the accepted format.
+pub fn read_points2d<T, P>(path: P) -> Result<Vec<Point2D<T>>, CsvError>
+where
+    T: Copy
+        + FromStr
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+    P: AsRef<Path>,
+{
+    parse_points2d(&std::fs::read_to_string(path)?)
+}
+
+/// The function `read_points3d` loads a CSV file of `x,y,z` rows.
+///
+/// See [`parse_points3d`] for the accepted format.
+pub fn read_points3d<T, P>(path: P) -> Result<Vec<Point3D<T>>, CsvError>
+where
+    T: Copy
+        + FromStr
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+    P: AsRef<Path>,
+{
+    parse_points3d(&std::fs::read_to_string(path)?)
+}
+
+/// Summary statistics of the pairwise quadrances of a point set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuadranceStats<T> {
+    /// The number of unordered pairs.
+    pub pairs: usize,
+    pub min: T,
+    pub max: T,
+    pub mean: T,
+}
+
+/// The function `quadrance_stats` computes the minimum, maximum and mean of
+/// the quadrances between all unordered pairs of points.
+///
+/// Returns:
+///
+/// `None` if there are fewer than two points, or if the number of pairs
+/// does not fit in an `i32` (more than about 65,500 points) and so cannot
+/// be converted to `T` to take the mean.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::csv::quadrance_stats;
+/// use rat_trig_rs::geometry::Point2D;
+/// let points = [(0, 0), (3, 0), (0, 4)].map(|(x, y)| Point2D::new(Rational32::from(x), Rational32::from(y)));
+/// let stats = quadrance_stats(&points).unwrap();
+/// assert_eq!(stats.pairs, 3);
+/// assert_eq!((stats.min, stats.max), (Rational32::from(9), Rational32::from(25)));
+/// assert_eq!(stats.mean, Rational32::new(50, 3));
+/// ```
+pub fn quadrance_stats<T>(points: &[Point2D<T>]) -> Option<QuadranceStats<T>>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>,
+{
+    let mut stats: Option<QuadranceStats<T>> = None;
+    for (i, a) in points.iter().enumerate() {
+        for b in &points[i + 1..] {
+            let q = quadrance(&(b.x - a.x, b.y - a.y));
+            stats = Some(match stats {
+                None => QuadranceStats {
+                    pairs: 1,
+                    min: q,
+                    max: q,
+                    mean: q,
+                },
+                Some(s) => QuadranceStats {
+                    pairs: s.pairs + 1,
+                    min: if q < s.min { q } else { s.min },
+                    max: if q > s.max { q } else { s.max },
+                    mean: s.mean + q, // the running sum until the end
+                },
+            });
+        }
+    }
+    stats.and_then(|s| {
+        let pairs = i32::try_from(s.pairs).ok()?;
+        Some(QuadranceStats {
+            mean: s.mean / T::from(pairs),
+            ..s
+        })
+    })
+}
+
+/// The quality report of a single triangle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TriangleQuality<T> {
+    /// The quadrances of the sides opposite each vertex.
+    pub quadrances: (T, T, T),
+    /// The quadrea (16 times the squared area).
+    pub quadrea: T,
+    /// The smallest of the three spreads, or `None` if two vertices
+    /// coincide. A small value flags a sliver triangle.
+    pub min_spread: Option<T>,
+}
+
+impl<T> TriangleQuality<T>
+where
+    T: PartialEq + From<i32>,
+{
+    /// Checks whether the vertices are collinear.
+    #[inline]
+    pub fn is_degenerate(&self) -> bool {
+        self.quadrea == T::from(0)
+    }
+}
+
+/// The function `triangle_quality` reports the quadrances, quadrea and
+/// smallest spread of a triangle.
+///
+/// The spreads follow from the quadrea `A` by the spread law,
+/// `s_1 = A / (4·q_2·q_3)` and cyclically.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::csv::triangle_quality;
+/// use rat_trig_rs::geometry::{Point2D, Triangle2D};
+/// let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+/// let report = triangle_quality(&Triangle2D::new(p(0, 0), p(4, 0), p(0, 3)));
+/// assert_eq!(report.quadrea, Rational32::from(576));
+/// assert_eq!(report.min_spread, Some(Rational32::new(9, 25)));
+/// ```
+pub fn triangle_quality<T>(tri: &Triangle2D<T>) -> TriangleQuality<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>,
+{
+    let (q_1, q_2, q_3) = tri.quadrances();
+    let quadrea = tri.area();
+    let zero = T::from(0);
+    let min_spread = if q_1 == zero || q_2 == zero || q_3 == zero {
+        None
+    } else {
+        let four = T::from(4);
+        [q_2 * q_3, q_1 * q_3, q_1 * q_2]
+            .into_iter()
+            .map(|denom| quadrea / (four * denom))
+            .reduce(|a, b| if b < a { b } else { a })
+    };
+    TriangleQuality {
+        quadrances: (q_1, q_2, q_3),
+        quadrea,
+        min_spread,
+    }
+}
+
+/// The function `triangle_reports` treats consecutive points as the
+/// vertices of triangles and reports on each; trailing points that do not
+/// make up a full triangle are ignored.
+pub fn triangle_reports<T>(points: &[Point2D<T>]) -> Vec<TriangleQuality<T>>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>,
+{
+    points
+        .chunks_exact(3)
+        .map(|c| triangle_quality(&Triangle2D::new(c[0], c[1], c[2])))
+        .collect()
+}
+
+/// The combined analysis of a point set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PointCloudReport<T> {
+    /// The number of points.
+    pub count: usize,
+    /// The convex hull, counterclockwise.
+    pub hull: Vec<Point2D<T>>,
+    /// The pairwise quadrance statistics.
+    pub quadrances: Option<QuadranceStats<T>>,
+}
+
+/// The function `analyze_points` computes the hull and pairwise quadrance
+/// statistics of a point set in one call.
+pub fn analyze_points<T>(points: &[Point2D<T>]) -> PointCloudReport<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>,
+{
+    PointCloudReport {
+        count: points.len(),
+        hull: convex_hull(points),
+        quadrances: quadrance_stats(points),
+    }
+}
+
+/// The function `analyze_csv` loads a CSV file of `x,y` rows and analyzes
+/// it with [`analyze_points`].
+pub fn analyze_csv<T, P>(path: P) -> Result<PointCloudReport<T>, CsvError>
+where
+    T: Copy
+        + FromStr
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>,
+    P: AsRef<Path>,
+{
+    Ok(analyze_points(&read_points2d::<T, P>(path)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+
+    type Q = Ratio<i32>;
+
+    #[test]
+    fn test_parse() {
+        let text = "# survey\nx, y, z\n1,2,3\n\n-1.5,0,2/3\n";
+        let points = parse_points3d::<Q>(text).unwrap();
+        assert_eq!(points.len(), 2);
+        assert_eq!(
+            points[1],
+            Point3D::new(Q::new(-3, 2), Q::from(0), Q::new(2, 3))
+        );
+
+        let points = parse_points2d::<i32>("3,4\n5,6\n").unwrap();
+        assert_eq!(points, vec![Point2D::new(3, 4), Point2D::new(5, 6)]);
+        assert!(matches!(
+            parse_points2d::<i32>("x,y\n1,2\n1.5,2\n"),
+            Err(CsvError::Parse { line: 3 })
+        ));
+        assert!(matches!(
+            parse_points2d::<i32>("1,2\n3,4,5\n"),
+            Err(CsvError::Parse { line: 2 })
+        ));
+        assert!(matches!(
+            parse_points2d_snapped::<Q>("0,0\n1e12,0\n", 2),
+            Err(CsvError::Parse { line: 2 })
+        ));
+        assert!(matches!(
+            parse_points2d_snapped::<Q>("0,0\n", 0),
+            Err(CsvError::InvalidDenominator(0))
+        ));
+        // long decimals are reduced before reaching `Ratio<i32>`
+        let points = parse_points2d::<Q>("37.77492950,-122.41941550\n").unwrap();
+        assert_eq!(points[0].x, Q::new(75549859, 2000000));
+        assert_eq!(points[0].y, Q::new(-244838831, 2000000));
+    }
+
+    #[test]
+    fn test_read_file() {
+        let path = std::env::temp_dir().join("rat_trig_rs_csv_test.csv");
+        std::fs::write(&path, "x,y\n0,0\n2,0\n2,2\n0,2\n1,1\n").unwrap();
+        let report = analyze_csv::<Q, _>(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(report.count, 5);
+        assert_eq!(report.hull.len(), 4);
+        let stats = report.quadrances.unwrap();
+        assert_eq!(stats.pairs, 10);
+        assert_eq!((stats.min, stats.max), (Q::from(2), Q::from(8)));
+        assert!(matches!(
+            read_points2d::<Q, _>("/nonexistent/points.csv"),
+            Err(CsvError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_triangle_reports() {
+        let points = parse_points2d::<Q>("0,0\n1,0\n0,1\n0,0\n1,1\n2,2\n5,5\n").unwrap();
+        let reports = triangle_reports(&points);
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].min_spread, Some(Q::new(1, 2)));
+        assert!(!reports[0].is_degenerate());
+        assert!(reports[1].is_degenerate());
+        assert_eq!(reports[1].min_spread, Some(Q::from(0)));
+    }
+}
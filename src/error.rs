@@ -0,0 +1,210 @@
+use core::fmt;
+
+/// Errors that can occur when evaluating rational trigonometry formulas.
+///
+/// Most functions in this crate are total over their numeric type, but a few
+/// of them need to divide (solving linear systems, inverting matrices, ...).
+/// Those functions return `Result<_, MathError>` instead of panicking or
+/// producing a meaningless value when the input is degenerate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MathError {
+    /// A division by zero would be required, e.g. the system is singular.
+    DivisionByZero,
+    /// The input does not satisfy the preconditions of the operation.
+    InvalidInput,
+    /// A vector has zero quadrance in the metric in use, so it cannot appear
+    /// where a spread or a normalization is required.
+    NullVector,
+    /// A line is null (its direction vector is null, or in projective
+    /// geometry it is tangent to the null conic), so it has no spread.
+    NullLine,
+    /// A projective point lies on the null conic, so it has no quadrance.
+    NullPoint,
+    /// The three points of a triangle are collinear (or coincide), so it has
+    /// no well-defined spreads, circumcenter, barycentric frame, ...
+    DegenerateTriangle,
+    /// A quadrance that must be nonnegative, e.g. in the Euclidean metric,
+    /// is negative.
+    NegativeQuadrance,
+    /// Two lines, circles or other objects that were expected to meet do
+    /// not.
+    NonIntersecting,
+    /// The exact result does not lie in the coefficient type, e.g. a
+    /// non-integer value requested as an integer.
+    NotRepresentable,
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MathError::DivisionByZero => write!(f, "division by zero"),
+            MathError::InvalidInput => write!(f, "invalid input"),
+            MathError::NullVector => write!(f, "null vector"),
+            MathError::NullLine => write!(f, "null line"),
+            MathError::NullPoint => write!(f, "null point"),
+            MathError::DegenerateTriangle => write!(f, "degenerate triangle"),
+            MathError::NegativeQuadrance => write!(f, "negative quadrance"),
+            MathError::NonIntersecting => write!(f, "objects do not intersect"),
+            MathError::NotRepresentable => write!(f, "result not representable"),
+        }
+    }
+}
+
+impl core::error::Error for MathError {}
+
+/// A [`MathError`] together with the operation that raised it and,
+/// optionally, the class of input that made it fail.
+///
+/// The low-level functions return a bare `MathError`, which is `Copy` and
+/// cheap to match on. Code that reports errors to people can attach context
+/// with [`ErrorContext::context`], so the message says e.g.
+/// `"spread (zero vector): null vector"` instead of just `"null vector"`.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::error::{ErrorContext, MathError, TrigResult};
+/// use rat_trig_rs::metric::BilinearForm2D;
+/// let result: TrigResult<i32> = BilinearForm2D::blue()
+///     .spread(&(1, 0), &(0, 0))
+///     .context_input("spread", "zero vector");
+/// let err = result.unwrap_err();
+/// assert_eq!(err.kind, MathError::NullVector);
+/// assert_eq!(err.to_string(), "spread (zero vector): null vector");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GeomError {
+    /// What went wrong.
+    pub kind: MathError,
+    /// The operation that failed, e.g. `"spread"` or `"inverse3"`.
+    pub operation: &'static str,
+    /// The class of input that caused the failure, e.g. `"collinear points"`.
+    pub input: Option<&'static str>,
+}
+
+impl GeomError {
+    /// Creates an error for `operation` without an input class.
+    #[inline]
+    pub const fn new(kind: MathError, operation: &'static str) -> Self {
+        GeomError {
+            kind,
+            operation,
+            input: None,
+        }
+    }
+
+    /// Sets the class of input that caused the failure.
+    #[inline]
+    pub const fn with_input(self, input: &'static str) -> Self {
+        GeomError {
+            input: Some(input),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for GeomError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.input {
+            Some(input) => write!(f, "{} ({}): {}", self.operation, input, self.kind),
+            None => write!(f, "{}: {}", self.operation, self.kind),
+        }
+    }
+}
+
+impl core::error::Error for GeomError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.kind)
+    }
+}
+
+impl From<GeomError> for MathError {
+    #[inline]
+    fn from(err: GeomError) -> Self {
+        err.kind
+    }
+}
+
+impl PartialEq<MathError> for GeomError {
+    #[inline]
+    fn eq(&self, other: &MathError) -> bool {
+        self.kind == *other
+    }
+}
+
+/// The result of an operation reporting a [`GeomError`].
+pub type TrigResult<T> = Result<T, GeomError>;
+
+/// Attaches context to a `Result<T, MathError>`.
+pub trait ErrorContext<T> {
+    /// Names the operation that failed.
+    fn context(self, operation: &'static str) -> TrigResult<T>;
+
+    /// Names the operation that failed and the class of input that caused
+    /// it.
+    fn context_input(self, operation: &'static str, input: &'static str) -> TrigResult<T>;
+}
+
+impl<T> ErrorContext<T> for Result<T, MathError> {
+    #[inline]
+    fn context(self, operation: &'static str) -> TrigResult<T> {
+        self.map_err(|kind| GeomError::new(kind, operation))
+    }
+
+    #[inline]
+    fn context_input(self, operation: &'static str, input: &'static str) -> TrigResult<T> {
+        self.map_err(|kind| GeomError::new(kind, operation).with_input(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(MathError::DivisionByZero.to_string(), "division by zero");
+        assert_eq!(MathError::InvalidInput.to_string(), "invalid input");
+        assert_eq!(MathError::NullVector.to_string(), "null vector");
+        assert_eq!(MathError::NullLine.to_string(), "null line");
+        assert_eq!(MathError::NullPoint.to_string(), "null point");
+        assert_eq!(
+            MathError::DegenerateTriangle.to_string(),
+            "degenerate triangle"
+        );
+        assert_eq!(
+            MathError::NotRepresentable.to_string(),
+            "result not representable"
+        );
+    }
+
+    #[test]
+    fn test_error_trait() {
+        let err: &dyn core::error::Error = &MathError::NonIntersecting;
+        assert_eq!(err.to_string(), "objects do not intersect");
+        assert!(err.source().is_none());
+    }
+
+    #[test]
+    fn test_geom_error() {
+        let err = GeomError::new(MathError::DivisionByZero, "inverse2");
+        assert_eq!(err.to_string(), "inverse2: division by zero");
+        assert_eq!(err, MathError::DivisionByZero);
+        let err = err.with_input("singular matrix");
+        assert_eq!(
+            err.to_string(),
+            "inverse2 (singular matrix): division by zero"
+        );
+        let source = core::error::Error::source(&err).unwrap();
+        assert_eq!(source.to_string(), "division by zero");
+
+        fn bubble() -> Result<(), MathError> {
+            Err(GeomError::new(MathError::NullLine, "line_spread"))?;
+            Ok(())
+        }
+        assert_eq!(bubble(), Err(MathError::NullLine));
+
+        let ok: Result<i32, MathError> = Ok(3);
+        assert_eq!(ok.context("anything"), Ok(3));
+    }
+}
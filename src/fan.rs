@@ -0,0 +1,151 @@
+//! Fans of evenly spread rational directions.
+//!
+//! Rotating a direction by `2π/k` leaves the rationals for most `k`. A
+//! rational fan instead picks, for each target angle `θⱼ = 2πj/k`, the
+//! exact rational point of the unit circle
+//!
+//! ```text
+//! ((n² − m²) / (n² + m²), 2mn / (n² + m²))
+//! ```
+//!
+//! whose integer parameter `(m, n)` is closest to
+//! `height·(sin(θⱼ/2), cos(θⱼ/2))`. The directions are exact, so spreads
+//! and quadrances computed from them are exact too; only their placement
+//! is approximate, by a bound reported with the fan.
+use crate::geometry::Vector2D;
+use core::ops::Div;
+use std::f64::consts::PI;
+use std::vec::Vec;
+
+/// The largest supported height; it keeps the denominators `n² + m²` within
+/// an `i32`.
+pub const MAX_HEIGHT: i32 = 32767;
+
+/// The result of [`rational_fan`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RationalFan<T> {
+    /// Unit directions, counterclockwise from `(1, 0)`.
+    pub directions: Vec<Vector2D<T>>,
+    /// The largest angle, in radians, between a direction and its target.
+    ///
+    /// Each consecutive spread differs from `sin²(2π/k)` by at most twice
+    /// this value.
+    pub max_angle_error: f64,
+}
+
+/// The function `rational_fan` generates `k` exact unit directions at
+/// approximately equal angles.
+///
+/// Arguments:
+///
+/// * `k`: The number of directions.
+/// * `height`: The bound on the integer parameters; a larger height gives a
+///   finer approximation (the angle error is at most about `1/height`) at
+///   the cost of larger denominators, up to `2·height²`.
+///
+/// Returns:
+///
+/// The directions and the angle error bound. Panics unless
+/// `1 <= height <= MAX_HEIGHT`.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::fan::rational_fan;
+/// let fan = rational_fan::<Rational32>(5, 100);
+/// assert_eq!(fan.directions.len(), 5);
+/// let target = (2.0 * std::f64::consts::PI / 5.0).sin().powi(2);
+/// for (u, v) in fan.directions.iter().zip(fan.directions.iter().cycle().skip(1)) {
+///     // the directions are unit vectors, so the spread is the squared cross
+///     let cross = u.x * v.y - u.y * v.x;
+///     let s = (*cross.numer() as f64 / *cross.denom() as f64).powi(2);
+///     assert!((s - target).abs() <= 2.0 * fan.max_angle_error);
+/// }
+/// ```
+pub fn rational_fan<T>(k: usize, height: i32) -> RationalFan<T>
+where
+    T: Div<Output = T> + From<i32>,
+{
+    assert!(
+        (1..=MAX_HEIGHT).contains(&height),
+        "the height must lie in 1..={}",
+        MAX_HEIGHT
+    );
+    let h = f64::from(height);
+    let mut max_angle_error: f64 = 0.0;
+    let directions = (0..k)
+        .map(|j| {
+            let theta = 2.0 * PI * j as f64 / k as f64;
+            let half = theta / 2.0;
+            let (m, n) = ((h * half.sin()).round(), (h * half.cos()).round());
+            // (m, n) = (0, 0) only for tiny heights; fall back to the nearest axis
+            let (m, n) = if (m, n) == (0.0, 0.0) {
+                if half.sin().abs() > half.cos().abs() {
+                    (half.sin().signum(), 0.0)
+                } else {
+                    (0.0, half.cos().signum())
+                }
+            } else {
+                (m, n)
+            };
+            let actual = 2.0 * m.atan2(n);
+            let diff = (actual - theta).rem_euclid(2.0 * PI);
+            max_angle_error = max_angle_error.max(diff.min(2.0 * PI - diff));
+            let (m, n) = (m as i32, n as i32);
+            let denom = n * n + m * m;
+            Vector2D::new(
+                T::from(n * n - m * m) / T::from(denom),
+                T::from(2 * m * n) / T::from(denom),
+            )
+        })
+        .collect();
+    RationalFan {
+        directions,
+        max_angle_error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+
+    type Q = Ratio<i32>;
+
+    #[test]
+    fn test_exact_cases() {
+        // quarter turns are exact for any height
+        let fan = rational_fan::<Q>(4, 1);
+        let axes =
+            [(1, 0), (0, 1), (-1, 0), (0, -1)].map(|(x, y)| Vector2D::new(Q::from(x), Q::from(y)));
+        assert_eq!(fan.directions, axes);
+        assert!(fan.max_angle_error < 1e-12);
+    }
+
+    #[test]
+    fn test_unit_and_bounded() {
+        // squaring denominators near 2·height² needs more than an i32
+        let widen = |r: Q| Ratio::new(i64::from(*r.numer()), i64::from(*r.denom()));
+        for k in [3, 7, 12, 360] {
+            for height in [1, 10, 1000, MAX_HEIGHT] {
+                let fan = rational_fan::<Q>(k, height);
+                assert_eq!(fan.directions.len(), k);
+                for v in &fan.directions {
+                    let (x, y) = (widen(v.x), widen(v.y));
+                    assert_eq!(x * x + y * y, Ratio::from(1));
+                }
+                if height >= 10 {
+                    assert!(fan.max_angle_error <= 2.0 / height as f64);
+                }
+            }
+        }
+        assert!(rational_fan::<Q>(0, 5).directions.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "the height must lie in")]
+    fn test_height_bounds() {
+        rational_fan::<Q>(3, 0);
+    }
+}
@@ -0,0 +1,2909 @@
+//! Structured geometry types.
+//!
+//! The free functions in [`crate::trigonom`] work on bare scalars. The types
+//! in this module give names to the objects those scalars describe, so that
+//! transformations and constructions can be expressed directly on points and
+//! vectors.
+use crate::error::{GeomError, MathError, TrigResult};
+use crate::logging::{degenerate, rejected, targets, Loggable};
+use crate::traits::AffineOps;
+use crate::trigonom::{
+    archimedes, line_from_points, line_intersection, midpoint, perpendicular_bisector, quadrance,
+    quadrance3d, quadrume,
+};
+use core::convert::From;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign};
+use core::str::FromStr;
+
+/// A point in the plane with coordinates of type `T`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[repr(C)]
+pub struct Point2D<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2D<T> {
+    /// Creates a new point from its coordinates.
+    #[inline]
+    pub const fn new(x: T, y: T) -> Self {
+        Point2D { x, y }
+    }
+}
+
+impl<T> Point2D<T>
+where
+    T: Copy + Add<Output = T> + Div<Output = T> + From<i32>,
+{
+    /// The midpoint of `self` and `other`; see [`midpoint`]. For the general
+    /// `self + t·(other − self)`, use [`AffineOps::affine_combination`].
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::Point2D;
+    /// let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+    /// assert_eq!(p(0, 0).midpoint(&p(1, 4)), Point2D::new(Rational32::new(1, 2), Rational32::from(2)));
+    /// ```
+    #[inline]
+    pub fn midpoint(&self, other: &Self) -> Self {
+        let (x, y) = midpoint(&(self.x, self.y), &(other.x, other.y));
+        Point2D::new(x, y)
+    }
+}
+
+/// A displacement vector in the plane with components of type `T`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[repr(C)]
+pub struct Vector2D<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vector2D<T> {
+    /// Creates a new vector from its components.
+    #[inline]
+    pub const fn new(x: T, y: T) -> Self {
+        Vector2D { x, y }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vector2D<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Vector2D::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vector2D<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Vector2D::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Vector2D<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Vector2D::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Copy + Div<Output = T>> Div<T> for Vector2D<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Vector2D::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vector2D<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Vector2D::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Copy + Add<Output = T>> AddAssign for Vector2D<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.x = self.x + rhs.x;
+        self.y = self.y + rhs.y;
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> SubAssign for Vector2D<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x = self.x - rhs.x;
+        self.y = self.y - rhs.y;
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2D<T> {
+    type Output = Vector2D<T>;
+
+    /// The displacement from `rhs` to `self`.
+    #[inline]
+    fn sub(self, rhs: Self) -> Vector2D<T> {
+        Vector2D::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add<Vector2D<T>> for Point2D<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Vector2D<T>) -> Self {
+        Point2D::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub<Vector2D<T>> for Point2D<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Vector2D<T>) -> Self {
+        Point2D::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T> Vector2D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// The dot product `x₁x₂ + y₁y₂`.
+    #[inline]
+    pub fn dot(&self, other: &Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// The cross product `x₁y₂ − y₁x₂`, positive when `other` is
+    /// counterclockwise from `self`.
+    #[inline]
+    pub fn cross(&self, other: &Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// The quadrance `x² + y²`.
+    #[inline]
+    pub fn quadrance(&self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T> Vector2D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// The spread `(u × v)² / (Q(u)·Q(v))` between `self` and `other`.
+    ///
+    /// Returns `MathError::NullVector` if either vector is zero.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::Vector2D;
+    /// let v = |x: i32, y: i32| Vector2D::new(Rational32::from(x), Rational32::from(y));
+    /// assert_eq!(v(4, 0).spread_to(&v(4, 3)), Ok(Rational32::new(9, 25)));
+    /// assert!(v(0, 0).spread_to(&v(1, 1)).is_err());
+    /// ```
+    pub fn spread_to(&self, other: &Self) -> Result<T, MathError>
+    where
+        T: Loggable,
+    {
+        let q = self.quadrance() * other.quadrance();
+        if q == T::from(0) {
+            return Err(degenerate(
+                targets::GEOMETRY,
+                "Vector2D::spread_to",
+                MathError::NullVector,
+                &(self, other),
+            ));
+        }
+        let cross = self.cross(other);
+        Ok(cross * cross / q)
+    }
+}
+
+/// The line `a·x + b·y + c = 0` in the plane, given by its coefficients.
+///
+/// Proportional coefficient triples describe the same line; the derived
+/// `PartialEq` compares coefficients, not lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[repr(C)]
+pub struct Line2D<T> {
+    pub a: T,
+    pub b: T,
+    pub c: T,
+}
+
+impl<T> Line2D<T> {
+    /// Creates a new line from its coefficients.
+    #[inline]
+    pub const fn new(a: T, b: T, c: T) -> Self {
+        Line2D { a, b, c }
+    }
+}
+
+impl<T> Line2D<T>
+where
+    T: Copy + Sub<Output = T> + Mul<Output = T> + PartialEq,
+{
+    /// Creates the line through two points, oriented from `p1` to `p2`;
+    /// see [`line_from_points`].
+    ///
+    /// Returns `MathError::InvalidInput` if the points coincide.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::error::MathError;
+    /// use rat_trig_rs::geometry::{Line2D, Point2D};
+    /// let l = Line2D::from_points(&Point2D::new(0, 1), &Point2D::new(2, 2));
+    /// assert_eq!(l, Ok(Line2D::new(-1, 2, -2)));
+    /// let p = Point2D::new(1, 1);
+    /// assert_eq!(Line2D::from_points(&p, &p), Err(MathError::InvalidInput));
+    /// ```
+    #[inline]
+    pub fn from_points(p1: &Point2D<T>, p2: &Point2D<T>) -> Result<Self, MathError>
+    where
+        T: Loggable,
+    {
+        line_from_points(&(p1.x, p1.y), &(p2.x, p2.y)).map(|(a, b, c)| Line2D::new(a, b, c))
+    }
+}
+
+impl<T> Line2D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq,
+{
+    /// Creates the perpendicular bisector of `p1` and `p2`, with `p2` on its
+    /// positive side; see [`perpendicular_bisector`].
+    ///
+    /// Returns `MathError::InvalidInput` if the points coincide.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::{Line2D, Point2D};
+    /// let l = Line2D::perpendicular_bisector(&Point2D::new(0, 0), &Point2D::new(0, 4));
+    /// assert_eq!(l, Ok(Line2D::new(0, 8, -16)));
+    /// ```
+    #[inline]
+    pub fn perpendicular_bisector(p1: &Point2D<T>, p2: &Point2D<T>) -> Result<Self, MathError>
+    where
+        T: Loggable,
+    {
+        perpendicular_bisector(&(p1.x, p1.y), &(p2.x, p2.y)).map(|(a, b, c)| Line2D::new(a, b, c))
+    }
+}
+
+impl<T> Line2D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<i32>,
+{
+    /// The direction vector `(b, −a)`, pointing from `p1` to `p2` for a line
+    /// built by [`from_points`](Line2D::from_points).
+    #[inline]
+    pub fn direction(&self) -> Vector2D<T> {
+        Vector2D::new(self.b, T::from(0) - self.a)
+    }
+
+    /// The normal vector `(a, b)`, pointing to the side where
+    /// `a·x + b·y + c > 0`.
+    #[inline]
+    pub fn normal(&self) -> Vector2D<T> {
+        Vector2D::new(self.a, self.b)
+    }
+
+    /// The line through `p` parallel to `self`, with the same normal.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::{Line2D, Point2D};
+    /// let l = Line2D::new(1, 2, -4);
+    /// assert_eq!(l.parallel_through(&Point2D::new(3, 1)), Line2D::new(1, 2, -5));
+    /// ```
+    #[inline]
+    pub fn parallel_through(&self, p: &Point2D<T>) -> Self {
+        Line2D::new(self.a, self.b, T::from(0) - (self.a * p.x + self.b * p.y))
+    }
+
+    /// The line through `p` perpendicular to `self`, whose normal is the
+    /// direction of `self`.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::{Line2D, Point2D};
+    /// let l = Line2D::new(1, 2, -4);
+    /// assert_eq!(l.perpendicular_through(&Point2D::new(3, 1)), Line2D::new(2, -1, -5));
+    /// ```
+    #[inline]
+    pub fn perpendicular_through(&self, p: &Point2D<T>) -> Self {
+        let d = self.direction();
+        Line2D::new(d.x, d.y, T::from(0) - (d.x * p.x + d.y * p.y))
+    }
+}
+
+impl<T> Line2D<T>
+where
+    T: Copy + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    /// Checks whether `self` and `other` are the same line, i.e. whether
+    /// their coefficients are proportional. The factor may be negative, so
+    /// orientation is ignored.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::Line2D;
+    /// assert!(Line2D::new(1, -2, 3).equivalent(&Line2D::new(-2, 4, -6)));
+    /// assert!(!Line2D::new(1, -2, 3).equivalent(&Line2D::new(1, -2, 4)));
+    /// ```
+    pub fn equivalent(&self, other: &Self) -> bool {
+        self.a * other.b == self.b * other.a
+            && self.a * other.c == self.c * other.a
+            && self.b * other.c == self.c * other.b
+    }
+}
+
+impl<T> Line2D<T>
+where
+    T: Copy + Sub<Output = T> + Div<Output = T> + Rem<Output = T> + PartialOrd + From<i32>,
+{
+    /// The coefficients divided by their greatest common divisor, with the
+    /// sign fixed so that the first nonzero of `a` and `b` is positive.
+    /// Equivalent lines have equal normalized forms, so these work as hash
+    /// keys.
+    ///
+    /// Meant for integers and rationals, where Euclid's algorithm gives the
+    /// gcd exactly; a rational line gets coprime integer coefficients. The
+    /// all-zero triple is returned unchanged.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::{Line2D, Point2D};
+    /// let (p, q, r) = (Point2D::new(0, 1), Point2D::new(2, 2), Point2D::new(-4, -1));
+    /// let l_1 = Line2D::from_points(&p, &q).unwrap();
+    /// let l_2 = Line2D::from_points(&q, &r).unwrap();
+    /// assert_eq!(l_2, Line2D::new(3, -6, 6));
+    /// assert_eq!(l_1.normalized(), Line2D::new(1, -2, 2));
+    /// assert_eq!(l_2.normalized(), Line2D::new(1, -2, 2));
+    /// ```
+    pub fn normalized(&self) -> Self {
+        let zero = T::from(0);
+        let mut g = gcd(gcd(self.a, self.b), self.c);
+        if g == zero {
+            return *self;
+        }
+        let lead = if self.a != zero {
+            self.a
+        } else if self.b != zero {
+            self.b
+        } else {
+            self.c
+        };
+        if (lead < zero) != (g < zero) {
+            g = zero - g;
+        }
+        Line2D::new(self.a / g, self.b / g, self.c / g)
+    }
+}
+
+/// A point in space with coordinates of type `T`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[repr(C)]
+pub struct Point3D<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3D<T> {
+    /// Creates a new point from its coordinates.
+    #[inline]
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3D { x, y, z }
+    }
+}
+
+/// A displacement vector in space with components of type `T`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[repr(C)]
+pub struct Vector3D<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vector3D<T> {
+    /// Creates a new vector from its components.
+    #[inline]
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Vector3D { x, y, z }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vector3D<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Vector3D::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vector3D<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Vector3D::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Vector3D<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Vector3D::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: Copy + Div<Output = T>> Div<T> for Vector3D<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Vector3D::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vector3D<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Vector3D::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Copy + Add<Output = T>> AddAssign for Vector3D<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.x = self.x + rhs.x;
+        self.y = self.y + rhs.y;
+        self.z = self.z + rhs.z;
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> SubAssign for Vector3D<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x = self.x - rhs.x;
+        self.y = self.y - rhs.y;
+        self.z = self.z - rhs.z;
+    }
+}
+
+/// Bridges to the tuple API of [`trigonom`](crate::trigonom), e.g.
+/// [`quadrance3d`].
+///
+/// ```rust
+/// use rat_trig_rs::geometry::Vector3D;
+/// use rat_trig_rs::trigonom::quadrance3d;
+/// let v = Vector3D::from((1, 2, 2));
+/// assert_eq!(quadrance3d(&v.into()), v.quadrance());
+/// ```
+impl<T> From<(T, T, T)> for Vector3D<T> {
+    #[inline]
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Vector3D::new(x, y, z)
+    }
+}
+
+impl<T> From<Vector3D<T>> for (T, T, T) {
+    #[inline]
+    fn from(v: Vector3D<T>) -> Self {
+        (v.x, v.y, v.z)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3D<T> {
+    type Output = Vector3D<T>;
+
+    /// The displacement from `rhs` to `self`.
+    #[inline]
+    fn sub(self, rhs: Self) -> Vector3D<T> {
+        Vector3D::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Add<Output = T>> Add<Vector3D<T>> for Point3D<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Vector3D<T>) -> Self {
+        Point3D::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub<Vector3D<T>> for Point3D<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Vector3D<T>) -> Self {
+        Point3D::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T> Vector3D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// The dot product `x₁x₂ + y₁y₂ + z₁z₂`.
+    #[inline]
+    pub fn dot(&self, other: &Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// The cross product, perpendicular to both vectors.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::Vector3D;
+    /// let (e_1, e_2) = (Vector3D::new(1, 0, 0), Vector3D::new(0, 1, 0));
+    /// assert_eq!(e_1.cross(&e_2), Vector3D::new(0, 0, 1));
+    /// ```
+    #[inline]
+    pub fn cross(&self, other: &Self) -> Self {
+        Vector3D::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// The quadrance `x² + y² + z²`.
+    #[inline]
+    pub fn quadrance(&self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T> Vector3D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// The spread `Q(u × v) / (Q(u)·Q(v))` between `self` and `other`.
+    ///
+    /// Returns `MathError::NullVector` if either vector is zero.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::Vector3D;
+    /// let v = |x: i32, y: i32, z: i32| Vector3D::new(Rational32::from(x), Rational32::from(y), Rational32::from(z));
+    /// assert_eq!(v(1, 0, 0).spread_to(&v(1, 1, 1)), Ok(Rational32::new(2, 3)));
+    /// ```
+    pub fn spread_to(&self, other: &Self) -> Result<T, MathError>
+    where
+        T: Loggable,
+    {
+        let q = self.quadrance() * other.quadrance();
+        if q == T::from(0) {
+            return Err(degenerate(
+                targets::GEOMETRY,
+                "Vector3D::spread_to",
+                MathError::NullVector,
+                &(self, other),
+            ));
+        }
+        Ok(self.cross(other).quadrance() / q)
+    }
+}
+
+/// The plane `a·x + b·y + c·z + d = 0` in space, given by its coefficients.
+///
+/// As with [`Line2D`], proportional coefficients describe the same plane;
+/// the derived `PartialEq` compares coefficients, not planes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[repr(C)]
+pub struct Plane3D<T> {
+    pub a: T,
+    pub b: T,
+    pub c: T,
+    pub d: T,
+}
+
+impl<T> Plane3D<T> {
+    /// Creates a new plane from its coefficients.
+    #[inline]
+    pub const fn new(a: T, b: T, c: T, d: T) -> Self {
+        Plane3D { a, b, c, d }
+    }
+}
+
+impl<T> Plane3D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    /// Creates the plane through three points, with normal
+    /// `(p2 − p1) × (p3 − p1)`.
+    ///
+    /// Returns `MathError::InvalidInput` if the points are collinear.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::error::MathError;
+    /// use rat_trig_rs::geometry::{Plane3D, Point3D};
+    /// let (p1, p2, p3) = (Point3D::new(1, 0, 0), Point3D::new(0, 1, 0), Point3D::new(0, 0, 1));
+    /// assert_eq!(Plane3D::from_three_points(&p1, &p2, &p3), Ok(Plane3D::new(1, 1, 1, -1)));
+    /// let p4 = Point3D::new(2, -1, 0);
+    /// assert_eq!(Plane3D::from_three_points(&p1, &p2, &p4), Err(MathError::InvalidInput));
+    /// ```
+    pub fn from_three_points(
+        p1: &Point3D<T>,
+        p2: &Point3D<T>,
+        p3: &Point3D<T>,
+    ) -> Result<Self, MathError>
+    where
+        T: Loggable,
+    {
+        let n = (*p2 - *p1).cross(&(*p3 - *p1));
+        if is_zero3(&n) {
+            return Err(degenerate(
+                targets::GEOMETRY,
+                "Plane3D::from_three_points",
+                MathError::InvalidInput,
+                &(p1, p2, p3),
+            ));
+        }
+        let d = T::from(0) - n.dot(&Vector3D::new(p1.x, p1.y, p1.z));
+        Ok(Plane3D::new(n.x, n.y, n.z, d))
+    }
+
+    /// The normal vector `(a, b, c)`.
+    #[inline]
+    pub fn normal(&self) -> Vector3D<T> {
+        Vector3D::new(self.a, self.b, self.c)
+    }
+
+    /// The value `a·x + b·y + c·z + d` at `p`; zero exactly on the plane.
+    #[inline]
+    pub fn evaluate(&self, p: &Point3D<T>) -> T {
+        self.a * p.x + self.b * p.y + self.c * p.z + self.d
+    }
+
+    /// Whether `p` lies on the plane.
+    #[inline]
+    pub fn contains_point(&self, p: &Point3D<T>) -> bool {
+        self.evaluate(p) == T::from(0)
+    }
+}
+
+impl<T> Plane3D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// The quadrance from `p` to its foot on the plane,
+    /// `(a·x + b·y + c·z + d)² / (a² + b² + c²)`; the 3D analogue of
+    /// [`quadrance_to_line`](crate::traits::RationalTrig::quadrance_to_line).
+    ///
+    /// Returns `MathError::NullVector` if the normal `(a, b, c)` is zero.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::{Plane3D, Point3D};
+    /// let r = Rational32::from;
+    /// let plane = Plane3D::new(r(1), r(1), r(1), r(-1));
+    /// let q = plane.quadrance_from_point(&Point3D::new(r(0), r(0), r(0)));
+    /// assert_eq!(q, Ok(Rational32::new(1, 3)));
+    /// ```
+    pub fn quadrance_from_point(&self, p: &Point3D<T>) -> Result<T, MathError>
+    where
+        T: Loggable,
+    {
+        let norm = self.normal().quadrance();
+        if norm == T::from(0) {
+            return Err(degenerate(
+                targets::GEOMETRY,
+                "Plane3D::quadrance_from_point",
+                MathError::NullVector,
+                self,
+            ));
+        }
+        let value = self.evaluate(p);
+        Ok(value * value / norm)
+    }
+
+    /// The foot of the perpendicular from `p` to the plane,
+    /// `p − n·(a·x + b·y + c·z + d) / Q(n)` for the normal `n`.
+    ///
+    /// Returns `MathError::NullVector` if the normal `(a, b, c)` is zero.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::{Plane3D, Point3D};
+    /// let r = Rational32::from;
+    /// let plane = Plane3D::new(r(1), r(1), r(1), r(-1));
+    /// let third = Rational32::new(1, 3);
+    /// let foot = plane.project_point(&Point3D::new(r(0), r(0), r(0)));
+    /// assert_eq!(foot, Ok(Point3D::new(third, third, third)));
+    /// ```
+    pub fn project_point(&self, p: &Point3D<T>) -> Result<Point3D<T>, MathError>
+    where
+        T: Loggable,
+    {
+        let norm = self.normal().quadrance();
+        if norm == T::from(0) {
+            return Err(degenerate(
+                targets::GEOMETRY,
+                "Plane3D::project_point",
+                MathError::NullVector,
+                self,
+            ));
+        }
+        Ok(*p - self.normal() * (self.evaluate(p) / norm))
+    }
+}
+
+/// A line in space in Plücker coordinates: a direction `d` and the moment
+/// `m = p × d` about the origin, for any point `p` on the line.
+///
+/// Scaling both vectors by the same nonzero factor gives the same line; the
+/// derived `PartialEq` compares coordinates, not lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[repr(C)]
+pub struct Line3D<T> {
+    pub direction: Vector3D<T>,
+    pub moment: Vector3D<T>,
+}
+
+impl<T> Line3D<T> {
+    /// Creates a new line from its Plücker coordinates.
+    #[inline]
+    pub const fn new(direction: Vector3D<T>, moment: Vector3D<T>) -> Self {
+        Line3D { direction, moment }
+    }
+}
+
+impl<T> Line3D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    /// Creates the line through two points, directed from `p1` to `p2`.
+    ///
+    /// Returns `MathError::InvalidInput` if the points coincide.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::{Line3D, Point3D, Vector3D};
+    /// let l = Line3D::from_points(&Point3D::new(0, 1, 0), &Point3D::new(1, 1, 0)).unwrap();
+    /// assert_eq!(l, Line3D::new(Vector3D::new(1, 0, 0), Vector3D::new(0, 0, -1)));
+    /// ```
+    pub fn from_points(p1: &Point3D<T>, p2: &Point3D<T>) -> Result<Self, MathError>
+    where
+        T: Loggable,
+    {
+        let d = *p2 - *p1;
+        if is_zero3(&d) {
+            return Err(degenerate(
+                targets::GEOMETRY,
+                "Line3D::from_points",
+                MathError::InvalidInput,
+                &(p1, p2),
+            ));
+        }
+        Ok(Line3D::new(d, Vector3D::new(p1.x, p1.y, p1.z).cross(&d)))
+    }
+
+    /// The reciprocal product `d₁·m₂ + d₂·m₁`, which vanishes exactly when
+    /// the two lines are coplanar.
+    #[inline]
+    pub fn reciprocal_product(&self, other: &Self) -> T {
+        self.direction.dot(&other.moment) + other.direction.dot(&self.moment)
+    }
+
+    /// Whether `self` and `other` lie in a common plane, i.e. meet or are
+    /// parallel.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::{Line3D, Point3D};
+    /// let l = |p: (i32, i32, i32), q: (i32, i32, i32)| {
+    ///     Line3D::from_points(&Point3D::new(p.0, p.1, p.2), &Point3D::new(q.0, q.1, q.2)).unwrap()
+    /// };
+    /// let x_axis = l((0, 0, 0), (1, 0, 0));
+    /// assert!(x_axis.are_coplanar(&l((0, 0, 0), (0, 1, 0))));
+    /// assert!(!x_axis.are_coplanar(&l((0, 0, 1), (0, 1, 1))));
+    /// ```
+    #[inline]
+    pub fn are_coplanar(&self, other: &Self) -> bool {
+        self.reciprocal_product(other) == T::from(0)
+    }
+
+    /// Whether `self` and `other` have parallel directions.
+    #[inline]
+    pub fn is_parallel(&self, other: &Self) -> bool {
+        is_zero3(&self.direction.cross(&other.direction))
+    }
+
+    /// Whether `p` lies on the line, i.e. `p × d = m`.
+    #[inline]
+    pub fn contains_point(&self, p: &Point3D<T>) -> bool {
+        Vector3D::new(p.x, p.y, p.z).cross(&self.direction) == self.moment
+    }
+}
+
+impl<T> Line3D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// The point of the line closest to the origin, `(d × m) / Q(d)`.
+    ///
+    /// Returns `MathError::NullVector` if the direction is zero.
+    pub fn closest_to_origin(&self) -> Result<Point3D<T>, MathError>
+    where
+        T: Loggable,
+    {
+        let q = self.direction.quadrance();
+        if q == T::from(0) {
+            return Err(degenerate(
+                targets::GEOMETRY,
+                "Line3D::closest_to_origin",
+                MathError::NullVector,
+                self,
+            ));
+        }
+        let c = self.direction.cross(&self.moment) / q;
+        Ok(Point3D::new(c.x, c.y, c.z))
+    }
+
+    /// The quadrance between the closest points of `self` and `other`.
+    ///
+    /// For non-parallel lines this is `(d₁·m₂ + d₂·m₁)² / Q(d₁ × d₂)`, zero
+    /// exactly when they meet; for parallel lines it is the quadrance from
+    /// any point of one to the other.
+    ///
+    /// Returns `MathError::NullVector` if either direction is zero.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::{Line3D, Point3D};
+    /// let p = |x: i32, y: i32, z: i32| Point3D::new(Rational32::from(x), Rational32::from(y), Rational32::from(z));
+    /// let x_axis = Line3D::from_points(&p(0, 0, 0), &p(1, 0, 0)).unwrap();
+    /// let skew = Line3D::from_points(&p(0, 0, 2), &p(0, 1, 2)).unwrap();
+    /// assert_eq!(x_axis.quadrance_between_lines(&skew), Ok(Rational32::from(4)));
+    /// ```
+    pub fn quadrance_between_lines(&self, other: &Self) -> Result<T, MathError>
+    where
+        T: Loggable,
+    {
+        let c1 = self.closest_to_origin()?;
+        let c2 = other.closest_to_origin()?;
+        let cross = self.direction.cross(&other.direction);
+        if is_zero3(&cross) {
+            let q = self.direction.cross(&(c2 - c1)).quadrance();
+            return Ok(q / self.direction.quadrance());
+        }
+        let r = self.reciprocal_product(other);
+        Ok(r * r / cross.quadrance())
+    }
+
+    /// The unique common point of `self` and `other`.
+    ///
+    /// Returns `MathError::NonIntersecting` if the lines are skew or
+    /// parallel (including coincident), and `MathError::NullVector` if
+    /// either direction is zero.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::error::MathError;
+    /// use rat_trig_rs::geometry::{Line3D, Point3D};
+    /// let p = |x: i32, y: i32, z: i32| Point3D::new(Rational32::from(x), Rational32::from(y), Rational32::from(z));
+    /// let l1 = Line3D::from_points(&p(0, 0, 0), &p(2, 2, 2)).unwrap();
+    /// let l2 = Line3D::from_points(&p(1, 0, 1), &p(1, 2, 1)).unwrap();
+    /// assert_eq!(l1.intersection(&l2), Ok(p(1, 1, 1)));
+    /// let l3 = Line3D::from_points(&p(1, 0, 0), &p(1, 2, 0)).unwrap();
+    /// assert_eq!(l1.intersection(&l3), Err(MathError::NonIntersecting));
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Result<Point3D<T>, MathError>
+    where
+        T: Loggable,
+    {
+        let c1 = self.closest_to_origin()?;
+        let c2 = other.closest_to_origin()?;
+        let cross = self.direction.cross(&other.direction);
+        if is_zero3(&cross) || !self.are_coplanar(other) {
+            return Err(degenerate(
+                targets::GEOMETRY,
+                "Line3D::intersection",
+                MathError::NonIntersecting,
+                &(self, other),
+            ));
+        }
+        let t = (c2 - c1).cross(&other.direction).dot(&cross) / cross.quadrance();
+        Ok(c1 + self.direction * t)
+    }
+}
+
+/// Whether every component of `v` is zero.
+#[inline]
+fn is_zero3<T: Copy + PartialEq + From<i32>>(v: &Vector3D<T>) -> bool {
+    let zero = T::from(0);
+    v.x == zero && v.y == zero && v.z == zero
+}
+
+/// A triangle in the plane, given by its three vertices.
+///
+/// Following the usual convention of rational trigonometry, the quadrance
+/// `q_i` and spread `s_i` are associated with vertex `p_i`: `q_1` is the
+/// quadrance of the side opposite `p1`, and so on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[repr(C)]
+pub struct Triangle2D<T> {
+    pub p1: Point2D<T>,
+    pub p2: Point2D<T>,
+    pub p3: Point2D<T>,
+}
+
+impl<T> Triangle2D<T> {
+    /// Creates a new triangle from its vertices.
+    #[inline]
+    pub const fn new(p1: Point2D<T>, p2: Point2D<T>, p3: Point2D<T>) -> Self {
+        Triangle2D { p1, p2, p3 }
+    }
+
+    /// Starts a [`TriangleBuilder`], which validates the vertices on
+    /// `build()`.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::error::MathError;
+    /// use rat_trig_rs::geometry::{Point2D, Triangle2D, Winding};
+    /// let tri = Triangle2D::builder()
+    ///     .vertex(Point2D::new(0, 0))
+    ///     .vertex(Point2D::new(0, 3))
+    ///     .vertex(Point2D::new(4, 0))
+    ///     .winding(Winding::CounterClockwise)
+    ///     .fix_winding()
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(tri.p2, Point2D::new(4, 0));
+    ///
+    /// let flat = Triangle2D::builder()
+    ///     .vertices(Point2D::new(0, 0), Point2D::new(1, 1), Point2D::new(2, 2))
+    ///     .build();
+    /// assert_eq!(flat.unwrap_err().kind, MathError::DegenerateTriangle);
+    /// ```
+    #[inline]
+    pub fn builder() -> TriangleBuilder<T> {
+        TriangleBuilder::default()
+    }
+}
+
+/// The orientation of a triangle or polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Winding {
+    CounterClockwise,
+    Clockwise,
+}
+
+/// Accumulates and validates the vertices of a [`Triangle2D`].
+///
+/// `build()` fails with `MathError::InvalidInput` unless exactly three
+/// vertices were given, with `MathError::DegenerateTriangle` if they are
+/// collinear, and with `MathError::InvalidInput` if a required winding does
+/// not match, unless [`fix_winding`](Self::fix_winding) was requested, in
+/// which case the last two vertices are swapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TriangleBuilder<T> {
+    vertices: [Option<Point2D<T>>; 3],
+    count: usize,
+    winding: Option<Winding>,
+    fix_winding: bool,
+}
+
+impl<T> Default for TriangleBuilder<T> {
+    fn default() -> Self {
+        TriangleBuilder {
+            vertices: [None, None, None],
+            count: 0,
+            winding: None,
+            fix_winding: false,
+        }
+    }
+}
+
+impl<T> TriangleBuilder<T> {
+    /// Appends a vertex.
+    pub fn vertex(mut self, p: Point2D<T>) -> Self {
+        if let Some(slot) = self.vertices.get_mut(self.count) {
+            *slot = Some(p);
+        }
+        self.count += 1;
+        self
+    }
+
+    /// Appends three vertices.
+    pub fn vertices(self, p1: Point2D<T>, p2: Point2D<T>, p3: Point2D<T>) -> Self {
+        self.vertex(p1).vertex(p2).vertex(p3)
+    }
+
+    /// Requires the given orientation.
+    pub fn winding(mut self, winding: Winding) -> Self {
+        self.winding = Some(winding);
+        self
+    }
+
+    /// Reorders the vertices to match the required orientation instead of
+    /// failing.
+    pub fn fix_winding(mut self) -> Self {
+        self.fix_winding = true;
+        self
+    }
+}
+
+impl<T> TriangleBuilder<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    /// Validates the vertices and builds the triangle.
+    pub fn build(self) -> TrigResult<Triangle2D<T>>
+    where
+        T: Loggable,
+    {
+        const OPERATION: &str = "Triangle2D::build";
+        let (p1, p2, p3) = match (self.count, self.vertices) {
+            (3, [Some(p1), Some(p2), Some(p3)]) => (p1, p2, p3),
+            _ => {
+                return Err(rejected(
+                    targets::GEOMETRY,
+                    GeomError::new(MathError::InvalidInput, OPERATION)
+                        .with_input("vertex count other than three"),
+                    &self.vertices,
+                ))
+            }
+        };
+        let twist = p1.twist(&p2, &p3);
+        let zero = T::from(0);
+        if twist == zero {
+            return Err(rejected(
+                targets::GEOMETRY,
+                GeomError::new(MathError::DegenerateTriangle, OPERATION)
+                    .with_input("collinear vertices"),
+                &(p1, p2, p3),
+            ));
+        }
+        let actual = if twist > zero {
+            Winding::CounterClockwise
+        } else {
+            Winding::Clockwise
+        };
+        match self.winding {
+            Some(required) if required != actual => {
+                if self.fix_winding {
+                    Ok(Triangle2D::new(p1, p3, p2))
+                } else {
+                    Err(rejected(
+                        targets::GEOMETRY,
+                        GeomError::new(MathError::InvalidInput, OPERATION)
+                            .with_input("wrong winding"),
+                        &(p1, p2, p3),
+                    ))
+                }
+            }
+            _ => Ok(Triangle2D::new(p1, p2, p3)),
+        }
+    }
+}
+
+impl<T> Triangle2D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<i32>,
+{
+    /// The quadrances `(q_1, q_2, q_3)` of the sides opposite `p1`, `p2` and
+    /// `p3`.
+    pub fn quadrances(&self) -> (T, T, T) {
+        let quad = |a: &Point2D<T>, b: &Point2D<T>| quadrance(&(b.x - a.x, b.y - a.y));
+        (
+            quad(&self.p2, &self.p3),
+            quad(&self.p1, &self.p3),
+            quad(&self.p1, &self.p2),
+        )
+    }
+
+    /// The quadrea of the triangle (16 times the square of its area), by
+    /// Archimedes' formula.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::{Point2D, Triangle2D};
+    /// let tri = Triangle2D::new(Point2D::new(0, 0), Point2D::new(4, 0), Point2D::new(0, 3));
+    /// assert_eq!(tri.area(), 16 * 36);
+    /// ```
+    pub fn area(&self) -> T {
+        let (q_1, q_2, q_3) = self.quadrances();
+        archimedes(&q_1, &q_2, &q_3)
+    }
+
+    /// A displayable summary of the triangle with its quadrances and
+    /// quadrea.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::{Point2D, Triangle2D};
+    /// let tri = Triangle2D::new(Point2D::new(0, 0), Point2D::new(4, 0), Point2D::new(0, 3));
+    /// assert_eq!(
+    ///     tri.summary().to_string(),
+    ///     "Triangle(Point(0, 0), Point(4, 0), Point(0, 3)): Q = (25, 9, 16), A = 576"
+    /// );
+    /// ```
+    pub fn summary(&self) -> TriangleSummary<T> {
+        TriangleSummary {
+            triangle: *self,
+            quadrances: self.quadrances(),
+            quadrea: self.area(),
+        }
+    }
+}
+
+impl<T> Triangle2D<T>
+where
+    T: Copy + Add<Output = T> + Div<Output = T> + From<i32>,
+{
+    /// The medians as segments `(vertex, midpoint of the opposite side)`,
+    /// starting at `p1`, `p2` and `p3`. Their quadrances are given by
+    /// [`median_quadrances`](crate::trigonom::median_quadrances).
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::{Point2D, Triangle2D};
+    /// let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+    /// let tri = Triangle2D::new(p(0, 0), p(4, 0), p(0, 2));
+    /// let [m_1, m_2, m_3] = tri.medians();
+    /// assert_eq!(m_1, (p(0, 0), p(2, 1)));
+    /// assert_eq!(m_2, (p(4, 0), p(0, 1)));
+    /// assert_eq!(m_3, (p(0, 2), p(2, 0)));
+    /// ```
+    pub fn medians(&self) -> [(Point2D<T>, Point2D<T>); 3] {
+        [
+            (self.p1, self.p2.midpoint(&self.p3)),
+            (self.p2, self.p1.midpoint(&self.p3)),
+            (self.p3, self.p1.midpoint(&self.p2)),
+        ]
+    }
+
+    /// The centroid, where the medians meet: the average of the vertices.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::{Point2D, Triangle2D};
+    /// let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+    /// let tri = Triangle2D::new(p(0, 0), p(4, 0), p(0, 2));
+    /// assert_eq!(tri.centroid(), Point2D::new(Rational32::new(4, 3), Rational32::new(2, 3)));
+    /// ```
+    #[inline]
+    pub fn centroid(&self) -> Point2D<T> {
+        let three = T::from(3);
+        Point2D::new(
+            (self.p1.x + self.p2.x + self.p3.x) / three,
+            (self.p1.y + self.p2.y + self.p3.y) / three,
+        )
+    }
+}
+
+impl<T> Triangle2D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// The feet of the altitudes from `p1`, `p2` and `p3` onto the lines of
+    /// the opposite sides, computed exactly by projection.
+    ///
+    /// Returns `MathError::DivisionByZero` if two vertices coincide, as the
+    /// opposite side then has no line. The feet of a collinear triangle are
+    /// its vertices themselves.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::{Point2D, Triangle2D};
+    /// let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+    /// let tri = Triangle2D::new(p(0, 0), p(4, 0), p(1, 3));
+    /// let [f_1, f_2, f_3] = tri.altitude_feet().unwrap();
+    /// assert_eq!(f_1, p(2, 2));
+    /// assert_eq!(f_2, Point2D::new(Rational32::new(2, 5), Rational32::new(6, 5)));
+    /// assert_eq!(f_3, p(1, 0));
+    /// ```
+    pub fn altitude_feet(&self) -> Result<[Point2D<T>; 3], MathError>
+    where
+        T: Loggable,
+    {
+        let foot = |p: &Point2D<T>, a: &Point2D<T>, b: &Point2D<T>| {
+            let (dx, dy) = (b.x - a.x, b.y - a.y);
+            let q = quadrance(&(dx, dy));
+            if q == T::from(0) {
+                return Err(degenerate(
+                    targets::GEOMETRY,
+                    "Triangle2D::altitude_feet",
+                    MathError::DivisionByZero,
+                    &(a, b),
+                ));
+            }
+            let t = ((p.x - a.x) * dx + (p.y - a.y) * dy) / q;
+            Ok(Point2D::new(a.x + t * dx, a.y + t * dy))
+        };
+        Ok([
+            foot(&self.p1, &self.p2, &self.p3)?,
+            foot(&self.p2, &self.p1, &self.p3)?,
+            foot(&self.p3, &self.p1, &self.p2)?,
+        ])
+    }
+
+    /// The circumcenter, where the perpendicular bisectors of the sides meet,
+    /// found exactly by Cramer's rule.
+    ///
+    /// Returns `MathError::DegenerateTriangle` if the vertices are collinear
+    /// (including coincident), as no circle passes through them.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::error::MathError;
+    /// use rat_trig_rs::geometry::{Point2D, Triangle2D};
+    /// let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+    /// let tri = Triangle2D::new(p(0, 0), p(4, 0), p(1, 3));
+    /// assert_eq!(tri.circumcenter(), Ok(p(2, 1)));
+    /// let flat = Triangle2D::new(p(0, 0), p(1, 1), p(3, 3));
+    /// assert_eq!(flat.circumcenter(), Err(MathError::DegenerateTriangle));
+    /// ```
+    pub fn circumcenter(&self) -> Result<Point2D<T>, MathError>
+    where
+        T: Loggable,
+    {
+        let err = || {
+            degenerate(
+                targets::GEOMETRY,
+                "Triangle2D::circumcenter",
+                MathError::DegenerateTriangle,
+                self,
+            )
+        };
+        let (p1, p2, p3) = (
+            (self.p1.x, self.p1.y),
+            (self.p2.x, self.p2.y),
+            (self.p3.x, self.p3.y),
+        );
+        let l_3 = perpendicular_bisector(&p1, &p2).map_err(|_| err())?;
+        let l_2 = perpendicular_bisector(&p1, &p3).map_err(|_| err())?;
+        line_intersection(&l_3, &l_2)
+            .map(|(x, y)| Point2D::new(x, y))
+            .ok_or_else(err)
+    }
+
+    /// The Euler line through the circumcenter and the centroid, oriented
+    /// from the former to the latter. The orthocenter and the nine-point
+    /// center lie on it too.
+    ///
+    /// Returns `MathError::DegenerateTriangle` for collinear vertices, and
+    /// `MathError::InvalidInput` for an equilateral triangle, where all
+    /// these centers coincide and the line is undefined. The latter cannot
+    /// happen over the rationals, which have no equilateral triangles.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::{Line2D, Point2D, Triangle2D};
+    /// let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+    /// // circumcenter (2, 1), centroid (5/3, 1), orthocenter (1, 1)
+    /// let tri = Triangle2D::new(p(0, 0), p(4, 0), p(1, 3));
+    /// let r = Rational32::from;
+    /// assert_eq!(tri.euler_line(), Ok(Line2D::new(r(0), Rational32::new(-1, 3), Rational32::new(1, 3))));
+    /// ```
+    pub fn euler_line(&self) -> Result<Line2D<T>, MathError>
+    where
+        T: Loggable,
+    {
+        let o = self.circumcenter()?;
+        Line2D::from_points(&o, &self.centroid())
+    }
+
+    /// The center of the nine-point circle, midway between the circumcenter
+    /// `O` and the orthocenter `3G − 2O`, where `G` is the centroid.
+    ///
+    /// Returns `MathError::DegenerateTriangle` for collinear vertices.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::{Point2D, Triangle2D};
+    /// let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+    /// // circumcenter (2, 1) and orthocenter (1, 1)
+    /// let tri = Triangle2D::new(p(0, 0), p(4, 0), p(1, 3));
+    /// assert_eq!(tri.nine_point_center(), Ok(Point2D::new(Rational32::new(3, 2), Rational32::from(1))));
+    /// ```
+    pub fn nine_point_center(&self) -> Result<Point2D<T>, MathError>
+    where
+        T: Loggable,
+    {
+        let o = self.circumcenter()?;
+        let g = self.centroid();
+        let (two, three) = (T::from(2), T::from(3));
+        Ok(Point2D::new(
+            (three * g.x - o.x) / two,
+            (three * g.y - o.y) / two,
+        ))
+    }
+
+    /// The quadrance of the nine-point circle, a quarter of the
+    /// circumquadrance.
+    ///
+    /// Returns `MathError::DegenerateTriangle` for collinear vertices.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::{Point2D, Triangle2D};
+    /// let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+    /// // the circumquadrance is 5
+    /// let tri = Triangle2D::new(p(0, 0), p(4, 0), p(1, 3));
+    /// assert_eq!(tri.nine_point_quadrance(), Ok(Rational32::new(5, 4)));
+    /// ```
+    pub fn nine_point_quadrance(&self) -> Result<T, MathError>
+    where
+        T: Loggable,
+    {
+        let o = self.circumcenter()?;
+        Ok(quadrance(&(self.p1.x - o.x, self.p1.y - o.y)) / T::from(4))
+    }
+}
+
+impl<T> Triangle2D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    /// The quadrances in increasing order, which identify the triangle up to
+    /// congruence (mirror images included) and can serve as a hash key.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::{Point2D, Triangle2D};
+    /// let p = Point2D::new;
+    /// let tri = Triangle2D::new(p(0, 0), p(4, 0), p(0, 3));
+    /// assert_eq!(tri.canonical_quadrances(), [9, 16, 25]);
+    /// ```
+    #[inline]
+    pub fn canonical_quadrances(&self) -> [T; 3] {
+        sort3(self.quadrances())
+    }
+
+    /// The quadrea with the sign of the orientation: positive when the
+    /// vertices run counterclockwise, negative when clockwise. Its absolute
+    /// value is [`area`](Triangle2D::area).
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::{Point2D, Triangle2D};
+    /// let (a, b, c) = (Point2D::new(0, 0), Point2D::new(4, 0), Point2D::new(0, 3));
+    /// assert_eq!(Triangle2D::new(a, b, c).signed_quadrea(), 576);
+    /// assert_eq!(Triangle2D::new(a, c, b).signed_quadrea(), -576);
+    /// ```
+    #[inline]
+    pub fn signed_quadrea(&self) -> T {
+        let twist = self.p1.twist(&self.p2, &self.p3);
+        let quadrea = T::from(4) * twist * twist;
+        if twist < T::from(0) {
+            T::from(0) - quadrea
+        } else {
+            quadrea
+        }
+    }
+}
+
+impl<T> Triangle2D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + PartialEq
+        + From<i32>
+        + Into<f64>,
+{
+    /// The signed area as a float, half the twist: positive when the
+    /// vertices run counterclockwise. The twist is computed in `T` first, so
+    /// over the integers only the final conversion rounds.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::{Point2D, Triangle2D};
+    /// let (a, b, c) = (Point2D::new(0, 0), Point2D::new(4, 0), Point2D::new(0, 3));
+    /// assert_eq!(Triangle2D::new(a, b, c).signed_area_f64(), 6.0);
+    /// assert_eq!(Triangle2D::new(a, c, b).signed_area_f64(), -6.0);
+    /// ```
+    #[inline]
+    pub fn signed_area_f64(&self) -> f64 {
+        self.p1.twist(&self.p2, &self.p3).into() / 2.0
+    }
+}
+
+impl<T> Triangle2D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>,
+{
+    /// The spreads in increasing order, which identify the triangle up to
+    /// similarity, or `None` if two vertices coincide. Over the rationals
+    /// the entries are in lowest terms and can serve as a hash key.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::{Point2D, Triangle2D};
+    /// let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+    /// let tri = Triangle2D::new(p(0, 0), p(8, 0), p(0, 6));
+    /// let r = Rational32::new;
+    /// assert_eq!(tri.canonical_spreads(), Some([r(9, 25), r(16, 25), r(1, 1)]));
+    /// ```
+    pub fn canonical_spreads(&self) -> Option<[T; 3]> {
+        let zero = T::from(0);
+        let (q_1, q_2, q_3) = self.quadrances();
+        if q_1 == zero || q_2 == zero || q_3 == zero {
+            return None;
+        }
+        // sᵢ = A / (4·qⱼ·qₖ)
+        let a = self.area() / T::from(4);
+        Some(sort3((a / (q_2 * q_3), a / (q_1 * q_3), a / (q_1 * q_2))))
+    }
+}
+
+impl<T> Triangle2D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Rem<Output = T>
+        + PartialOrd
+        + From<i32>,
+{
+    /// The quadrances in increasing order, divided by their greatest common
+    /// divisor, which identify the triangle up to similarity.
+    ///
+    /// Meant for integers and rationals, where Euclid's algorithm gives the
+    /// gcd exactly; a rational triple becomes a coprime integer triple. A
+    /// triangle collapsed to a point keeps its zero quadrances.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::{Point2D, Triangle2D};
+    /// let p = Point2D::new;
+    /// let tri = Triangle2D::new(p(0, 0), p(8, 0), p(0, 6));
+    /// assert_eq!(tri.primitive_quadrances(), [9, 16, 25]);
+    /// let h = Rational32::new(1, 2);
+    /// let q = Point2D::new;
+    /// let small = Triangle2D::new(q(h, h), q(h, h * h), q(h * h, h));
+    /// assert_eq!(small.primitive_quadrances(), [1, 1, 2].map(Rational32::from));
+    /// ```
+    pub fn primitive_quadrances(&self) -> [T; 3] {
+        let zero = T::from(0);
+        let qs = self.canonical_quadrances();
+        let g = gcd(gcd(qs[0], qs[1]), qs[2]);
+        if g == zero {
+            return qs;
+        }
+        qs.map(|q| q / g)
+    }
+}
+
+/// A triangle in space, given by its three vertices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[repr(C)]
+pub struct Triangle3D<T> {
+    pub p1: Point3D<T>,
+    pub p2: Point3D<T>,
+    pub p3: Point3D<T>,
+}
+
+impl<T> Triangle3D<T> {
+    /// Creates a new triangle from its vertices.
+    #[inline]
+    pub const fn new(p1: Point3D<T>, p2: Point3D<T>, p3: Point3D<T>) -> Self {
+        Triangle3D { p1, p2, p3 }
+    }
+}
+
+impl<T> Triangle3D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// The quadrances `(q_1, q_2, q_3)` of the sides opposite `p1`, `p2` and
+    /// `p3`.
+    pub fn quadrances(&self) -> (T, T, T) {
+        let quad = |a: &Point3D<T>, b: &Point3D<T>| quadrance3d(&(b.x - a.x, b.y - a.y, b.z - a.z));
+        (
+            quad(&self.p2, &self.p3),
+            quad(&self.p1, &self.p3),
+            quad(&self.p1, &self.p2),
+        )
+    }
+}
+
+impl<T> Triangle3D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    /// The quadrea of the triangle (16 times the square of its area), by
+    /// Archimedes' formula.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::{Point3D, Triangle3D};
+    /// let tri = Triangle3D::new(Point3D::new(0, 0, 1), Point3D::new(4, 0, 1), Point3D::new(0, 3, 1));
+    /// assert_eq!(tri.area(), 16 * 36);
+    /// ```
+    pub fn area(&self) -> T {
+        let (q_1, q_2, q_3) = self.quadrances();
+        archimedes(&q_1, &q_2, &q_3)
+    }
+
+    /// The normal `(p2 − p1) × (p3 − p1)`, whose length is twice the area.
+    /// It points to the side from which the vertices appear counterclockwise.
+    pub fn normal(&self) -> Vector3D<T> {
+        let u = Vector3D::new(
+            self.p2.x - self.p1.x,
+            self.p2.y - self.p1.y,
+            self.p2.z - self.p1.z,
+        );
+        let v = Vector3D::new(
+            self.p3.x - self.p1.x,
+            self.p3.y - self.p1.y,
+            self.p3.z - self.p1.z,
+        );
+        u.cross(&v)
+    }
+
+    /// The twist relative to a reference normal: the dot product of
+    /// `reference` with [`normal`](Triangle3D::normal). It is positive when
+    /// the vertices appear counterclockwise seen from the tip of
+    /// `reference`, and equals the planar twist for triangles in the plane
+    /// `z = const` with reference `(0, 0, 1)`.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::{Point3D, Triangle3D, Vector3D};
+    /// let tri = Triangle3D::new(Point3D::new(0, 0, 1), Point3D::new(4, 0, 1), Point3D::new(0, 3, 1));
+    /// assert_eq!(tri.twist(&Vector3D::new(0, 0, 1)), 12);
+    /// assert_eq!(tri.twist(&Vector3D::new(0, 0, -1)), -12);
+    /// ```
+    pub fn twist(&self, reference: &Vector3D<T>) -> T {
+        reference.dot(&self.normal())
+    }
+
+    /// Checks whether the vertices are collinear.
+    #[inline]
+    pub fn is_degenerate(&self) -> bool {
+        let zero = T::from(0);
+        self.normal() == Vector3D::new(zero, zero, zero)
+    }
+
+    /// The supporting plane, with normal [`normal`](Triangle3D::normal);
+    /// see [`Plane3D::from_three_points`].
+    ///
+    /// Returns `MathError::InvalidInput` if the vertices are collinear.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::{Plane3D, Point3D, Triangle3D};
+    /// let tri = Triangle3D::new(Point3D::new(0, 0, 1), Point3D::new(4, 0, 1), Point3D::new(0, 3, 1));
+    /// assert_eq!(tri.plane(), Ok(Plane3D::new(0, 0, 12, -12)));
+    /// ```
+    #[inline]
+    pub fn plane(&self) -> Result<Plane3D<T>, MathError>
+    where
+        T: Loggable,
+    {
+        Plane3D::from_three_points(&self.p1, &self.p2, &self.p3)
+    }
+}
+
+impl<T> Triangle3D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// The spreads `(s_1, s_2, s_3)` at `p1`, `p2` and `p3`, by
+    /// `sᵢ = A / (4·qⱼ·qₖ)`, or `None` if two vertices coincide.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::{Point3D, Triangle3D};
+    /// let p = |x: i32, y: i32, z: i32| Point3D::new(Rational32::from(x), Rational32::from(y), Rational32::from(z));
+    /// // an equilateral triangle on the corners of a cube
+    /// let tri = Triangle3D::new(p(1, 0, 0), p(0, 1, 0), p(0, 0, 1));
+    /// let s = Rational32::new(3, 4);
+    /// assert_eq!(tri.spreads(), Some((s, s, s)));
+    /// ```
+    pub fn spreads(&self) -> Option<(T, T, T)> {
+        let zero = T::from(0);
+        let (q_1, q_2, q_3) = self.quadrances();
+        if q_1 == zero || q_2 == zero || q_3 == zero {
+            return None;
+        }
+        let a = self.area() / T::from(4);
+        Some((a / (q_2 * q_3), a / (q_1 * q_3), a / (q_1 * q_2)))
+    }
+
+    /// The orthogonal projection of `p` onto the plane of the triangle; see
+    /// [`Plane3D::project_point`].
+    ///
+    /// Returns `MathError::InvalidInput` if the vertices are collinear.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::{Point3D, Triangle3D};
+    /// let p = |x: i32, y: i32, z: i32| Point3D::new(Rational32::from(x), Rational32::from(y), Rational32::from(z));
+    /// let tri = Triangle3D::new(p(2, 0, 0), p(0, 2, 0), p(0, 0, 2));
+    /// let foot = Rational32::new(2, 3);
+    /// assert_eq!(tri.project_point(&p(0, 0, 0)), Ok(Point3D::new(foot, foot, foot)));
+    /// ```
+    pub fn project_point(&self, p: &Point3D<T>) -> Result<Point3D<T>, MathError>
+    where
+        T: Loggable,
+    {
+        self.plane()?.project_point(p)
+    }
+}
+
+/// A tetrahedron in space, given by its four vertices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[repr(C)]
+pub struct Tetrahedron3D<T> {
+    pub p1: Point3D<T>,
+    pub p2: Point3D<T>,
+    pub p3: Point3D<T>,
+    pub p4: Point3D<T>,
+}
+
+impl<T> Tetrahedron3D<T> {
+    /// Creates a new tetrahedron from its vertices.
+    #[inline]
+    pub const fn new(p1: Point3D<T>, p2: Point3D<T>, p3: Point3D<T>, p4: Point3D<T>) -> Self {
+        Tetrahedron3D { p1, p2, p3, p4 }
+    }
+}
+
+impl<T> Tetrahedron3D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    /// The quadrances of the six edges, in the order
+    /// `[q₁₂, q₁₃, q₁₄, q₂₃, q₂₄, q₃₄]`.
+    pub fn quadrances(&self) -> [T; 6] {
+        let quad = |a: &Point3D<T>, b: &Point3D<T>| (*b - *a).quadrance();
+        [
+            quad(&self.p1, &self.p2),
+            quad(&self.p1, &self.p3),
+            quad(&self.p1, &self.p4),
+            quad(&self.p2, &self.p3),
+            quad(&self.p2, &self.p4),
+            quad(&self.p3, &self.p4),
+        ]
+    }
+
+    /// The quadrume (`288·V²`) from the six edge quadrances; see
+    /// [`quadrume`].
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::{Point3D, Tetrahedron3D};
+    /// let p = Point3D::new;
+    /// let tet = Tetrahedron3D::new(p(0, 0, 0), p(2, 0, 0), p(0, 2, 0), p(0, 0, 2));
+    /// assert_eq!(tet.quadrume(), 512);
+    /// ```
+    pub fn quadrume(&self) -> T {
+        let [q_12, q_13, q_14, q_23, q_24, q_34] = self.quadrances();
+        quadrume(&q_12, &q_13, &q_14, &q_23, &q_24, &q_34)
+    }
+
+    /// The triple product `(p2 − p1) · ((p3 − p1) × (p4 − p1))`, six times
+    /// the signed volume. It is positive when `p4` lies on the side of
+    /// `p1 p2 p3` from which those vertices appear counterclockwise.
+    pub fn triple_product(&self) -> T {
+        let u = self.p2 - self.p1;
+        let v = self.p3 - self.p1;
+        let w = self.p4 - self.p1;
+        u.dot(&v.cross(&w))
+    }
+
+    /// Checks whether the four vertices are coplanar.
+    #[inline]
+    pub fn is_degenerate(&self) -> bool {
+        self.triple_product() == T::from(0)
+    }
+}
+
+impl<T> Tetrahedron3D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// The center of the circumsphere, solving `2·(pᵢ − p1)·c = Q(pᵢ) − Q(p1)`
+    /// exactly by Cramer's rule. With `u`, `v`, `w` the edges from `p1`, it is
+    /// `p1 + (Q(u)·(v × w) + Q(v)·(w × u) + Q(w)·(u × v)) / (2·u·(v × w))`.
+    ///
+    /// Returns `MathError::DivisionByZero` if the vertices are coplanar
+    /// (including coincident), as the system is then singular.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::error::MathError;
+    /// use rat_trig_rs::geometry::{Point3D, Tetrahedron3D};
+    /// let p = |x: i32, y: i32, z: i32| Point3D::new(Rational32::from(x), Rational32::from(y), Rational32::from(z));
+    /// let tet = Tetrahedron3D::new(p(0, 0, 0), p(2, 0, 0), p(0, 4, 0), p(0, 0, 6));
+    /// assert_eq!(tet.circumcenter(), Ok(p(1, 2, 3)));
+    /// let flat = Tetrahedron3D::new(p(0, 0, 0), p(2, 0, 0), p(0, 4, 0), p(2, 4, 0));
+    /// assert_eq!(flat.circumcenter(), Err(MathError::DivisionByZero));
+    /// ```
+    pub fn circumcenter(&self) -> Result<Point3D<T>, MathError>
+    where
+        T: Loggable,
+    {
+        let denom = T::from(2) * self.triple_product();
+        if denom == T::from(0) {
+            return Err(degenerate(
+                targets::GEOMETRY,
+                "Tetrahedron3D::circumcenter",
+                MathError::DivisionByZero,
+                self,
+            ));
+        }
+        let u = self.p2 - self.p1;
+        let v = self.p3 - self.p1;
+        let w = self.p4 - self.p1;
+        let offset =
+            v.cross(&w) * u.quadrance() + w.cross(&u) * v.quadrance() + u.cross(&v) * w.quadrance();
+        Ok(self.p1 + offset / denom)
+    }
+}
+
+/// The greatest common divisor by Euclid's algorithm, up to sign. Over the
+/// rationals this is the largest `g` making both `a / g` and `b / g`
+/// integers.
+pub(crate) fn gcd<T>(mut a: T, mut b: T) -> T
+where
+    T: Copy + Rem<Output = T> + PartialEq + From<i32>,
+{
+    let zero = T::from(0);
+    while b != zero {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Sorts a triple in increasing order.
+pub(crate) fn sort3<T: Copy + PartialOrd>((a, b, c): (T, T, T)) -> [T; 3] {
+    let mut v = [a, b, c];
+    if v[0] > v[1] {
+        v.swap(0, 1);
+    }
+    if v[1] > v[2] {
+        v.swap(1, 2);
+    }
+    if v[0] > v[1] {
+        v.swap(0, 1);
+    }
+    v
+}
+
+/// Parses one comma-separated component; `None` means it is missing.
+fn parse_component<T: FromStr>(token: Option<&str>) -> Result<T, MathError> {
+    token
+        .ok_or(MathError::InvalidInput)?
+        .trim()
+        .parse()
+        .map_err(|_| MathError::InvalidInput)
+}
+
+/// Strips an optional leading keyword, as written by the `Display` impls.
+fn strip_keyword<'a>(s: &'a str, keyword: &str) -> &'a str {
+    let s = s.trim();
+    s.strip_prefix(keyword).unwrap_or(s)
+}
+
+/// Strips one optional pair of enclosing parentheses.
+fn strip_optional_parens(s: &str) -> &str {
+    let s = s.trim();
+    s.strip_prefix('(')
+        .and_then(|rest| rest.strip_suffix(')'))
+        .unwrap_or(s)
+}
+
+/// Accepts `"(3, 4)"`, `"3,4"` or the `Display` form `"Point(3, 4)"`.
+///
+/// Returns `MathError::InvalidInput` on malformed text.
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::geometry::Point2D;
+/// let p: Point2D<Rational32> = "(3/2, -4)".parse().unwrap();
+/// assert_eq!(p, Point2D::new(Rational32::new(3, 2), Rational32::from(-4)));
+/// ```
+impl<T: FromStr> FromStr for Point2D<T> {
+    type Err = MathError;
+
+    fn from_str(s: &str) -> Result<Self, MathError> {
+        let mut parts = strip_optional_parens(strip_keyword(s, "Point")).split(',');
+        let x = parse_component(parts.next())?;
+        let y = parse_component(parts.next())?;
+        match parts.next() {
+            None => Ok(Point2D::new(x, y)),
+            Some(_) => Err(MathError::InvalidInput),
+        }
+    }
+}
+
+/// Accepts `"(1, 2, 3)"`, `"1,2,3"` or the `Display` form
+/// `"Point(1, 2, 3)"`.
+///
+/// Returns `MathError::InvalidInput` on malformed text.
+impl<T: FromStr> FromStr for Point3D<T> {
+    type Err = MathError;
+
+    fn from_str(s: &str) -> Result<Self, MathError> {
+        let mut parts = strip_optional_parens(strip_keyword(s, "Point")).split(',');
+        let x = parse_component(parts.next())?;
+        let y = parse_component(parts.next())?;
+        let z = parse_component(parts.next())?;
+        match parts.next() {
+            None => Ok(Point3D::new(x, y, z)),
+            Some(_) => Err(MathError::InvalidInput),
+        }
+    }
+}
+
+/// Accepts an equation such as `"2x + 3y - 5 = 0"` or the coefficients
+/// `"2,3,-5"` (optionally in parentheses), with an optional `Line:` prefix
+/// as written by the `Display` impl.
+///
+/// In the equation form, terms may appear in any order, each at most once;
+/// a missing term has coefficient zero, a bare `x` or `-y` has coefficient
+/// ±1, and the `= 0` may be omitted. An equation without variables, such as
+/// `"0 = 0"`, gives a line with `a = b = 0`. Coefficients are parsed with
+/// `T::from_str`, so `3/2x` works for rational types.
+///
+/// Returns `MathError::InvalidInput` on malformed text.
+///
+/// ```rust
+/// use rat_trig_rs::geometry::Line2D;
+/// assert_eq!("2x+3y-5=0".parse(), Ok(Line2D::new(2, 3, -5)));
+/// assert_eq!("-y + x".parse(), Ok(Line2D::new(1, -1, 0)));
+/// assert_eq!("(2, 3, -5)".parse(), Ok(Line2D::new(2, 3, -5)));
+/// assert_eq!("Line: 0 = 0".parse(), Ok(Line2D::new(0, 0, 0)));
+/// ```
+impl<T: FromStr + Neg<Output = T>> FromStr for Line2D<T> {
+    type Err = MathError;
+
+    fn from_str(s: &str) -> Result<Self, MathError> {
+        let s = strip_keyword(s, "Line:");
+        if !s.contains(['x', 'y', 'X', 'Y', '=']) {
+            let mut parts = strip_optional_parens(s).split(',');
+            let a = parse_component(parts.next())?;
+            let b = parse_component(parts.next())?;
+            let c = parse_component(parts.next())?;
+            return match parts.next() {
+                None => Ok(Line2D::new(a, b, c)),
+                Some(_) => Err(MathError::InvalidInput),
+            };
+        }
+
+        let expr = match s.split_once('=') {
+            Some((lhs, rhs)) if rhs.trim() == "0" => lhs,
+            Some(_) => return Err(MathError::InvalidInput),
+            None => s,
+        };
+        if expr.trim().is_empty() {
+            return Err(MathError::InvalidInput);
+        }
+        let parse = |text: &str| text.parse::<T>().map_err(|_| MathError::InvalidInput);
+        let (mut a, mut b, mut c) = (None, None, None);
+        let mut rest = expr.trim();
+        while !rest.is_empty() {
+            // a term runs up to the next sign that is not part of a number
+            let bytes = rest.as_bytes();
+            let end = (1..bytes.len())
+                .find(|&i| {
+                    matches!(bytes[i], b'+' | b'-') && !matches!(bytes[i - 1], b'e' | b'E' | b'/')
+                })
+                .unwrap_or(bytes.len());
+            let (term, tail) = rest.split_at(end);
+            rest = tail.trim_start();
+
+            let term = term.trim();
+            let (negative, body) = match term.strip_prefix('-') {
+                Some(body) => (true, body),
+                None => (false, term.strip_prefix('+').unwrap_or(term)),
+            };
+            let (slot, coeff) = match body.trim_end().char_indices().last() {
+                Some((i, 'x' | 'X')) => (&mut a, &body[..i]),
+                Some((i, 'y' | 'Y')) => (&mut b, &body[..i]),
+                Some(_) => (&mut c, body),
+                None => return Err(MathError::InvalidInput),
+            };
+            let coeff = coeff.trim();
+            let coeff = coeff.strip_suffix('*').unwrap_or(coeff).trim();
+            if slot.is_some() {
+                return Err(MathError::InvalidInput);
+            }
+            let value = parse(if coeff.is_empty() { "1" } else { coeff })?;
+            *slot = Some(if negative { -value } else { value });
+        }
+        let or_zero = |v: Option<T>| v.map_or_else(|| parse("0"), Ok);
+        Ok(Line2D::new(or_zero(a)?, or_zero(b)?, or_zero(c)?))
+    }
+}
+
+/// A triangle together with its quadrances and quadrea, for display.
+///
+/// Created by [`Triangle2D::summary`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TriangleSummary<T> {
+    pub triangle: Triangle2D<T>,
+    pub quadrances: (T, T, T),
+    pub quadrea: T,
+}
+
+// The `Display` impls forward the formatter to each component, so a
+// precision such as `{:.3}` applies to every coordinate.
+
+macro_rules! impl_display_components {
+    ($ty:ident, $name:literal, $first:ident $(, $field:ident)*) => {
+        impl<T: fmt::Display> fmt::Display for $ty<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(concat!($name, "("))?;
+                self.$first.fmt(f)?;
+                $(
+                    f.write_str(", ")?;
+                    self.$field.fmt(f)?;
+                )*
+                f.write_str(")")
+            }
+        }
+    };
+}
+
+impl_display_components!(Point2D, "Point", x, y);
+impl_display_components!(Vector2D, "Vector", x, y);
+impl_display_components!(Point3D, "Point", x, y, z);
+impl_display_components!(Vector3D, "Vector", x, y, z);
+impl_display_components!(Triangle2D, "Triangle", p1, p2, p3);
+impl_display_components!(Triangle3D, "Triangle", p1, p2, p3);
+
+/// Writes the line as an equation, e.g. `Line: 2x + 3y - 5 = 0`, leaving out
+/// zero terms and unit coefficients. The output parses back with `FromStr`.
+impl<T> fmt::Display for Line2D<T>
+where
+    T: fmt::Display + Copy + PartialOrd + Neg<Output = T> + From<i32>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (zero, one) = (T::from(0), T::from(1));
+        f.write_str("Line: ")?;
+        let mut first = true;
+        for (coeff, var) in [(self.a, "x"), (self.b, "y"), (self.c, "")] {
+            if coeff == zero {
+                continue;
+            }
+            let negative = coeff < zero;
+            f.write_str(match (first, negative) {
+                (true, false) => "",
+                (true, true) => "-",
+                (false, false) => " + ",
+                (false, true) => " - ",
+            })?;
+            let magnitude = if negative { -coeff } else { coeff };
+            if var.is_empty() || magnitude != one {
+                magnitude.fmt(f)?;
+            }
+            f.write_str(var)?;
+            first = false;
+        }
+        if first {
+            f.write_str("0")?;
+        }
+        f.write_str(" = 0")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for TriangleSummary<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (q_1, q_2, q_3) = &self.quadrances;
+        self.triangle.fmt(f)?;
+        f.write_str(": Q = (")?;
+        q_1.fmt(f)?;
+        f.write_str(", ")?;
+        q_2.fmt(f)?;
+        f.write_str(", ")?;
+        q_3.fmt(f)?;
+        f.write_str("), A = ")?;
+        self.quadrea.fmt(f)
+    }
+}
+
+// SAFETY: every type below is `#[repr(C)]` and consists solely of fields of
+// type `T` (directly or through other such types), so it has no padding and
+// any bit pattern valid for `T` is valid for it.
+#[cfg(feature = "bytemuck")]
+mod pod_impls {
+    use super::*;
+    use crate::projective::ProjectivePoint2D;
+    use bytemuck::{Pod, Zeroable};
+
+    macro_rules! impl_pod {
+        ($($ty:ident),*) => {
+            $(
+                unsafe impl<T: Zeroable> Zeroable for $ty<T> {}
+                unsafe impl<T: Pod> Pod for $ty<T> {}
+            )*
+        };
+    }
+
+    impl_pod!(
+        Point2D,
+        Vector2D,
+        Line2D,
+        Point3D,
+        Vector3D,
+        Plane3D,
+        Line3D,
+        Triangle2D,
+        Triangle3D,
+        Tetrahedron3D,
+        ProjectivePoint2D
+    );
+}
+
+/// Conversions to and from the `euclid` crate's point and vector types. The
+/// unit parameter `U` is dropped on the way in and chosen by the caller (or
+/// inferred) on the way out, so tagged coordinates round-trip unchanged.
+#[cfg(feature = "euclid")]
+mod euclid_impls {
+    use super::*;
+
+    macro_rules! impl_euclid_2d {
+        ($ty:ident) => {
+            impl<T, U> From<euclid::$ty<T, U>> for $ty<T> {
+                #[inline]
+                fn from(value: euclid::$ty<T, U>) -> Self {
+                    $ty::new(value.x, value.y)
+                }
+            }
+
+            impl<T, U> From<$ty<T>> for euclid::$ty<T, U> {
+                #[inline]
+                fn from(value: $ty<T>) -> Self {
+                    euclid::$ty::new(value.x, value.y)
+                }
+            }
+        };
+    }
+
+    macro_rules! impl_euclid_3d {
+        ($ty:ident) => {
+            impl<T, U> From<euclid::$ty<T, U>> for $ty<T> {
+                #[inline]
+                fn from(value: euclid::$ty<T, U>) -> Self {
+                    $ty::new(value.x, value.y, value.z)
+                }
+            }
+
+            impl<T, U> From<$ty<T>> for euclid::$ty<T, U> {
+                #[inline]
+                fn from(value: $ty<T>) -> Self {
+                    euclid::$ty::new(value.x, value.y, value.z)
+                }
+            }
+        };
+    }
+
+    impl_euclid_2d!(Point2D);
+    impl_euclid_2d!(Vector2D);
+    impl_euclid_3d!(Point3D);
+    impl_euclid_3d!(Vector3D);
+}
+
+/// Approximate comparisons from the `approx` crate, component-wise, so
+/// float geometry can be checked with `assert_relative_eq!` and friends.
+#[cfg(feature = "approx")]
+mod approx_impls {
+    use super::*;
+    use crate::projective::ProjectivePoint2D;
+    use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+    macro_rules! impl_approx {
+        ($ty:ident { $($field:ident),* }) => {
+            impl<T: AbsDiffEq> AbsDiffEq for $ty<T>
+            where
+                T::Epsilon: Copy,
+            {
+                type Epsilon = T::Epsilon;
+
+                #[inline]
+                fn default_epsilon() -> T::Epsilon {
+                    T::default_epsilon()
+                }
+
+                #[inline]
+                fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+                    $(self.$field.abs_diff_eq(&other.$field, epsilon))&&*
+                }
+            }
+
+            impl<T: RelativeEq> RelativeEq for $ty<T>
+            where
+                T::Epsilon: Copy,
+            {
+                #[inline]
+                fn default_max_relative() -> T::Epsilon {
+                    T::default_max_relative()
+                }
+
+                #[inline]
+                fn relative_eq(
+                    &self,
+                    other: &Self,
+                    epsilon: T::Epsilon,
+                    max_relative: T::Epsilon,
+                ) -> bool {
+                    $(self.$field.relative_eq(&other.$field, epsilon, max_relative))&&*
+                }
+            }
+
+            impl<T: UlpsEq> UlpsEq for $ty<T>
+            where
+                T::Epsilon: Copy,
+            {
+                #[inline]
+                fn default_max_ulps() -> u32 {
+                    T::default_max_ulps()
+                }
+
+                #[inline]
+                fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+                    $(self.$field.ulps_eq(&other.$field, epsilon, max_ulps))&&*
+                }
+            }
+        };
+    }
+
+    impl_approx!(Point2D { x, y });
+    impl_approx!(Vector2D { x, y });
+    impl_approx!(Line2D { a, b, c });
+    impl_approx!(Point3D { x, y, z });
+    impl_approx!(Vector3D { x, y, z });
+    impl_approx!(Plane3D { a, b, c, d });
+    impl_approx!(Line3D { direction, moment });
+    impl_approx!(Triangle2D { p1, p2, p3 });
+    impl_approx!(Triangle3D { p1, p2, p3 });
+    impl_approx!(Tetrahedron3D { p1, p2, p3, p4 });
+    impl_approx!(ProjectivePoint2D { x, y, z });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trigonom::median_quadrances;
+
+    #[test]
+    fn test_vector2d_add_sub() {
+        let v1 = Vector2D::new(1, 2);
+        let v2 = Vector2D::new(3, 5);
+        assert_eq!(v1 + v2, Vector2D::new(4, 7));
+        assert_eq!(v2 - v1, Vector2D::new(2, 3));
+    }
+
+    #[test]
+    fn test_vector3d_add_sub() {
+        let v1 = Vector3D::new(1, 2, 3);
+        let v2 = Vector3D::new(3, 5, 7);
+        assert_eq!(v1 + v2, Vector3D::new(4, 7, 10));
+        assert_eq!(v2 - v1, Vector3D::new(2, 3, 4));
+    }
+
+    #[test]
+    fn test_vector3d_tuple_parity() {
+        let (u, v) = (Vector3D::from((1, 2, 3)), Vector3D::from((-2, 0, 5)));
+        assert_eq!(u.quadrance(), quadrance3d(&u.into()));
+        assert_eq!(<(i32, i32, i32)>::from(u.cross(&v)), (10, -11, 4));
+        assert_eq!(u.dot(&v), 13);
+        assert_eq!(-u * 2, Vector3D::from((-2, -4, -6)));
+    }
+
+    #[test]
+    fn test_vector_scalar_ops() {
+        let mut v = Vector2D::new(2, -4);
+        assert_eq!(v * 3, Vector2D::new(6, -12));
+        assert_eq!(v / 2, Vector2D::new(1, -2));
+        assert_eq!(-v, Vector2D::new(-2, 4));
+        v += Vector2D::new(1, 1);
+        assert_eq!(v, Vector2D::new(3, -3));
+        v -= Vector2D::new(3, 0);
+        assert_eq!(v, Vector2D::new(0, -3));
+        let mut w = Vector3D::new(1.0, 2.0, -0.5);
+        assert_eq!(w * 2.0, Vector3D::new(2.0, 4.0, -1.0));
+        assert_eq!(w / 4.0, Vector3D::new(0.25, 0.5, -0.125));
+        assert_eq!(-w, Vector3D::new(-1.0, -2.0, 0.5));
+        w += Vector3D::new(1.0, 1.0, 1.0);
+        w -= Vector3D::new(0.0, 3.0, 0.5);
+        assert_eq!(w, Vector3D::new(2.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_plane3d() {
+        type Q = num_rational::Ratio<i32>;
+        let p = |x: i32, y: i32, z: i32| Point3D::new(Q::from(x), Q::from(y), Q::from(z));
+        let plane = Plane3D::from_three_points(&p(1, 0, 0), &p(0, 2, 0), &p(0, 0, 3)).unwrap();
+        assert_eq!(
+            plane,
+            Plane3D::new(Q::from(6), Q::from(3), Q::from(2), Q::from(-6))
+        );
+        assert!(plane.contains_point(&p(1, 0, 0)));
+        assert!(plane.contains_point(&p(-1, 2, 3)));
+        assert!(!plane.contains_point(&p(0, 0, 0)));
+        assert_eq!(plane.quadrance_from_point(&p(0, 0, 0)), Ok(Q::new(36, 49)));
+        assert_eq!(plane.quadrance_from_point(&p(0, 0, 3)), Ok(Q::from(0)));
+        assert_eq!(
+            Plane3D::from_three_points(&p(0, 0, 0), &p(1, 1, 1), &p(2, 2, 2)),
+            Err(MathError::InvalidInput)
+        );
+        let null = Plane3D::new(Q::from(0), Q::from(0), Q::from(0), Q::from(1));
+        assert_eq!(
+            null.quadrance_from_point(&p(1, 1, 1)),
+            Err(MathError::NullVector)
+        );
+    }
+
+    #[test]
+    fn test_triangle3d_plane_projection() {
+        type Q = num_rational::Ratio<i32>;
+        let p = |x: i32, y: i32, z: i32| Point3D::new(Q::from(x), Q::from(y), Q::from(z));
+        let tri = Triangle3D::new(p(1, 0, 0), p(0, 2, 0), p(0, 0, 3));
+        let plane = tri.plane().unwrap();
+        assert_eq!(plane.normal(), tri.normal());
+        assert!([tri.p1, tri.p2, tri.p3]
+            .iter()
+            .all(|v| plane.contains_point(v)));
+        let x = p(5, -1, 7);
+        let foot = tri.project_point(&x).unwrap();
+        assert!(plane.contains_point(&foot));
+        assert_eq!(
+            (x - foot).cross(&tri.normal()),
+            Vector3D::new(Q::from(0), Q::from(0), Q::from(0))
+        );
+        assert_eq!(
+            (x - foot).quadrance(),
+            plane.quadrance_from_point(&x).unwrap()
+        );
+        assert_eq!(tri.project_point(&tri.p2), Ok(tri.p2));
+        let flat = Triangle3D::new(p(0, 0, 0), p(1, 1, 1), p(2, 2, 2));
+        assert_eq!(flat.plane(), Err(MathError::InvalidInput));
+        assert_eq!(flat.project_point(&x), Err(MathError::InvalidInput));
+    }
+
+    #[test]
+    fn test_tetrahedron3d() {
+        let tet = Tetrahedron3D::new(
+            Point3D::new(1, 0, 2),
+            Point3D::new(4, 1, 0),
+            Point3D::new(0, 3, 1),
+            Point3D::new(2, 2, 5),
+        );
+        assert_eq!(tet.quadrances(), [14, 11, 14, 21, 30, 21]);
+        let t = tet.triple_product();
+        assert_eq!(t, 45);
+        assert_eq!(tet.quadrume(), 8 * t * t);
+        assert!(!tet.is_degenerate());
+        let flat = Tetrahedron3D::new(tet.p1, tet.p2, tet.p3, tet.p1 + (tet.p2 - tet.p3));
+        assert!(flat.is_degenerate());
+        assert_eq!(flat.quadrume(), 0);
+    }
+
+    #[test]
+    fn test_tetrahedron3d_circumcenter() {
+        type Q = num_rational::Ratio<i32>;
+        let p = |x: i32, y: i32, z: i32| Point3D::new(Q::from(x), Q::from(y), Q::from(z));
+        let tet = Tetrahedron3D::new(p(1, 0, 2), p(4, 1, 0), p(0, 3, 1), p(2, 2, 5));
+        let c = tet.circumcenter().unwrap();
+        let q = (tet.p1 - c).quadrance();
+        for v in [tet.p2, tet.p3, tet.p4] {
+            assert_eq!((v - c).quadrance(), q);
+        }
+        let shifted = Tetrahedron3D::new(tet.p2, tet.p1, tet.p4, tet.p3);
+        assert_eq!(shifted.circumcenter(), Ok(c));
+        let flat = Tetrahedron3D::new(p(0, 0, 0), p(1, 1, 1), p(2, 2, 2), p(0, 1, 0));
+        assert_eq!(flat.circumcenter(), Err(MathError::DivisionByZero));
+    }
+
+    #[test]
+    fn test_line3d() {
+        type Q = num_rational::Ratio<i32>;
+        let p = |x: i32, y: i32, z: i32| Point3D::new(Q::from(x), Q::from(y), Q::from(z));
+        let l = |a: Point3D<Q>, b: Point3D<Q>| Line3D::from_points(&a, &b).unwrap();
+        let l1 = l(p(1, 0, 0), p(1, 2, 2));
+        assert!(l1.contains_point(&p(1, 3, 3)));
+        assert!(!l1.contains_point(&p(0, 3, 3)));
+        assert_eq!(l1.closest_to_origin(), Ok(p(1, 0, 0)));
+        // Parallel lines: never intersecting, at a constant quadrance.
+        let l2 = l(p(0, 1, 0), p(0, 4, 3));
+        assert!(l1.is_parallel(&l2) && l1.are_coplanar(&l2));
+        assert_eq!(l1.quadrance_between_lines(&l2), Ok(Q::new(3, 2)));
+        assert_eq!(l1.intersection(&l2), Err(MathError::NonIntersecting));
+        assert_eq!(l1.quadrance_between_lines(&l1), Ok(Q::from(0)));
+        // Meeting lines.
+        let l3 = l(p(0, 0, 0), p(1, 1, 1));
+        assert!(l1.are_coplanar(&l3));
+        assert_eq!(l1.quadrance_between_lines(&l3), Ok(Q::from(0)));
+        assert_eq!(l1.intersection(&l3), Ok(p(1, 1, 1)));
+        // Skew lines.
+        let l4 = l(p(0, 0, 5), p(0, 1, 5));
+        assert!(!l3.are_coplanar(&l4));
+        assert_eq!(
+            l(p(0, 0, 0), p(1, 0, 0)).quadrance_between_lines(&l4),
+            Ok(Q::from(25))
+        );
+        assert_eq!(
+            Line3D::from_points(&p(1, 1, 1), &p(1, 1, 1)),
+            Err(MathError::InvalidInput)
+        );
+        let null = Line3D::new(Vector3D::new(Q::from(0), Q::from(0), Q::from(0)), l1.moment);
+        assert_eq!(
+            null.quadrance_between_lines(&l1),
+            Err(MathError::NullVector)
+        );
+    }
+
+    #[test]
+    fn test_point_vector_arithmetic() {
+        let (p, q) = (Point2D::new(1, 5), Point2D::new(4, 1));
+        let d = q - p;
+        assert_eq!(d, Vector2D::new(3, -4));
+        assert_eq!(p + d, q);
+        assert_eq!(q - d, p);
+        assert_eq!(d.quadrance(), 25);
+        let (p, q) = (Point3D::new(0, 1, 2), Point3D::new(2, 2, 2));
+        assert_eq!(q - p, Vector3D::new(2, 1, 0));
+        assert_eq!(p + (q - p) * 2, Point3D::new(4, 3, 2));
+        assert_eq!(q - Vector3D::new(2, 2, 2), Point3D::new(0, 0, 0));
+    }
+
+    #[test]
+    fn test_line_constructions() {
+        let (p, q) = (Point2D::new(1, 1), Point2D::new(4, 3));
+        let l = Line2D::from_points(&p, &q).unwrap();
+        assert_eq!(l.direction(), q - p);
+        assert_eq!(l.normal().dot(&l.direction()), 0);
+        let r = Point2D::new(-2, 7);
+        let on = |l: &Line2D<i32>, p: &Point2D<i32>| l.a * p.x + l.b * p.y + l.c == 0;
+        let par = l.parallel_through(&r);
+        assert!(on(&par, &r) && !on(&par, &p));
+        assert_eq!(par.normal(), l.normal());
+        let perp = l.perpendicular_through(&r);
+        assert!(on(&perp, &r));
+        assert_eq!(perp.direction().dot(&l.direction()), 0);
+    }
+
+    #[test]
+    fn test_line_normalization() {
+        assert_eq!(Line2D::new(-6, 0, 9).normalized(), Line2D::new(2, 0, -3));
+        assert_eq!(Line2D::new(0, -4, -2).normalized(), Line2D::new(0, 2, 1));
+        assert_eq!(Line2D::new(0, 0, -5).normalized(), Line2D::new(0, 0, 1));
+        type Q = num_rational::Ratio<i32>;
+        let r = Line2D::new(Q::new(1, 2), Q::new(-1, 3), Q::from(0));
+        assert_eq!(
+            r.normalized(),
+            Line2D::new(Q::from(3), Q::from(-2), Q::from(0))
+        );
+        assert!(r.equivalent(&r.normalized()));
+        assert_eq!(Line2D::new(0, 0, 0).normalized(), Line2D::new(0, 0, 0));
+    }
+
+    #[test]
+    fn test_vector_products() {
+        let (u, v) = (Vector2D::new(3, 1), Vector2D::new(-1, 2));
+        assert_eq!((u.dot(&v), u.cross(&v), v.cross(&u)), (-1, 7, -7));
+        assert_eq!(u.quadrance(), 10);
+        let (u, v) = (Vector3D::new(1, 2, 3), Vector3D::new(-2, 0, 1));
+        let w = u.cross(&v);
+        assert_eq!((w.dot(&u), w.dot(&v)), (0, 0));
+        assert_eq!(
+            w.quadrance(),
+            u.quadrance() * v.quadrance() - u.dot(&v) * u.dot(&v)
+        );
+        // spreads agree with the perpendicular components
+        let (a, b) = (Vector2D::new(1.0_f64, 0.0), Vector2D::new(1.0, 1.0));
+        assert!((a.spread_to(&b).unwrap() - 0.5).abs() < 1e-12);
+        assert_eq!(
+            Vector2D::new(0.0, 0.0).spread_to(&a),
+            Err(MathError::NullVector)
+        );
+        let e = Vector3D::new(1.0, 0.0, 0.0);
+        assert_eq!(e.spread_to(&Vector3D::new(0.0, 2.0, 0.0)), Ok(1.0));
+        assert_eq!(e.spread_to(&Vector3D::new(-3.0, 0.0, 0.0)), Ok(0.0));
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv_round_trip() {
+        use rkyv::rancor::Error;
+
+        let tri = Triangle2D::new(Point2D::new(0, 0), Point2D::new(4, 0), Point2D::new(0, 3));
+        let bytes = rkyv::to_bytes::<Error>(&tri).unwrap();
+        let archived = rkyv::access::<ArchivedTriangle2D<i32>, Error>(&bytes).unwrap();
+        assert_eq!(archived.p2.x, 4);
+        assert_eq!(
+            rkyv::deserialize::<Triangle2D<i32>, Error>(archived).unwrap(),
+            tri
+        );
+
+        let v = Vector3D::new(1.5, -2.0, 0.25);
+        let bytes = rkyv::to_bytes::<Error>(&v).unwrap();
+        let back = rkyv::from_bytes::<Vector3D<f64>, Error>(&bytes).unwrap();
+        assert_eq!(back, v);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_bytemuck_cast() {
+        let points = [Point2D::new(1.0f32, 2.0), Point2D::new(3.0, 4.0)];
+        let bytes: &[u8] = bytemuck::cast_slice(&points);
+        assert_eq!(bytes.len(), 16);
+        let floats: &[f32] = bytemuck::cast_slice(&points);
+        assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0]);
+        let back: &[Point2D<f32>] = bytemuck::cast_slice(bytes);
+        assert_eq!(back, &points);
+
+        let tri: Triangle3D<i64> = bytemuck::Zeroable::zeroed();
+        assert_eq!(tri, Triangle3D::default());
+        assert_eq!(core::mem::size_of::<Triangle3D<i64>>(), 72);
+        let tet: Tetrahedron3D<i32> = bytemuck::Zeroable::zeroed();
+        assert_eq!(tet, Tetrahedron3D::default());
+        assert_eq!(bytemuck::bytes_of(&tet).len(), 48);
+    }
+
+    #[cfg(feature = "euclid")]
+    #[test]
+    fn test_euclid_round_trip() {
+        struct ScreenSpace;
+
+        let p: euclid::Point2D<i32, ScreenSpace> = euclid::point2(3, 4);
+        let ours: Point2D<i32> = p.into();
+        assert_eq!(ours, Point2D::new(3, 4));
+        let back: euclid::Point2D<i32, ScreenSpace> = ours.into();
+        assert_eq!(back, p);
+
+        let v: euclid::Vector3D<f64, ScreenSpace> = euclid::vec3(1.0, 2.0, 3.0);
+        assert_eq!(Vector3D::from(v), Vector3D::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_triangle_quadrances() {
+        let tri = Triangle2D::new(Point2D::new(0, 0), Point2D::new(4, 0), Point2D::new(0, 3));
+        assert_eq!(tri.quadrances(), (25, 9, 16));
+        assert_eq!(tri.area(), 576);
+
+        let tri = Triangle3D::new(
+            Point3D::new(0, 0, 0),
+            Point3D::new(1, 2, 2),
+            Point3D::new(0, 0, 1),
+        );
+        assert_eq!(tri.quadrances(), (6, 1, 9));
+        assert_eq!(tri.area(), 4 * 5);
+        assert_eq!(tri.normal(), Vector3D::new(2, -1, 0));
+        assert_eq!(tri.twist(&Vector3D::new(1, 0, 0)), 2);
+        assert!(!tri.is_degenerate());
+        let flat = Triangle3D::new(
+            Point3D::new(0, 0, 0),
+            Point3D::new(1, 2, 3),
+            Point3D::new(2, 4, 6),
+        );
+        assert!(flat.is_degenerate());
+        assert_eq!(flat.area(), 0);
+    }
+
+    #[test]
+    fn test_triangle_medians() {
+        let tri = Triangle2D::new(
+            Point2D::new(1.0, 1.0),
+            Point2D::new(5.0, 2.0),
+            Point2D::new(2.0, 7.0),
+        );
+        let (q_1, q_2, q_3) = tri.quadrances();
+        let (m_1, m_2, m_3) = median_quadrances(&q_1, &q_2, &q_3);
+        let quad = |(a, b): (Point2D<f64>, Point2D<f64>)| quadrance(&(b.x - a.x, b.y - a.y));
+        let [l_1, l_2, l_3] = tri.medians();
+        assert_eq!((quad(l_1), quad(l_2), quad(l_3)), (m_1, m_2, m_3));
+        // the average of the vertices, exact for integers divisible by 3
+        let g = tri.centroid();
+        assert_eq!(g, Point2D::new(8.0 / 3.0, 10.0 / 3.0));
+        let exact = Triangle2D::new(Point2D::new(0, 0), Point2D::new(6, 0), Point2D::new(3, 9));
+        assert_eq!(exact.centroid(), Point2D::new(3, 3));
+    }
+
+    #[test]
+    fn test_altitude_feet() {
+        let tri = Triangle2D::new(
+            Point2D::new(0.0, 0.0),
+            Point2D::new(4.0, 0.0),
+            Point2D::new(0.0, 3.0),
+        );
+        let [f_1, f_2, f_3] = tri.altitude_feet().unwrap();
+        // the right angle at p1 puts two feet there
+        assert_eq!((f_2, f_3), (tri.p1, tri.p1));
+        assert_eq!(f_1, Point2D::new(1.44, 1.92));
+        let flat = Triangle2D::new(Point2D::new(0, 0), Point2D::new(0, 0), Point2D::new(1, 2));
+        assert_eq!(flat.altitude_feet(), Err(MathError::DivisionByZero));
+    }
+
+    #[test]
+    fn test_circumcenter() {
+        type Q = num_rational::Ratio<i32>;
+        let p = |x: i32, y: i32| Point2D::new(Q::from(x), Q::from(y));
+        let tri = Triangle2D::new(p(1, 2), p(-3, 5), p(4, -1));
+        let o = tri.circumcenter().unwrap();
+        let quad = |a: &Point2D<Q>| quadrance(&(a.x - o.x, a.y - o.y));
+        assert_eq!(quad(&tri.p1), quad(&tri.p2));
+        assert_eq!(quad(&tri.p1), quad(&tri.p3));
+        // a right triangle has it at the midpoint of the hypotenuse
+        let right = Triangle2D::new(p(0, 0), p(4, 0), p(0, 2));
+        assert_eq!(right.circumcenter(), Ok(p(2, 1)));
+        let twice = Triangle2D::new(p(0, 0), p(1, 1), p(0, 0));
+        assert_eq!(twice.circumcenter(), Err(MathError::DegenerateTriangle));
+
+        let l = tri.euler_line().unwrap();
+        // the orthocenter 3G − 2O lies on it
+        let (g, two, three) = (tri.centroid(), Q::from(2), Q::from(3));
+        let h = Point2D::new(three * g.x - two * o.x, three * g.y - two * o.y);
+        assert_eq!(l.a * h.x + l.b * h.y + l.c, Q::from(0));
+        assert_eq!(twice.euler_line(), Err(MathError::DegenerateTriangle));
+
+        // the nine-point circle passes through the midpoints of the sides
+        // and the feet of the altitudes
+        let n = tri.nine_point_center().unwrap();
+        let q_n = tri.nine_point_quadrance().unwrap();
+        let on_circle = |a: &Point2D<Q>| quadrance(&(a.x - n.x, a.y - n.y)) == q_n;
+        assert!(tri.medians().iter().all(|(_, m)| on_circle(m)));
+        assert!(tri.altitude_feet().unwrap().iter().all(on_circle));
+        assert_eq!(
+            twice.nine_point_quadrance(),
+            Err(MathError::DegenerateTriangle)
+        );
+    }
+
+    #[test]
+    fn test_canonical_forms() {
+        type Q = num_rational::Ratio<i32>;
+        let p = |x: i32, y: i32| Point2D::new(Q::from(x), Q::from(y));
+        let tri = Triangle2D::new(p(0, 0), p(3, 1), p(1, 2));
+        let moved = Triangle2D::new(p(5, 5), p(4, 2), p(3, 4));
+        let scaled = Triangle2D::new(p(0, 0), p(-6, -2), p(-2, -4));
+        assert_eq!(tri.canonical_quadrances(), moved.canonical_quadrances());
+        assert_ne!(tri.canonical_quadrances(), scaled.canonical_quadrances());
+        assert_eq!(tri.canonical_spreads(), scaled.canonical_spreads());
+        assert_eq!(tri.primitive_quadrances(), scaled.primitive_quadrances());
+        assert_eq!(tri.primitive_quadrances(), [1, 1, 2].map(Q::from));
+        let point = Triangle2D::new(p(1, 1), p(1, 1), p(1, 1));
+        assert_eq!(point.canonical_spreads(), None);
+        assert_eq!(point.primitive_quadrances(), [Q::from(0); 3]);
+        // integer triangles bucket by shape without spreads
+        let int = Triangle2D::new(Point2D::new(0, 0), Point2D::new(0, 6), Point2D::new(3, 3));
+        assert_eq!(int.primitive_quadrances(), [1, 1, 2]);
+    }
+
+    #[test]
+    fn test_signed_area() {
+        let (a, b, c) = (
+            Point2D::new(1.0, 1.0),
+            Point2D::new(2.0, 3.5),
+            Point2D::new(-1.0, 2.0),
+        );
+        let tri = Triangle2D::new(a, b, c);
+        assert_eq!(tri.signed_area_f64(), 3.0);
+        assert_eq!(tri.signed_quadrea(), tri.area());
+        let flipped = Triangle2D::new(a, c, b);
+        assert_eq!(flipped.signed_area_f64(), -3.0);
+        assert_eq!(flipped.signed_quadrea(), -tri.area());
+        let flat = Triangle2D::new(a, a, b);
+        assert_eq!((flat.signed_area_f64(), flat.signed_quadrea()), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_rational_triangle_builder() {
+        type Q = num_rational::Ratio<i32>;
+        let p = |x: i32, y: i32| Point2D::new(Q::new(x, 7), Q::new(y, 7));
+        let tri = Triangle2D::builder()
+            .vertices(p(0, 0), p(1, 3), p(3, 1))
+            .winding(Winding::Clockwise)
+            .build()
+            .unwrap();
+        assert_eq!(tri.p2, p(1, 3));
+        let err = Triangle2D::builder()
+            .vertices(p(0, 0), p(1, 3), p(3, 1))
+            .vertex(p(5, 5))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind, MathError::InvalidInput);
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn test_approx() {
+        use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq};
+
+        let p = Point2D::new(0.1 + 0.2, 1.0);
+        assert_ne!(p, Point2D::new(0.3, 1.0));
+        assert_relative_eq!(p, Point2D::new(0.3, 1.0));
+        assert_ulps_eq!(p, Point2D::new(0.3, 1.0));
+        assert_relative_ne!(p, Point2D::new(0.3, 1.001));
+        assert_abs_diff_eq!(
+            Vector2D::new(1.0, 2.0),
+            Vector2D::new(1.05, 2.0),
+            epsilon = 0.1
+        );
+        assert_relative_eq!(
+            Line2D::new(1.0, -1.0, 0.1 * 3.0),
+            Line2D::new(1.0, -1.0, 0.3)
+        );
+
+        let tri = |d: f32| {
+            Triangle2D::new(
+                Point2D::new(0.0, 0.0),
+                Point2D::new(4.0, d),
+                Point2D::new(0.0, 3.0),
+            )
+        };
+        assert_relative_eq!(tri(0.0), tri(1e-9), epsilon = 1e-6);
+        assert_relative_ne!(tri(0.0), tri(1e-3), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_from_str() {
+        type Q = num_rational::Ratio<i32>;
+        assert_eq!("(3, 4)".parse(), Ok(Point2D::new(3, 4)));
+        assert_eq!(" 3,4 ".parse(), Ok(Point2D::new(3, 4)));
+        assert_eq!(
+            "(1/2, -3)".parse(),
+            Ok(Point2D::new(Q::new(1, 2), Q::from(-3)))
+        );
+        assert_eq!("1, 2, 3".parse(), Ok(Point3D::new(1.0, 2.0, 3.0)));
+        assert_eq!(
+            "(3, 4, 5)".parse::<Point2D<i32>>(),
+            Err(MathError::InvalidInput)
+        );
+        assert_eq!(
+            "(3; 4)".parse::<Point2D<i32>>(),
+            Err(MathError::InvalidInput)
+        );
+        assert_eq!(
+            "(3, 4".parse::<Point2D<i32>>(),
+            Err(MathError::InvalidInput)
+        );
+
+        assert_eq!("2x + 3y - 5 = 0".parse(), Ok(Line2D::new(2, 3, -5)));
+        assert_eq!("5 - y".parse(), Ok(Line2D::new(0, -1, 5)));
+        assert_eq!("- 2*x + 1".parse(), Ok(Line2D::new(-2, 0, 1)));
+        assert_eq!(
+            "3/2x - 1/3y + 1 = 0".parse(),
+            Ok(Line2D::new(Q::new(3, 2), Q::new(-1, 3), Q::from(1)))
+        );
+        assert_eq!("1e-1x + y".parse(), Ok(Line2D::new(0.1, 1.0, 0.0)));
+        assert_eq!("2,3,-5".parse(), Ok(Line2D::new(2, 3, -5)));
+        for bad in ["x + x", "2x + 3y = 1", "2z + 1", "x +", "", "1, 2"] {
+            assert_eq!(
+                bad.parse::<Line2D<i32>>(),
+                Err(MathError::InvalidInput),
+                "{}",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        type Q = num_rational::Ratio<i32>;
+        let p = Point2D::new(Q::new(3, 2), Q::from(5));
+        assert_eq!(p.to_string(), "Point(3/2, 5)");
+        assert_eq!(p.to_string().parse(), Ok(p));
+        assert_eq!(
+            format!("{:.2}", Vector3D::new(1.0, 0.5, -2.0)),
+            "Vector(1.00, 0.50, -2.00)"
+        );
+        assert_eq!(
+            Point3D::new(1, 2, 3).to_string().parse(),
+            Ok(Point3D::new(1, 2, 3))
+        );
+
+        let cases = [
+            (Line2D::new(2, 3, -5), "Line: 2x + 3y - 5 = 0"),
+            (Line2D::new(-1, 1, 0), "Line: -x + y = 0"),
+            (Line2D::new(0, -1, 1), "Line: -y + 1 = 0"),
+            (Line2D::new(0, 0, 4), "Line: 4 = 0"),
+            (Line2D::new(0, 0, 0), "Line: 0 = 0"),
+        ];
+        for (line, text) in cases {
+            assert_eq!(line.to_string(), text);
+            assert_eq!(text.parse(), Ok(line));
+        }
+        assert_eq!("= 0".parse::<Line2D<i32>>(), Err(MathError::InvalidInput));
+        let line = Line2D::new(Q::new(1, 2), Q::from(0), Q::new(-1, 3));
+        assert_eq!(line.to_string(), "Line: 1/2x - 1/3 = 0");
+        assert_eq!(line.to_string().parse(), Ok(line));
+        assert_eq!(
+            format!("{:.1}", Line2D::new(1.0, 2.25, 0.0)),
+            "Line: x + 2.2y = 0"
+        );
+
+        let tri = Triangle2D::new(
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.5, 0.0),
+            Point2D::new(0.0, 2.0),
+        );
+        assert_eq!(
+            format!("{:.1}", tri.summary()),
+            "Triangle(Point(0.0, 0.0), Point(1.5, 0.0), Point(0.0, 2.0)): Q = (6.2, 4.0, 2.2), A = 36.0"
+        );
+    }
+}
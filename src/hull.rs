@@ -0,0 +1,128 @@
+//! Exact convex hulls.
+//!
+//! The hull is computed with Andrew's monotone chain, whose only geometric
+//! test is the sign of a twist (twice a signed area). Over integers and
+//! rationals that sign is exact, so the result never depends on a tolerance
+//! and nearly-collinear inputs are handled correctly.
+use crate::geometry::Point2D;
+use crate::traits::AffineOps;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::From;
+use core::ops::{Add, Mul, Sub};
+
+/// The function `convex_hull` computes the convex hull of a set of points.
+///
+/// Arguments:
+///
+/// * `points`: The input points, in any order, possibly with duplicates.
+///
+/// Returns:
+///
+/// The hull vertices in counterclockwise order, starting from the
+/// lexicographically smallest point. Points lying in the interior of a hull
+/// edge are omitted. Fewer than three points are returned (deduplicated)
+/// when the input has fewer than three distinct points or is collinear, in
+/// which case the result holds the two extreme points.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::geometry::Point2D;
+/// use rat_trig_rs::hull::convex_hull;
+/// let points = [
+///     Point2D::new(0, 0),
+///     Point2D::new(2, 2),
+///     Point2D::new(1, 1),
+///     Point2D::new(2, 0),
+///     Point2D::new(0, 2),
+///     Point2D::new(1, 0),
+/// ];
+/// let hull = convex_hull(&points);
+/// assert_eq!(
+///     hull,
+///     vec![Point2D::new(0, 0), Point2D::new(2, 0), Point2D::new(2, 2), Point2D::new(0, 2)]
+/// );
+/// ```
+pub fn convex_hull<T>(points: &[Point2D<T>]) -> Vec<Point2D<T>>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| {
+        a.x.partial_cmp(&b.x)
+            .unwrap_or(Ordering::Equal)
+            .then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+    });
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let zero = T::from(0);
+    let turns_left = |hull: &[Point2D<T>], p: &Point2D<T>| {
+        hull[hull.len() - 2].twist(&hull[hull.len() - 1], p) > zero
+    };
+    let mut hull: Vec<Point2D<T>> = Vec::with_capacity(2 * sorted.len());
+    for p in sorted.iter() {
+        while hull.len() >= 2 && !turns_left(&hull, p) {
+            hull.pop();
+        }
+        hull.push(*p);
+    }
+    let lower_len = hull.len() + 1;
+    for p in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_len && !turns_left(&hull, p) {
+            hull.pop();
+        }
+        hull.push(*p);
+    }
+    hull.pop(); // the first point is repeated at the end
+    hull
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+
+    #[test]
+    fn test_collinear_and_small() {
+        let empty: [Point2D<i32>; 0] = [];
+        assert!(convex_hull(&empty).is_empty());
+        assert_eq!(
+            convex_hull(&[Point2D::new(1, 1); 3]),
+            vec![Point2D::new(1, 1)]
+        );
+        let line = [Point2D::new(2, 2), Point2D::new(0, 0), Point2D::new(1, 1)];
+        assert_eq!(
+            convex_hull(&line),
+            vec![Point2D::new(0, 0), Point2D::new(2, 2)]
+        );
+    }
+
+    #[test]
+    fn test_nearly_collinear_rational() {
+        type Q = Ratio<i32>;
+        let p = |x: i32, y: i32, d: i32| Point2D::new(Q::new(x, d), Q::new(y, d));
+        // the middle point is just barely above the segment
+        let points = [p(0, 0, 1), p(1, 1, 1), p(1000, 1001, 2000)];
+        assert_eq!(convex_hull(&points).len(), 3);
+        let points = [p(0, 0, 1), p(1, 1, 1), p(1000, 1000, 2000)];
+        assert_eq!(convex_hull(&points).len(), 2);
+    }
+
+    #[test]
+    fn test_hull_contains_all() {
+        let points: Vec<Point2D<i32>> = (0..50)
+            .map(|i| Point2D::new((i * 37) % 23 - 11, (i * 53) % 19 - 9))
+            .collect();
+        let hull = convex_hull(&points);
+        for w in 0..hull.len() {
+            let (a, b) = (hull[w], hull[(w + 1) % hull.len()]);
+            for p in &points {
+                assert!(a.twist(&b, p) >= 0);
+            }
+        }
+    }
+}
@@ -1,4 +1,48 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod analysis;
+pub mod chromo;
+pub mod circle;
+pub mod collision;
+pub mod const_trigonom;
+#[cfg(feature = "std")]
+pub mod csv;
+pub mod error;
+#[cfg(feature = "std")]
+pub mod fan;
+pub mod geometry;
+#[cfg(feature = "alloc")]
+pub mod hull;
+pub mod linalg;
+pub mod logging;
+pub mod metric;
+#[cfg(feature = "alloc")]
+pub mod polygon;
+pub mod prelude;
+pub mod projective;
+pub mod quadext;
+#[cfg(feature = "rand")]
+pub mod random;
+#[cfg(feature = "alloc")]
+pub mod region;
+pub mod relativity;
+pub mod segment;
+#[cfg(feature = "serde")]
+pub mod serde_compact;
+#[cfg(feature = "alloc")]
+pub mod simplify;
+pub mod solver;
+pub mod spread_poly;
+#[cfg(feature = "svg")]
+pub mod svg;
+pub mod traits;
+pub mod transform;
 pub mod trigonom;
+#[cfg(feature = "alloc")]
+pub mod wkt;
 
 pub fn add(left: usize, right: usize) -> usize {
     left + right
@@ -0,0 +1,734 @@
+//! Exact small linear algebra helpers.
+//!
+//! Many rational trigonometry constructions (intersections of lines,
+//! circumcenters, conics through points, ...) boil down to a 2×2 or 3×3
+//! linear system. Over the rationals these can be solved exactly with
+//! determinants and Cramer's rule, without any pivoting or tolerance.
+//!
+//! Matrices are stored row-major as plain arrays, i.e. `m[row][col]`.
+use crate::error::MathError;
+use crate::logging::{degenerate, targets, Loggable};
+use core::convert::From;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// The function `det2` calculates the determinant of a 2×2 matrix.
+///
+/// Arguments:
+///
+/// * `m`: The matrix, stored row-major.
+///
+/// Returns:
+///
+/// The determinant `m[0][0] * m[1][1] - m[0][1] * m[1][0]`.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::linalg::det2;
+/// assert_eq!(det2(&[[1, 2], [3, 4]]), -2);
+/// ```
+#[inline]
+pub fn det2<T>(m: &[[T; 2]; 2]) -> T
+where
+    T: Copy + Sub<Output = T> + Mul<Output = T>,
+{
+    m[0][0] * m[1][1] - m[0][1] * m[1][0]
+}
+
+/// The function `det3` calculates the determinant of a 3×3 matrix by cofactor
+/// expansion along the first row.
+///
+/// Arguments:
+///
+/// * `m`: The matrix, stored row-major.
+///
+/// Returns:
+///
+/// The determinant of `m`.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::linalg::det3;
+/// assert_eq!(det3(&[[2, 0, 1], [1, 3, 2], [1, 1, 2]]), 6);
+/// ```
+#[inline]
+pub fn det3<T>(m: &[[T; 3]; 3]) -> T
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+/// The function `adjugate2` calculates the adjugate (transposed cofactor
+/// matrix) of a 2×2 matrix, so that `m * adj(m) = det(m) * I`.
+///
+/// Arguments:
+///
+/// * `m`: The matrix, stored row-major.
+///
+/// Returns:
+///
+/// The adjugate of `m`.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::linalg::adjugate2;
+/// assert_eq!(adjugate2(&[[1, 2], [3, 4]]), [[4, -2], [-3, 1]]);
+/// ```
+#[inline]
+pub fn adjugate2<T>(m: &[[T; 2]; 2]) -> [[T; 2]; 2]
+where
+    T: Copy + Neg<Output = T>,
+{
+    [[m[1][1], -m[0][1]], [-m[1][0], m[0][0]]]
+}
+
+/// The function `adjugate3` calculates the adjugate (transposed cofactor
+/// matrix) of a 3×3 matrix, so that `m * adj(m) = det(m) * I`.
+///
+/// Arguments:
+///
+/// * `m`: The matrix, stored row-major.
+///
+/// Returns:
+///
+/// The adjugate of `m`.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::linalg::adjugate3;
+/// let adj = adjugate3(&[[2, 0, 1], [1, 3, 2], [1, 1, 2]]);
+/// assert_eq!(adj, [[4, 1, -3], [0, 3, -3], [-2, -2, 6]]);
+/// ```
+pub fn adjugate3<T>(m: &[[T; 3]; 3]) -> [[T; 3]; 3]
+where
+    T: Copy + Sub<Output = T> + Mul<Output = T>,
+{
+    let cof =
+        |r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+    [
+        [cof(1, 2, 1, 2), cof(0, 2, 2, 1), cof(0, 1, 1, 2)],
+        [cof(1, 2, 2, 0), cof(0, 2, 0, 2), cof(0, 1, 2, 0)],
+        [cof(1, 2, 0, 1), cof(0, 2, 1, 0), cof(0, 1, 0, 1)],
+    ]
+}
+
+/// The function `minor3` returns the 3×3 submatrix of a 4×4 matrix obtained
+/// by deleting row `row` and column `col`.
+fn minor3<T: Copy>(m: &[[T; 4]; 4], row: usize, col: usize) -> [[T; 3]; 3] {
+    let mut result = [[m[0][0]; 3]; 3];
+    for (r, src) in (0..4).filter(|&r| r != row).enumerate() {
+        for (c, sc) in (0..4).filter(|&c| c != col).enumerate() {
+            result[r][c] = m[src][sc];
+        }
+    }
+    result
+}
+
+/// The function `det4` calculates the determinant of a 4×4 matrix by cofactor
+/// expansion along the first row.
+///
+/// Arguments:
+///
+/// * `m`: The matrix, stored row-major.
+///
+/// Returns:
+///
+/// The determinant of `m`.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::linalg::det4;
+/// let m = [[1, 0, 0, 0], [0, 2, 0, 0], [0, 0, 3, 0], [5, 6, 7, 4]];
+/// assert_eq!(det4(&m), 24);
+/// ```
+pub fn det4<T>(m: &[[T; 4]; 4]) -> T
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    m[0][0] * det3(&minor3(m, 0, 0)) - m[0][1] * det3(&minor3(m, 0, 1))
+        + m[0][2] * det3(&minor3(m, 0, 2))
+        - m[0][3] * det3(&minor3(m, 0, 3))
+}
+
+/// The function `adjugate4` calculates the adjugate (transposed cofactor
+/// matrix) of a 4×4 matrix, so that `m * adj(m) = det(m) * I`.
+///
+/// Arguments:
+///
+/// * `m`: The matrix, stored row-major.
+///
+/// Returns:
+///
+/// The adjugate of `m`.
+pub fn adjugate4<T>(m: &[[T; 4]; 4]) -> [[T; 4]; 4]
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Neg<Output = T>,
+{
+    let mut adj = *m;
+    for (i, row) in adj.iter_mut().enumerate() {
+        for (j, entry) in row.iter_mut().enumerate() {
+            let cofactor = det3(&minor3(m, j, i));
+            *entry = if (i + j) % 2 == 0 {
+                cofactor
+            } else {
+                -cofactor
+            };
+        }
+    }
+    adj
+}
+
+/// The function `inverse2` calculates the exact inverse of a 2×2 matrix as
+/// its adjugate divided by its determinant.
+///
+/// Arguments:
+///
+/// * `m`: The matrix, stored row-major.
+///
+/// Returns:
+///
+/// The inverse of `m`, or `MathError::DivisionByZero` if `m` is singular.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::linalg::inverse2;
+/// let m = [[Rational32::from(1), Rational32::from(2)], [Rational32::from(3), Rational32::from(4)]];
+/// let inv = inverse2(&m).unwrap();
+/// assert_eq!(inv[0][0], Rational32::from(-2));
+/// assert_eq!(inv[1][0], Rational32::new(3, 2));
+/// ```
+pub fn inverse2<T>(m: &[[T; 2]; 2]) -> Result<[[T; 2]; 2], MathError>
+where
+    T: Copy
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + PartialEq
+        + From<i32>
+        + Loggable,
+{
+    let det = det2(m);
+    if det == T::from(0) {
+        return Err(degenerate(
+            targets::LINALG,
+            "inverse2",
+            MathError::DivisionByZero,
+            m,
+        ));
+    }
+    Ok(adjugate2(m).map(|row| row.map(|x| x / det)))
+}
+
+/// The function `inverse3` calculates the exact inverse of a 3×3 matrix as
+/// its adjugate divided by its determinant.
+///
+/// Arguments:
+///
+/// * `m`: The matrix, stored row-major.
+///
+/// Returns:
+///
+/// The inverse of `m`, or `MathError::DivisionByZero` if `m` is singular.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::linalg::inverse3;
+/// let r = |x: i32| Rational32::from(x);
+/// let m = [[r(2), r(0), r(0)], [r(0), r(4), r(0)], [r(0), r(0), r(1)]];
+/// let inv = inverse3(&m).unwrap();
+/// assert_eq!(inv[1][1], Rational32::new(1, 4));
+/// ```
+pub fn inverse3<T>(m: &[[T; 3]; 3]) -> Result<[[T; 3]; 3], MathError>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>
+        + Loggable,
+{
+    let det = det3(m);
+    if det == T::from(0) {
+        return Err(degenerate(
+            targets::LINALG,
+            "inverse3",
+            MathError::DivisionByZero,
+            m,
+        ));
+    }
+    Ok(adjugate3(m).map(|row| row.map(|x| x / det)))
+}
+
+/// The function `solve2` solves the linear system `m * x = b` with Cramer's
+/// rule.
+///
+/// Arguments:
+///
+/// * `m`: The 2×2 coefficient matrix, stored row-major.
+/// * `b`: The right-hand side vector.
+///
+/// Returns:
+///
+/// The unique solution `x`, or `MathError::DivisionByZero` if `m` is singular
+/// (the system has no solution or infinitely many).
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::linalg::solve2;
+/// let r = |x: i32| Rational32::from(x);
+/// // x + y = 3, x - y = 0
+/// let x = solve2(&[[r(1), r(1)], [r(1), r(-1)]], &[r(3), r(0)]).unwrap();
+/// assert_eq!(x, [Rational32::new(3, 2), Rational32::new(3, 2)]);
+/// ```
+pub fn solve2<T>(m: &[[T; 2]; 2], b: &[T; 2]) -> Result<[T; 2], MathError>
+where
+    T: Copy
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>
+        + Loggable,
+{
+    let det = det2(m);
+    if det == T::from(0) {
+        return Err(degenerate(
+            targets::LINALG,
+            "solve2",
+            MathError::DivisionByZero,
+            &(m, b),
+        ));
+    }
+    let det_x = det2(&[[b[0], m[0][1]], [b[1], m[1][1]]]);
+    let det_y = det2(&[[m[0][0], b[0]], [m[1][0], b[1]]]);
+    Ok([det_x / det, det_y / det])
+}
+
+/// The function `solve3` solves the linear system `m * x = b` with Cramer's
+/// rule.
+///
+/// Arguments:
+///
+/// * `m`: The 3×3 coefficient matrix, stored row-major.
+/// * `b`: The right-hand side vector.
+///
+/// Returns:
+///
+/// The unique solution `x`, or `MathError::DivisionByZero` if `m` is singular.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::linalg::solve3;
+/// let r = |x: i32| Rational32::from(x);
+/// let m = [[r(2), r(0), r(1)], [r(1), r(3), r(2)], [r(1), r(1), r(2)]];
+/// let x = solve3(&m, &[r(3), r(6), r(4)]).unwrap();
+/// assert_eq!(x, [r(1), r(1), r(1)]);
+/// ```
+pub fn solve3<T>(m: &[[T; 3]; 3], b: &[T; 3]) -> Result<[T; 3], MathError>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>
+        + Loggable,
+{
+    let det = det3(m);
+    if det == T::from(0) {
+        return Err(degenerate(
+            targets::LINALG,
+            "solve3",
+            MathError::DivisionByZero,
+            &(m, b),
+        ));
+    }
+    let mut x = [T::from(0); 3];
+    for (col, xi) in x.iter_mut().enumerate() {
+        let mut mc = *m;
+        for (row, bi) in b.iter().enumerate() {
+            mc[row][col] = *bi;
+        }
+        *xi = det3(&mc) / det;
+    }
+    Ok(x)
+}
+
+/// The function `transpose` returns the transpose of a square matrix.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::linalg::transpose;
+/// assert_eq!(transpose(&[[1, 2], [3, 4]]), [[1, 3], [2, 4]]);
+/// ```
+pub fn transpose<T: Copy, const N: usize>(m: &[[T; N]; N]) -> [[T; N]; N] {
+    let mut result = *m;
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, entry) in row.iter_mut().enumerate() {
+            *entry = m[j][i];
+        }
+    }
+    result
+}
+
+/// The function `mat_mul` multiplies two square matrices.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::linalg::mat_mul;
+/// assert_eq!(mat_mul(&[[1, 2], [3, 4]], &[[0, 1], [1, 0]]), [[2, 1], [4, 3]]);
+/// ```
+pub fn mat_mul<T, const N: usize>(a: &[[T; N]; N], b: &[[T; N]; N]) -> [[T; N]; N]
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + From<i32>,
+{
+    let mut result = [[T::from(0); N]; N];
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, entry) in row.iter_mut().enumerate() {
+            *entry = (0..N).fold(T::from(0), |acc, k| acc + a[i][k] * b[k][j]);
+        }
+    }
+    result
+}
+
+/// The function `mat_vec_mul` multiplies a square matrix by a column vector.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::linalg::mat_vec_mul;
+/// assert_eq!(mat_vec_mul(&[[1, 2], [3, 4]], &[1, -1]), [-1, -1]);
+/// ```
+pub fn mat_vec_mul<T, const N: usize>(m: &[[T; N]; N], v: &[T; N]) -> [T; N]
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + From<i32>,
+{
+    let mut result = [T::from(0); N];
+    for (i, entry) in result.iter_mut().enumerate() {
+        *entry = (0..N).fold(T::from(0), |acc, k| acc + m[i][k] * v[k]);
+    }
+    result
+}
+
+/// A 2×2 matrix, wrapping the row-major arrays used by the functions above
+/// so that products can be written with `*`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Mat2<T> {
+    pub rows: [[T; 2]; 2],
+}
+
+/// A 3×3 matrix, wrapping the row-major arrays used by the functions above
+/// so that products can be written with `*`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Mat3<T> {
+    pub rows: [[T; 3]; 3],
+}
+
+macro_rules! impl_mat {
+    ($mat:ident, $n:literal) => {
+        impl<T> $mat<T> {
+            /// Creates a matrix from its rows.
+            #[inline]
+            pub const fn new(rows: [[T; $n]; $n]) -> Self {
+                $mat { rows }
+            }
+        }
+
+        impl<T: Copy> $mat<T> {
+            /// The transpose.
+            #[inline]
+            pub fn transpose(&self) -> Self {
+                $mat::new(transpose(&self.rows))
+            }
+        }
+
+        impl<T> $mat<T>
+        where
+            T: Copy + Add<Output = T> + Mul<Output = T> + From<i32>,
+        {
+            /// The identity matrix.
+            pub fn identity() -> Self {
+                let mut rows = [[T::from(0); $n]; $n];
+                for (i, row) in rows.iter_mut().enumerate() {
+                    row[i] = T::from(1);
+                }
+                $mat::new(rows)
+            }
+
+            /// The product with a column vector.
+            #[inline]
+            pub fn mul_vec(&self, v: &[T; $n]) -> [T; $n] {
+                mat_vec_mul(&self.rows, v)
+            }
+        }
+
+        impl<T> From<[[T; $n]; $n]> for $mat<T> {
+            #[inline]
+            fn from(rows: [[T; $n]; $n]) -> Self {
+                $mat::new(rows)
+            }
+        }
+
+        impl<T> Mul for $mat<T>
+        where
+            T: Copy + Add<Output = T> + Mul<Output = T> + From<i32>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                $mat::new(mat_mul(&self.rows, &rhs.rows))
+            }
+        }
+    };
+}
+
+impl_mat!(Mat2, 2);
+impl_mat!(Mat3, 3);
+
+impl<T> Mat2<T>
+where
+    T: Copy + Sub<Output = T> + Mul<Output = T>,
+{
+    /// The determinant; see [`det2`].
+    #[inline]
+    pub fn determinant(&self) -> T {
+        det2(&self.rows)
+    }
+}
+
+impl<T> Mat2<T>
+where
+    T: Copy
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// The exact inverse; see [`inverse2`].
+    ///
+    /// Returns `MathError::DivisionByZero` if the matrix is singular.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::linalg::Mat2;
+    /// let m = Mat2::new([[2, 1], [1, 1]].map(|row| row.map(Rational32::from)));
+    /// assert_eq!(m * m.try_inverse().unwrap(), Mat2::identity());
+    /// ```
+    #[inline]
+    pub fn try_inverse(&self) -> Result<Self, MathError>
+    where
+        T: Loggable,
+    {
+        inverse2(&self.rows).map(Mat2::new)
+    }
+
+    /// Solves `self * x = b`; see [`solve2`].
+    #[inline]
+    pub fn solve(&self, b: &[T; 2]) -> Result<[T; 2], MathError>
+    where
+        T: Loggable,
+    {
+        solve2(&self.rows, b)
+    }
+}
+
+impl<T> Mat3<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// The determinant; see [`det3`].
+    #[inline]
+    pub fn determinant(&self) -> T {
+        det3(&self.rows)
+    }
+}
+
+impl<T> Mat3<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// The exact inverse; see [`inverse3`].
+    ///
+    /// Returns `MathError::DivisionByZero` if the matrix is singular.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::linalg::Mat3;
+    /// let m = Mat3::new([[2, 0, 1], [1, 3, 2], [1, 1, 2]].map(|row| row.map(Rational32::from)));
+    /// assert_eq!(m.determinant(), Rational32::from(6));
+    /// assert_eq!(m.try_inverse().unwrap() * m, Mat3::identity());
+    /// ```
+    #[inline]
+    pub fn try_inverse(&self) -> Result<Self, MathError>
+    where
+        T: Loggable,
+    {
+        inverse3(&self.rows).map(Mat3::new)
+    }
+
+    /// Solves `self * x = b`; see [`solve3`].
+    #[inline]
+    pub fn solve(&self, b: &[T; 3]) -> Result<[T; 3], MathError>
+    where
+        T: Loggable,
+    {
+        solve3(&self.rows, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+
+    type Q = Ratio<i32>;
+
+    fn q(n: i32) -> Q {
+        Q::from_integer(n)
+    }
+
+    #[test]
+    fn test_det() {
+        assert_eq!(det2(&[[1, 2], [3, 4]]), -2);
+        assert_eq!(det3(&[[1, 2, 3], [4, 5, 6], [7, 8, 9]]), 0);
+        assert_eq!(
+            det3(&[[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]]),
+            6.0
+        );
+    }
+
+    fn mul3<T>(a: &[[T; 3]; 3], b: &[[T; 3]; 3]) -> [[T; 3]; 3]
+    where
+        T: Copy + Add<Output = T> + Mul<Output = T>,
+    {
+        let entry = |i: usize, j: usize| a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j];
+        [
+            [entry(0, 0), entry(0, 1), entry(0, 2)],
+            [entry(1, 0), entry(1, 1), entry(1, 2)],
+            [entry(2, 0), entry(2, 1), entry(2, 2)],
+        ]
+    }
+
+    #[test]
+    fn test_adjugate_identity() {
+        let m = [[3, 1, 4], [1, 5, 9], [2, 6, 5]];
+        let det = det3(&m);
+        assert_eq!(
+            mul3(&m, &adjugate3(&m)),
+            [[det, 0, 0], [0, det, 0], [0, 0, det]]
+        );
+    }
+
+    #[test]
+    fn test_inverse() {
+        let m = [[q(3), q(1), q(4)], [q(1), q(5), q(9)], [q(2), q(6), q(5)]];
+        let inv = inverse3(&m).unwrap();
+        assert_eq!(
+            mul3(&m, &inv),
+            [[q(1), q(0), q(0)], [q(0), q(1), q(0)], [q(0), q(0), q(1)]]
+        );
+        let m2 = [[q(1), q(2)], [q(3), q(4)]];
+        let inv2 = inverse2(&m2).unwrap();
+        assert_eq!(inv2, [[q(-2), q(1)], [Q::new(3, 2), Q::new(-1, 2)]]);
+    }
+
+    #[test]
+    fn test_singular() {
+        assert_eq!(
+            inverse2(&[[q(1), q(2)], [q(2), q(4)]]),
+            Err(MathError::DivisionByZero)
+        );
+        let m = [[q(1), q(2), q(3)], [q(4), q(5), q(6)], [q(7), q(8), q(9)]];
+        assert_eq!(inverse3(&m), Err(MathError::DivisionByZero));
+        assert_eq!(
+            solve3(&m, &[q(1), q(1), q(1)]),
+            Err(MathError::DivisionByZero)
+        );
+        assert_eq!(
+            solve2(&[[q(1), q(1)], [q(2), q(2)]], &[q(1), q(2)]),
+            Err(MathError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_solve() {
+        let x = solve2(&[[q(2), q(1)], [q(1), q(3)]], &[q(1), q(2)]).unwrap();
+        assert_eq!(x, [Q::new(1, 5), Q::new(3, 5)]);
+        let m = [[q(1), q(1), q(1)], [q(0), q(2), q(5)], [q(2), q(5), q(-1)]];
+        let x = solve3(&m, &[q(6), q(-4), q(27)]).unwrap();
+        assert_eq!(x, [q(5), q(3), q(-2)]);
+    }
+
+    #[test]
+    fn test_det4_adjugate4() {
+        let m = [[2, 0, 1, 3], [1, 3, 2, 0], [1, 1, 2, 1], [0, 4, 1, 5]];
+        let det = det4(&m);
+        let mut expected = [[0; 4]; 4];
+        for (i, row) in expected.iter_mut().enumerate() {
+            row[i] = det;
+        }
+        assert_eq!(mat_mul(&m, &adjugate4(&m)), expected);
+        assert_eq!(det4(&transpose(&m)), det);
+    }
+
+    #[test]
+    fn test_mat2_mat3() {
+        let a = Mat2::new([[q(1), q(2)], [q(3), q(4)]]);
+        let b = Mat2::from([[q(0), q(1)], [q(1), q(0)]]);
+        assert_eq!((a * b).rows, mat_mul(&a.rows, &b.rows));
+        assert_eq!(a.transpose().rows, [[q(1), q(3)], [q(2), q(4)]]);
+        assert_eq!(a.determinant(), q(-2));
+        assert_eq!(a * a.try_inverse().unwrap(), Mat2::identity());
+        assert_eq!(a.solve(&a.mul_vec(&[q(5), q(-7)])), Ok([q(5), q(-7)]));
+        let singular = Mat2::new([[q(1), q(2)], [q(2), q(4)]]);
+        assert_eq!(singular.try_inverse(), Err(MathError::DivisionByZero));
+
+        let m = Mat3::new([[q(1), q(2), q(0)], [q(0), q(1), q(3)], [q(4), q(0), q(1)]]);
+        assert_eq!(m.determinant(), q(25));
+        assert_eq!((m * m.transpose()).transpose(), m * m.transpose());
+        let inv = m.try_inverse().unwrap();
+        assert_eq!(inv * m, Mat3::identity());
+        assert_eq!((m * m).try_inverse(), Ok(inv * inv));
+        assert_eq!(
+            m.solve(&m.mul_vec(&[q(1), q(0), q(-2)])),
+            Ok([q(1), q(0), q(-2)])
+        );
+        let flat = Mat3::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(flat.determinant(), 0);
+        assert_eq!(Mat3::<i32>::identity() * flat, flat);
+    }
+}
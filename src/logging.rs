@@ -0,0 +1,177 @@
+//! Diagnostic events for degenerate inputs.
+//!
+//! With the `log` feature enabled, every function that rejects a degenerate
+//! input (a singular matrix, a null vector, collinear triangle vertices, a
+//! self-intersecting polygon, ...) emits a `debug` event through the
+//! [`log`](https://docs.rs/log) facade before returning its error. The event
+//! names the operation, the error kind and the offending values, e.g.
+//!
+//! ```text
+//! DEBUG rat_trig::linalg: inverse2: division by zero for [[1, 2], [2, 4]]
+//! DEBUG rat_trig::metric: BilinearForm2D::cross: null vector for ((1, 1), (1, 0))
+//! ```
+//!
+//! To print the values, the functions that log require their coefficient
+//! type to implement [`Loggable`]. That is `Debug` with the feature and
+//! nothing without it, so code that never enables `log` can keep using
+//! coefficient types without a `Debug` implementation.
+//!
+//! Each module logs to its own target, listed in [`targets`], all below
+//! [`TARGET`], so a filter such as `rat_trig::metric=debug` selects one
+//! subsystem and `rat_trig=debug` selects them all.
+//!
+//! Without the feature the hooks are empty inline functions, so they cost
+//! nothing. With it, the events go through the `log` macros, which honor
+//! `log`'s `max_level_*` and `release_max_level_*` features: enabling one of
+//! them on the `log` dependency anywhere in the build turns the calls below
+//! that level into dead code. Since all events here are `debug`, any level
+//! up to `info` compiles them out entirely:
+//!
+//! ```toml
+//! log = { version = "0.4", features = ["max_level_info"] }
+//! ```
+use crate::error::{GeomError, MathError};
+
+/// The bound on values attached to log events: [`Debug`](core::fmt::Debug)
+/// with the `log` feature, and satisfied by every type without it.
+#[cfg(feature = "log")]
+pub trait Loggable: core::fmt::Debug {}
+
+#[cfg(feature = "log")]
+impl<T: core::fmt::Debug + ?Sized> Loggable for T {}
+
+/// The bound on values attached to log events: [`Debug`](core::fmt::Debug)
+/// with the `log` feature, and satisfied by every type without it.
+#[cfg(not(feature = "log"))]
+pub trait Loggable {}
+
+#[cfg(not(feature = "log"))]
+impl<T: ?Sized> Loggable for T {}
+
+/// The common prefix of every target used by this crate.
+pub const TARGET: &str = "rat_trig";
+
+/// The log target of each module.
+pub mod targets {
+    /// Circle predicates and constructions.
+    pub const CIRCLE: &str = "rat_trig::circle";
+    /// Points, lines and triangles, including `Triangle2D::builder`.
+    pub const GEOMETRY: &str = "rat_trig::geometry";
+    /// Determinants, inverses and linear solvers.
+    pub const LINALG: &str = "rat_trig::linalg";
+    /// Bilinear forms and their quadrances, spreads and crosses.
+    pub const METRIC: &str = "rat_trig::metric";
+    /// `Polygon2D::builder` and the polygon predicates.
+    pub const POLYGON: &str = "rat_trig::polygon";
+    /// Homogeneous points, their incidences and cross-ratios.
+    pub const PROJECTIVE: &str = "rat_trig::projective";
+    /// Velocity composition in the red geometry.
+    pub const RELATIVITY: &str = "rat_trig::relativity";
+    /// The triangle solvers.
+    pub const SOLVER: &str = "rat_trig::solver";
+    /// The affine operations of `AffineOps`.
+    pub const TRAITS: &str = "rat_trig::traits";
+    /// Affine, projective and isometric maps.
+    pub const TRANSFORM: &str = "rat_trig::transform";
+    /// The formulas of `trigonom`.
+    pub const TRIGONOM: &str = "rat_trig::trigonom";
+}
+
+/// Reports that `operation` rejected the input `values` with `kind`, and
+/// returns `kind` so the call site can stay a one-liner.
+#[inline]
+pub(crate) fn degenerate(
+    target: &'static str,
+    operation: &'static str,
+    kind: MathError,
+    values: &dyn Loggable,
+) -> MathError {
+    #[cfg(feature = "log")]
+    log::debug!(target: target, "{}: {} for {:?}", operation, kind, values);
+    #[cfg(not(feature = "log"))]
+    let _ = (target, operation, values);
+    kind
+}
+
+/// Reports a [`GeomError`] for the input `values` and returns it.
+#[inline]
+pub(crate) fn rejected(target: &'static str, err: GeomError, values: &dyn Loggable) -> GeomError {
+    #[cfg(feature = "log")]
+    log::debug!(target: target, "{} for {:?}", err, values);
+    #[cfg(not(feature = "log"))]
+    let _ = (target, values);
+    err
+}
+
+#[cfg(all(test, feature = "log"))]
+mod tests {
+    use super::*;
+    use crate::geometry::{Point2D, Triangle2D};
+    use crate::linalg::inverse2;
+    use crate::metric::BilinearForm2D;
+    use std::string::String;
+    use std::sync::Mutex;
+    use std::vec::Vec;
+
+    static EVENTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    struct Capture;
+
+    impl log::Log for Capture {
+        fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+            metadata.target().starts_with(TARGET)
+        }
+
+        fn log(&self, record: &log::Record<'_>) {
+            if self.enabled(record.metadata()) {
+                let line =
+                    std::format!("{} {}: {}", record.level(), record.target(), record.args());
+                EVENTS.lock().unwrap().push(line);
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    #[test]
+    fn test_events() {
+        static LOGGER: Capture = Capture;
+        let _ = log::set_logger(&LOGGER);
+        log::set_max_level(log::LevelFilter::Debug);
+
+        assert!(inverse2(&[[1, 2], [2, 4]]).is_err());
+        let p = Point2D::new(0, 0);
+        assert!(Triangle2D::builder().vertices(p, p, p).build().is_err());
+        assert!(BilinearForm2D::red().spread(&(1, 1), &(1, 0)).is_err());
+        assert!(BilinearForm2D::red()
+            .line_spread(&(1, 1, 0), &(1, 0, 0))
+            .is_err());
+
+        let events = EVENTS.lock().unwrap();
+        if log::STATIC_MAX_LEVEL < log::LevelFilter::Debug {
+            // compiled out by a `max_level_*` feature of `log`
+            assert!(events.is_empty());
+            return;
+        }
+        // other tests may log concurrently, so only look for ours
+        let has = |line: &str| events.iter().any(|e| e == line);
+        assert!(has(
+            "DEBUG rat_trig::linalg: inverse2: division by zero for [[1, 2], [2, 4]]"
+        ));
+        assert!(has(
+            "DEBUG rat_trig::geometry: Triangle2D::build (collinear vertices): degenerate \
+             triangle for (Point2D { x: 0, y: 0 }, Point2D { x: 0, y: 0 }, Point2D { x: 0, y: 0 })"
+        ));
+        assert!(has(
+            "DEBUG rat_trig::metric: BilinearForm2D::cross: null vector for ((1, 1), (1, 0))"
+        ));
+        // the null direction is reported as a null line, not a null vector
+        assert!(has(
+            "DEBUG rat_trig::metric: BilinearForm2D::line_spread: null line for \
+             ((1, 1, 0), (1, 0, 0))"
+        ));
+        assert!(!has(
+            "DEBUG rat_trig::metric: BilinearForm2D::cross: null vector for ((1, -1), (0, -1))"
+        ));
+    }
+}
@@ -0,0 +1,483 @@
+//! Rational trigonometry over an arbitrary symmetric bilinear form.
+//!
+//! Quadrance, spread and cross only ever use the dot product, so they make
+//! sense for any symmetric bilinear form `⟨u, v⟩ = uᵀ·B·v`, not just the
+//! Euclidean one. Choosing `B` recovers the blue (Euclidean), red
+//! (Minkowski/relativistic) and green geometries of chromogeometry as special
+//! cases.
+//!
+//! In a non-Euclidean form, nonzero vectors can have zero quadrance. Such
+//! null vectors have no spread with anything; the functions here report
+//! them with `MathError::NullVector` instead of dividing by zero. Likewise,
+//! lines with a null direction report `MathError::NullLine`, and in the
+//! projective (universal hyperbolic) reading of a 3×3 form, points and lines
+//! on the null conic report `MathError::NullPoint` / `MathError::NullLine`.
+use crate::error::MathError;
+use crate::linalg::adjugate3;
+use crate::logging::{degenerate, targets, Loggable};
+use core::convert::From;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// A symmetric bilinear form on the plane, given by its Gram matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BilinearForm2D<T> {
+    /// The symmetric Gram matrix `B`, stored row-major.
+    pub matrix: [[T; 2]; 2],
+}
+
+impl<T> BilinearForm2D<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    /// Creates a form from its Gram matrix, returning
+    /// `MathError::InvalidInput` unless the matrix is symmetric.
+    pub fn new(matrix: [[T; 2]; 2]) -> Result<Self, MathError>
+    where
+        T: Loggable,
+    {
+        if matrix[0][1] != matrix[1][0] {
+            return Err(degenerate(
+                targets::METRIC,
+                "BilinearForm2D::new",
+                MathError::InvalidInput,
+                &matrix,
+            ));
+        }
+        Ok(BilinearForm2D { matrix })
+    }
+
+    /// The blue (Euclidean) form `x₁x₂ + y₁y₂`.
+    pub fn blue() -> Self {
+        let (zero, one) = (T::from(0), T::from(1));
+        BilinearForm2D {
+            matrix: [[one, zero], [zero, one]],
+        }
+    }
+
+    /// The red (Minkowski) form `x₁x₂ − y₁y₂`.
+    pub fn red() -> Self {
+        let (zero, one) = (T::from(0), T::from(1));
+        BilinearForm2D {
+            matrix: [[one, zero], [zero, T::from(-1)]],
+        }
+    }
+
+    /// The green form `x₁y₂ + x₂y₁`.
+    pub fn green() -> Self {
+        let (zero, one) = (T::from(0), T::from(1));
+        BilinearForm2D {
+            matrix: [[zero, one], [one, zero]],
+        }
+    }
+
+    /// The value `⟨u, v⟩ = uᵀ·B·v`.
+    #[inline]
+    pub fn dot(&self, u: &(T, T), v: &(T, T)) -> T {
+        let b = &self.matrix;
+        u.0 * (b[0][0] * v.0 + b[0][1] * v.1) + u.1 * (b[1][0] * v.0 + b[1][1] * v.1)
+    }
+
+    /// The quadrance `⟨v, v⟩` of a vector.
+    #[inline]
+    pub fn quadrance(&self, v: &(T, T)) -> T {
+        self.dot(v, v)
+    }
+
+    /// Checks whether `v` has zero quadrance.
+    #[inline]
+    pub fn is_null(&self, v: &(T, T)) -> bool {
+        self.quadrance(v) == T::from(0)
+    }
+
+    /// Checks whether `u` and `v` are perpendicular with respect to the form.
+    #[inline]
+    pub fn is_perpendicular(&self, u: &(T, T), v: &(T, T)) -> bool {
+        self.dot(u, v) == T::from(0)
+    }
+
+    /// Checks whether `v` is a null vector proper: nonzero, yet of zero
+    /// quadrance.
+    #[inline]
+    pub fn is_null_vector(&self, v: &(T, T)) -> bool {
+        let zero = T::from(0);
+        (v.0 != zero || v.1 != zero) && self.is_null(v)
+    }
+
+    /// Checks whether the line `ax + by + c = 0`, given as `(a, b, c)`, is
+    /// null, i.e. whether its direction vector `(b, −a)` is a null vector.
+    #[inline]
+    pub fn is_null_line(&self, line: &(T, T, T)) -> bool
+    where
+        T: Sub<Output = T>,
+    {
+        self.is_null_vector(&(line.1, T::from(0) - line.0))
+    }
+}
+
+impl<T> BilinearForm2D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// The cross `⟨u, v⟩² / (Q(u)·Q(v))` between two vectors.
+    ///
+    /// Returns `MathError::NullVector` if either vector is null.
+    pub fn cross(&self, u: &(T, T), v: &(T, T)) -> Result<T, MathError>
+    where
+        T: Loggable,
+    {
+        let (q_u, q_v) = (self.quadrance(u), self.quadrance(v));
+        if q_u == T::from(0) || q_v == T::from(0) {
+            return Err(degenerate(
+                targets::METRIC,
+                "BilinearForm2D::cross",
+                MathError::NullVector,
+                &(u, v),
+            ));
+        }
+        let d = self.dot(u, v);
+        Ok(d * d / (q_u * q_v))
+    }
+
+    /// The spread `1 − cross(u, v)` between two vectors.
+    ///
+    /// Returns `MathError::NullVector` if either vector is null.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::metric::BilinearForm2D;
+    /// let r = |x: i32| Rational32::from(x);
+    /// let blue = BilinearForm2D::blue();
+    /// assert_eq!(blue.spread(&(r(1), r(0)), &(r(1), r(1))), Ok(Rational32::new(1, 2)));
+    /// let red = BilinearForm2D::red();
+    /// assert!(red.spread(&(r(1), r(0)), &(r(1), r(1))).is_err());
+    /// ```
+    pub fn spread(&self, u: &(T, T), v: &(T, T)) -> Result<T, MathError>
+    where
+        T: Loggable,
+    {
+        Ok(T::from(1) - self.cross(u, v)?)
+    }
+
+    /// The spread between two lines `ax + by + c = 0`, given as `(a, b, c)`,
+    /// i.e. the spread between their directions.
+    ///
+    /// Returns `MathError::NullLine` if either line is null.
+    pub fn line_spread(&self, l_1: &(T, T, T), l_2: &(T, T, T)) -> Result<T, MathError>
+    where
+        T: Loggable,
+    {
+        let zero = T::from(0);
+        let d_1 = (l_1.1, zero - l_1.0);
+        let d_2 = (l_2.1, zero - l_2.0);
+        if self.is_null(&d_1) || self.is_null(&d_2) {
+            return Err(degenerate(
+                targets::METRIC,
+                "BilinearForm2D::line_spread",
+                MathError::NullLine,
+                &(l_1, l_2),
+            ));
+        }
+        self.spread(&d_1, &d_2)
+    }
+}
+
+/// A symmetric bilinear form on three-dimensional space, given by its Gram
+/// matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BilinearForm3D<T> {
+    /// The symmetric Gram matrix `B`, stored row-major.
+    pub matrix: [[T; 3]; 3],
+}
+
+impl<T> BilinearForm3D<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    /// Creates a form from its Gram matrix, returning
+    /// `MathError::InvalidInput` unless the matrix is symmetric.
+    pub fn new(matrix: [[T; 3]; 3]) -> Result<Self, MathError>
+    where
+        T: Loggable,
+    {
+        let symmetric = matrix[0][1] == matrix[1][0]
+            && matrix[0][2] == matrix[2][0]
+            && matrix[1][2] == matrix[2][1];
+        if !symmetric {
+            return Err(degenerate(
+                targets::METRIC,
+                "BilinearForm3D::new",
+                MathError::InvalidInput,
+                &matrix,
+            ));
+        }
+        Ok(BilinearForm3D { matrix })
+    }
+
+    /// The diagonal form `a·x₁x₂ + b·y₁y₂ + c·z₁z₂`.
+    pub fn diagonal(a: T, b: T, c: T) -> Self {
+        let zero = T::from(0);
+        BilinearForm3D {
+            matrix: [[a, zero, zero], [zero, b, zero], [zero, zero, c]],
+        }
+    }
+
+    /// The Euclidean form `x₁x₂ + y₁y₂ + z₁z₂`.
+    pub fn euclidean() -> Self {
+        let one = T::from(1);
+        Self::diagonal(one, one, one)
+    }
+
+    /// The value `⟨u, v⟩ = uᵀ·B·v`.
+    pub fn dot(&self, u: &(T, T, T), v: &(T, T, T)) -> T {
+        let b = &self.matrix;
+        let row = |i: usize| b[i][0] * v.0 + b[i][1] * v.1 + b[i][2] * v.2;
+        u.0 * row(0) + u.1 * row(1) + u.2 * row(2)
+    }
+
+    /// The quadrance `⟨v, v⟩` of a vector.
+    #[inline]
+    pub fn quadrance(&self, v: &(T, T, T)) -> T {
+        self.dot(v, v)
+    }
+
+    /// Checks whether `v` has zero quadrance.
+    #[inline]
+    pub fn is_null(&self, v: &(T, T, T)) -> bool {
+        self.quadrance(v) == T::from(0)
+    }
+
+    /// Checks whether `u` and `v` are perpendicular with respect to the form.
+    #[inline]
+    pub fn is_perpendicular(&self, u: &(T, T, T), v: &(T, T, T)) -> bool {
+        self.dot(u, v) == T::from(0)
+    }
+
+    /// Checks whether the projective point `[x : y : z]` is null, i.e.
+    /// nonzero and on the null conic `⟨a, a⟩ = 0`. For the form
+    /// `diag(1, 1, −1)` this is the absolute of universal hyperbolic
+    /// geometry.
+    #[inline]
+    pub fn is_null_point(&self, a: &(T, T, T)) -> bool {
+        let zero = T::from(0);
+        (a.0 != zero || a.1 != zero || a.2 != zero) && self.is_null(a)
+    }
+
+    /// Checks whether the projective line `⟨l : m : n⟩` (the points with
+    /// `lx + my + nz = 0`) is null, i.e. tangent to the null conic. Lines
+    /// are measured with the adjugate of the Gram matrix.
+    pub fn is_null_line(&self, line: &(T, T, T)) -> bool
+    where
+        T: Sub<Output = T>,
+    {
+        let zero = T::from(0);
+        let dual = BilinearForm3D {
+            matrix: adjugate3(&self.matrix),
+        };
+        (line.0 != zero || line.1 != zero || line.2 != zero) && dual.is_null(line)
+    }
+}
+
+impl<T> BilinearForm3D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// The cross `⟨u, v⟩² / (Q(u)·Q(v))` between two vectors.
+    ///
+    /// Returns `MathError::NullVector` if either vector is null.
+    pub fn cross(&self, u: &(T, T, T), v: &(T, T, T)) -> Result<T, MathError>
+    where
+        T: Loggable,
+    {
+        let (q_u, q_v) = (self.quadrance(u), self.quadrance(v));
+        if q_u == T::from(0) || q_v == T::from(0) {
+            return Err(degenerate(
+                targets::METRIC,
+                "BilinearForm3D::cross",
+                MathError::NullVector,
+                &(u, v),
+            ));
+        }
+        let d = self.dot(u, v);
+        Ok(d * d / (q_u * q_v))
+    }
+
+    /// The spread `1 − cross(u, v)` between two vectors.
+    ///
+    /// Returns `MathError::NullVector` if either vector is null.
+    pub fn spread(&self, u: &(T, T, T), v: &(T, T, T)) -> Result<T, MathError>
+    where
+        T: Loggable,
+    {
+        Ok(T::from(1) - self.cross(u, v)?)
+    }
+
+    /// The projective quadrance `1 − ⟨a₁, a₂⟩² / (⟨a₁, a₁⟩·⟨a₂, a₂⟩)`
+    /// between two projective points, as in universal hyperbolic geometry.
+    ///
+    /// Returns `MathError::NullPoint` if either point is null.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::error::MathError;
+    /// use rat_trig_rs::metric::BilinearForm3D;
+    /// let r = |x: i32| Rational32::from(x);
+    /// let hyperbolic = BilinearForm3D::diagonal(r(1), r(1), r(-1));
+    /// let q = hyperbolic.projective_quadrance(&(r(0), r(0), r(1)), &(r(1), r(0), r(2)));
+    /// assert_eq!(q, Ok(Rational32::new(-1, 3)));
+    /// let null = (r(3), r(4), r(5));
+    /// assert_eq!(
+    ///     hyperbolic.projective_quadrance(&null, &(r(0), r(0), r(1))),
+    ///     Err(MathError::NullPoint)
+    /// );
+    /// ```
+    pub fn projective_quadrance(&self, a_1: &(T, T, T), a_2: &(T, T, T)) -> Result<T, MathError>
+    where
+        T: Loggable,
+    {
+        if self.is_null(a_1) || self.is_null(a_2) {
+            return Err(degenerate(
+                targets::METRIC,
+                "BilinearForm3D::projective_quadrance",
+                MathError::NullPoint,
+                &(a_1, a_2),
+            ));
+        }
+        self.spread(a_1, a_2)
+    }
+
+    /// The projective spread between two projective lines, measured with the
+    /// adjugate of the Gram matrix.
+    ///
+    /// Returns `MathError::NullLine` if either line is null.
+    pub fn projective_spread(&self, l_1: &(T, T, T), l_2: &(T, T, T)) -> Result<T, MathError>
+    where
+        T: Loggable,
+    {
+        let dual = BilinearForm3D {
+            matrix: adjugate3(&self.matrix),
+        };
+        if dual.is_null(l_1) || dual.is_null(l_2) {
+            return Err(degenerate(
+                targets::METRIC,
+                "BilinearForm3D::projective_spread",
+                MathError::NullLine,
+                &(l_1, l_2),
+            ));
+        }
+        dual.spread(l_1, l_2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trigonom::quadrance;
+    use num_rational::Ratio;
+
+    type Q = Ratio<i32>;
+
+    fn q(n: i32) -> Q {
+        Q::from_integer(n)
+    }
+
+    #[test]
+    fn test_chromo_special_cases() {
+        let v = (3, 2);
+        assert_eq!(BilinearForm2D::blue().quadrance(&v), quadrance(&v));
+        assert_eq!(BilinearForm2D::red().quadrance(&v), 5);
+        assert_eq!(BilinearForm2D::green().quadrance(&v), 12);
+        // Q_b² = Q_r² + Q_g²
+        let (b, r, g) = (
+            BilinearForm2D::blue().quadrance(&v),
+            BilinearForm2D::red().quadrance(&v),
+            BilinearForm2D::green().quadrance(&v),
+        );
+        assert_eq!(b * b, r * r + g * g);
+    }
+
+    #[test]
+    fn test_null_vectors() {
+        let red = BilinearForm2D::<Q>::red();
+        let light = (q(1), q(-1));
+        assert!(red.is_null(&light));
+        assert!(red.is_perpendicular(&light, &light));
+        assert_eq!(
+            red.spread(&light, &(q(2), q(1))),
+            Err(MathError::NullVector)
+        );
+        assert_eq!(red.spread(&(q(2), q(1)), &(q(3), q(1))), Ok(Q::new(-1, 24)));
+        assert_eq!(
+            BilinearForm2D::new([[q(1), q(2)], [q(0), q(1)]]),
+            Err(MathError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_form3d() {
+        let euclid = BilinearForm3D::<Q>::euclidean();
+        let (u, v) = ((q(1), q(0), q(0)), (q(1), q(1), q(0)));
+        assert_eq!(euclid.spread(&u, &v), Ok(Q::new(1, 2)));
+        assert_eq!(euclid.cross(&u, &v), Ok(Q::new(1, 2)));
+
+        let minkowski = BilinearForm3D::diagonal(q(1), q(1), q(-1));
+        let null = (q(3), q(4), q(5));
+        assert!(minkowski.is_null(&null));
+        assert_eq!(minkowski.spread(&null, &u), Err(MathError::NullVector));
+
+        let skew = [[q(1), q(1), q(0)], [q(0), q(1), q(0)], [q(0), q(0), q(1)]];
+        assert_eq!(BilinearForm3D::new(skew), Err(MathError::InvalidInput));
+    }
+
+    #[test]
+    fn test_null_lines_2d() {
+        let red = BilinearForm2D::<Q>::red();
+        assert!(!red.is_null_vector(&(q(0), q(0))));
+        assert!(red.is_null_vector(&(q(2), q(2))));
+        // x - y = 0 has direction (1, 1)
+        let diagonal = (q(1), q(-1), q(0));
+        assert!(red.is_null_line(&diagonal));
+        assert!(!BilinearForm2D::blue().is_null_line(&diagonal));
+        let (l_1, l_2) = ((q(1), q(0), q(-1)), (q(1), q(2), q(0)));
+        assert_eq!(red.line_spread(&diagonal, &l_1), Err(MathError::NullLine));
+        assert_eq!(
+            BilinearForm2D::blue().line_spread(&l_1, &l_2),
+            Ok(Q::new(4, 5))
+        );
+    }
+
+    #[test]
+    fn test_null_points_lines_projective() {
+        let hyperbolic = BilinearForm3D::diagonal(q(1), q(1), q(-1));
+        assert!(hyperbolic.is_null_point(&(q(3), q(4), q(5))));
+        assert!(!hyperbolic.is_null_point(&(q(0), q(0), q(0))));
+        assert!(!hyperbolic.is_null_point(&(q(0), q(0), q(1))));
+        // the line x = z touches the unit circle at [1 : 0 : 1]
+        let tangent = (q(1), q(0), q(-1));
+        assert!(hyperbolic.is_null_line(&tangent));
+        assert!(!hyperbolic.is_null_line(&(q(1), q(0), q(0))));
+        assert_eq!(
+            hyperbolic.projective_spread(&tangent, &(q(0), q(1), q(0))),
+            Err(MathError::NullLine)
+        );
+        assert_eq!(
+            hyperbolic.projective_spread(&(q(1), q(0), q(0)), &(q(0), q(1), q(0))),
+            Ok(q(1))
+        );
+    }
+}
@@ -0,0 +1,554 @@
+//! Polygons in the plane.
+//!
+//! A [`Polygon2D`] is a closed chain of vertices; the closing edge from the
+//! last vertex back to the first is implicit. All predicates here are sign
+//! tests on twists, so they are exact over integers and rationals.
+use crate::error::{GeomError, MathError, TrigResult};
+use crate::geometry::{Point2D, Winding};
+use crate::hull::convex_hull;
+use crate::logging::{degenerate, rejected, targets, Loggable};
+use crate::segment::{on_segment, orientation, segments_intersect};
+use alloc::vec::Vec;
+use core::convert::From;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// A polygon given by its vertices in order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polygon2D<T> {
+    pub vertices: Vec<Point2D<T>>,
+}
+
+impl<T> Polygon2D<T> {
+    /// Creates a polygon from its vertices without validating them.
+    #[inline]
+    pub const fn new(vertices: Vec<Point2D<T>>) -> Self {
+        Polygon2D { vertices }
+    }
+
+    /// Starts a [`PolygonBuilder`], which validates the vertices on
+    /// `build()`.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::error::MathError;
+    /// use rat_trig_rs::geometry::{Point2D, Winding};
+    /// use rat_trig_rs::polygon::Polygon2D;
+    /// let square = Polygon2D::builder()
+    ///     .vertices([(0, 0), (0, 1), (1, 1), (1, 0)].map(|(x, y)| Point2D::new(x, y)))
+    ///     .winding(Winding::CounterClockwise)
+    ///     .fix_winding()
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(square.vertices[1], Point2D::new(1, 0));
+    ///
+    /// let bowtie = Polygon2D::builder()
+    ///     .vertices([(0, 0), (1, 1), (1, 0), (0, 1)].map(|(x, y)| Point2D::new(x, y)))
+    ///     .build();
+    /// assert_eq!(bowtie.unwrap_err().kind, MathError::InvalidInput);
+    /// ```
+    #[inline]
+    pub fn builder() -> PolygonBuilder<T> {
+        PolygonBuilder::default()
+    }
+
+    /// The number of vertices.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Checks whether the polygon has no vertices.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+}
+
+impl<T> Polygon2D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    /// Twice the signed area by the shoelace formula, positive when the
+    /// vertices wind counterclockwise. It is the sum of the twists of the
+    /// edges as seen from the origin.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::Point2D;
+    /// use rat_trig_rs::polygon::Polygon2D;
+    /// let l_shape = Polygon2D::new(
+    ///     [(0, 0), (2, 0), (2, 1), (1, 1), (1, 2), (0, 2)].map(|(x, y)| Point2D::new(x, y)).to_vec(),
+    /// );
+    /// assert_eq!(l_shape.twist_area(), 6);
+    /// assert!(l_shape.is_ccw());
+    /// ```
+    #[inline]
+    pub fn twist_area(&self) -> T {
+        twice_signed_area(&self.vertices)
+    }
+
+    /// Checks whether the vertices wind counterclockwise, i.e. whether the
+    /// signed area is positive.
+    #[inline]
+    pub fn is_ccw(&self) -> bool {
+        self.twist_area() > T::from(0)
+    }
+
+    /// Checks whether the boundary has no self-intersections; see
+    /// [`PolygonBuilder::build`] for what counts as simple.
+    #[inline]
+    pub fn is_simple(&self) -> bool {
+        is_simple_chain(&self.vertices)
+    }
+
+    /// Checks whether the polygon is simple and convex. Straight angles,
+    /// where three consecutive vertices are collinear, are allowed.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::Point2D;
+    /// use rat_trig_rs::polygon::Polygon2D;
+    /// let poly = |c: &[(i32, i32)]| Polygon2D::new(c.iter().map(|&(x, y)| Point2D::new(x, y)).collect());
+    /// assert!(poly(&[(0, 0), (2, 0), (4, 0), (4, 4), (0, 4)]).is_convex());
+    /// assert!(!poly(&[(0, 0), (4, 0), (2, 1), (4, 4), (0, 4)]).is_convex());
+    /// // a pentagram turns the same way at every vertex, but is not simple
+    /// assert!(!poly(&[(0, 0), (2, 6), (4, 0), (-1, 4), (5, 4)]).is_convex());
+    /// ```
+    pub fn is_convex(&self) -> bool {
+        let n = self.vertices.len();
+        let (mut left, mut right) = (false, false);
+        for i in 0..n {
+            let (a, b, c) = (
+                &self.vertices[i],
+                &self.vertices[(i + 1) % n],
+                &self.vertices[(i + 2) % n],
+            );
+            match orientation(a, b, c) {
+                1 => left = true,
+                -1 => right = true,
+                _ => {}
+            }
+        }
+        !(left && right) && self.is_simple()
+    }
+
+    /// Checks whether `p` lies in the closed polygon, boundary included.
+    ///
+    /// Uses the winding number, computed with sign tests only, so it is exact
+    /// over integers and rationals. For a self-intersecting boundary, the
+    /// points with nonzero winding number count as inside.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::Point2D;
+    /// use rat_trig_rs::polygon::Polygon2D;
+    /// let p = Point2D::new;
+    /// let l_shape = Polygon2D::new(vec![p(0, 0), p(4, 0), p(4, 2), p(2, 2), p(2, 4), p(0, 4)]);
+    /// assert!(l_shape.contains_point(&p(1, 3)));
+    /// assert!(l_shape.contains_point(&p(3, 2)));
+    /// assert!(!l_shape.contains_point(&p(3, 3)));
+    /// ```
+    pub fn contains_point(&self, p: &Point2D<T>) -> bool {
+        let n = self.vertices.len();
+        let mut winding = 0;
+        for i in 0..n {
+            let (a, b) = (&self.vertices[i], &self.vertices[(i + 1) % n]);
+            let turn = orientation(a, b, p);
+            if turn == 0 && on_segment(a, b, p) {
+                return true;
+            }
+            if a.y <= p.y {
+                if b.y > p.y && turn > 0 {
+                    winding += 1;
+                }
+            } else if b.y <= p.y && turn < 0 {
+                winding -= 1;
+            }
+        }
+        winding != 0
+    }
+
+    /// The convex hull of the vertices as a counterclockwise polygon; see
+    /// [`convex_hull`].
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::Point2D;
+    /// use rat_trig_rs::polygon::Polygon2D;
+    /// let p = Point2D::new;
+    /// let arrow = Polygon2D::new(vec![p(0, 0), p(4, 2), p(0, 4), p(1, 2)]);
+    /// assert!(!arrow.is_convex());
+    /// let hull = arrow.convex_hull();
+    /// assert_eq!(hull.vertices, [p(0, 0), p(4, 2), p(0, 4)]);
+    /// assert!(hull.is_convex());
+    /// ```
+    #[inline]
+    pub fn convex_hull(&self) -> Self {
+        Polygon2D::new(convex_hull(&self.vertices))
+    }
+
+    /// The quadrances of the edges, starting with the edge from the first
+    /// vertex to the second and ending with the closing edge.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::Point2D;
+    /// use rat_trig_rs::polygon::Polygon2D;
+    /// let tri = Polygon2D::new(vec![Point2D::new(0, 0), Point2D::new(4, 0), Point2D::new(0, 3)]);
+    /// let qs: Vec<_> = tri.perimeter_quadrances().collect();
+    /// assert_eq!(qs, [16, 25, 9]);
+    /// ```
+    pub fn perimeter_quadrances(&self) -> impl Iterator<Item = T> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| (self.vertices[(i + 1) % n] - self.vertices[i]).quadrance())
+    }
+}
+
+impl<T> Polygon2D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>,
+{
+    /// The centroid of the enclosed region, exact over the rationals.
+    ///
+    /// Returns `MathError::InvalidInput` if the signed area is zero.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::Point2D;
+    /// use rat_trig_rs::polygon::Polygon2D;
+    /// let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+    /// let l_shape = Polygon2D::new(vec![p(0, 0), p(2, 0), p(2, 1), p(1, 1), p(1, 2), p(0, 2)]);
+    /// let c = Rational32::new(5, 6);
+    /// assert_eq!(l_shape.centroid(), Ok(Point2D::new(c, c)));
+    /// ```
+    pub fn centroid(&self) -> Result<Point2D<T>, MathError>
+    where
+        T: Loggable,
+    {
+        let twist_area = self.twist_area();
+        if twist_area == T::from(0) {
+            return Err(degenerate(
+                targets::POLYGON,
+                "Polygon2D::centroid",
+                MathError::InvalidInput,
+                self,
+            ));
+        }
+        let n = self.vertices.len();
+        let (sx, sy) = (0..n).fold((T::from(0), T::from(0)), |(sx, sy), i| {
+            let (p, q) = (&self.vertices[i], &self.vertices[(i + 1) % n]);
+            let twist = p.x * q.y - p.y * q.x;
+            (sx + (p.x + q.x) * twist, sy + (p.y + q.y) * twist)
+        });
+        let denom = T::from(3) * twist_area;
+        Ok(Point2D::new(sx / denom, sy / denom))
+    }
+}
+
+/// Twice the signed area of the closed chain, positive when it winds
+/// counterclockwise.
+pub(crate) fn twice_signed_area<T>(vertices: &[Point2D<T>]) -> T
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<i32>,
+{
+    let n = vertices.len();
+    (0..n).fold(T::from(0), |acc, i| {
+        let (p, q) = (&vertices[i], &vertices[(i + 1) % n]);
+        acc + (p.x * q.y - p.y * q.x)
+    })
+}
+
+/// Checks whether the closed chain has no self-intersections: adjacent
+/// edges meet only at their shared vertex and other edges do not meet.
+pub(crate) fn is_simple_chain<T>(vertices: &[Point2D<T>]) -> bool
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    let n = vertices.len();
+    if n < 3 {
+        return false;
+    }
+    let edge = |i: usize| (&vertices[i], &vertices[(i + 1) % n]);
+    for i in 0..n {
+        let (a, b) = edge(i);
+        if a == b {
+            return false;
+        }
+        for j in i + 1..n {
+            let (c, d) = edge(j);
+            let adjacent = j == i + 1 || (i == 0 && j == n - 1);
+            if adjacent {
+                // the edges share one vertex; they must not fold back
+                let (shared, u, v) = if j == i + 1 { (b, a, d) } else { (a, b, c) };
+                if orientation(shared, u, v) == 0
+                    && (on_segment(shared, u, v) || on_segment(shared, v, u))
+                {
+                    return false;
+                }
+            } else if segments_intersect(a, b, c, d) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Accumulates and validates the vertices of a [`Polygon2D`].
+///
+/// `build()` fails with `MathError::InvalidInput` if there are fewer than
+/// three vertices, the chain is not simple (it crosses or touches itself,
+/// repeats a vertex, or encloses no area), or a required winding does not
+/// match and [`fix_winding`](Self::fix_winding) was not requested.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PolygonBuilder<T> {
+    vertices: Vec<Point2D<T>>,
+    winding: Option<Winding>,
+    fix_winding: bool,
+}
+
+impl<T> Default for PolygonBuilder<T> {
+    fn default() -> Self {
+        PolygonBuilder {
+            vertices: Vec::new(),
+            winding: None,
+            fix_winding: false,
+        }
+    }
+}
+
+impl<T> PolygonBuilder<T> {
+    /// Appends a vertex.
+    pub fn vertex(mut self, p: Point2D<T>) -> Self {
+        self.vertices.push(p);
+        self
+    }
+
+    /// Appends several vertices.
+    pub fn vertices<I: IntoIterator<Item = Point2D<T>>>(mut self, points: I) -> Self {
+        self.vertices.extend(points);
+        self
+    }
+
+    /// Requires the given orientation.
+    pub fn winding(mut self, winding: Winding) -> Self {
+        self.winding = Some(winding);
+        self
+    }
+
+    /// Reverses the vertex order (keeping the first vertex) to match the
+    /// required orientation instead of failing.
+    pub fn fix_winding(mut self) -> Self {
+        self.fix_winding = true;
+        self
+    }
+}
+
+impl<T> PolygonBuilder<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    /// Validates the vertices and builds the polygon.
+    pub fn build(mut self) -> TrigResult<Polygon2D<T>>
+    where
+        T: Loggable,
+    {
+        const OPERATION: &str = "Polygon2D::build";
+        let invalid = |input| {
+            rejected(
+                targets::POLYGON,
+                GeomError::new(MathError::InvalidInput, OPERATION).with_input(input),
+                &self.vertices,
+            )
+        };
+        if self.vertices.len() < 3 {
+            return Err(invalid("fewer than three vertices"));
+        }
+        if !is_simple_chain(&self.vertices) {
+            return Err(invalid("self-intersecting chain"));
+        }
+        // a simple chain encloses a nonzero area
+        let actual = if twice_signed_area(&self.vertices) > T::from(0) {
+            Winding::CounterClockwise
+        } else {
+            Winding::Clockwise
+        };
+        match self.winding {
+            Some(required) if required != actual => {
+                if !self.fix_winding {
+                    return Err(invalid("wrong winding"));
+                }
+                self.vertices[1..].reverse();
+            }
+            _ => {}
+        }
+        Ok(Polygon2D::new(self.vertices))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+
+    fn points(coords: &[(i32, i32)]) -> Vec<Point2D<i32>> {
+        coords.iter().map(|&(x, y)| Point2D::new(x, y)).collect()
+    }
+
+    #[test]
+    fn test_is_simple_chain() {
+        assert!(is_simple_chain(&points(&[(0, 0), (2, 0), (2, 2), (0, 2)])));
+        assert!(is_simple_chain(&points(&[
+            (0, 0),
+            (4, 0),
+            (2, 1),
+            (4, 4),
+            (0, 4)
+        ])));
+        // bowtie
+        assert!(!is_simple_chain(&points(&[(0, 0), (2, 2), (2, 0), (0, 2)])));
+        // a vertex touching a non-adjacent edge
+        assert!(!is_simple_chain(&points(&[(0, 0), (4, 0), (2, 0), (2, 2)])));
+        assert!(!is_simple_chain(&points(&[
+            (0, 0),
+            (4, 0),
+            (4, 4),
+            (2, 0),
+            (0, 4)
+        ])));
+        // spike folding back along the previous edge
+        assert!(!is_simple_chain(&points(&[(0, 0), (4, 0), (2, 0), (0, 4)])));
+        // repeated vertex
+        assert!(!is_simple_chain(&points(&[(0, 0), (4, 0), (4, 0), (0, 4)])));
+    }
+
+    #[test]
+    fn test_builder() {
+        let ccw = points(&[(0, 0), (2, 0), (2, 2), (0, 2)]);
+        let mut cw = ccw.clone();
+        cw[1..].reverse();
+
+        let poly = Polygon2D::builder().vertices(cw.clone()).build().unwrap();
+        assert_eq!(poly.vertices, cw);
+        let err = Polygon2D::builder()
+            .vertices(cw.clone())
+            .winding(Winding::CounterClockwise)
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Polygon2D::build (wrong winding): invalid input"
+        );
+        let poly = Polygon2D::builder()
+            .vertices(cw)
+            .winding(Winding::CounterClockwise)
+            .fix_winding()
+            .build()
+            .unwrap();
+        assert_eq!(poly.vertices, ccw);
+        assert_eq!(twice_signed_area(&poly.vertices), 8);
+
+        let err = Polygon2D::builder()
+            .vertex(Point2D::new(0, 0))
+            .vertex(Point2D::new(1, 1))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind, MathError::InvalidInput);
+
+        // collinear vertices fold back onto themselves
+        let err = Polygon2D::builder()
+            .vertices(points(&[(0, 0), (1, 1), (2, 2)]))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind, MathError::InvalidInput);
+    }
+
+    #[test]
+    fn test_area_and_centroid() {
+        type Q = Ratio<i32>;
+        let square = Polygon2D::new(points(&[(0, 0), (2, 0), (2, 2), (0, 2)]));
+        assert_eq!(square.twist_area(), 8);
+        assert!(square.is_ccw());
+        assert_eq!(square.perimeter_quadrances().sum::<i32>(), 16);
+        let mut cw = square.clone();
+        cw.vertices.reverse();
+        assert_eq!(cw.twist_area(), -8);
+        assert!(!cw.is_ccw());
+
+        let p = |x: i32, y: i32| Point2D::new(Q::from(x), Q::from(y));
+        let tri = Polygon2D::new(vec![p(0, 0), p(6, 0), p(0, 3)]);
+        assert_eq!(tri.centroid(), Ok(p(2, 1)));
+        // the orientation does not move the centroid
+        let quad = Polygon2D::new(vec![p(0, 0), p(0, 4), p(4, 4), p(4, 0)]);
+        assert_eq!(quad.centroid(), Ok(p(2, 2)));
+        let flat = Polygon2D::new(vec![p(0, 0), p(1, 1), p(2, 2)]);
+        assert_eq!(flat.centroid(), Err(MathError::InvalidInput));
+        assert_eq!(Polygon2D::<Q>::default().perimeter_quadrances().count(), 0);
+    }
+
+    #[test]
+    fn test_predicates() {
+        let square = Polygon2D::new(points(&[(0, 0), (4, 0), (4, 4), (0, 4)]));
+        assert!(square.is_simple() && square.is_convex());
+        let mut cw = square.clone();
+        cw.vertices.reverse();
+        assert!(cw.is_convex());
+        let bowtie = Polygon2D::new(points(&[(0, 0), (4, 4), (4, 0), (0, 4)]));
+        assert!(!bowtie.is_simple() && !bowtie.is_convex());
+
+        for poly in [&square, &cw] {
+            assert!(poly.contains_point(&Point2D::new(2, 2)));
+            assert!(poly.contains_point(&Point2D::new(4, 1)));
+            assert!(poly.contains_point(&Point2D::new(0, 0)));
+            assert!(!poly.contains_point(&Point2D::new(5, 2)));
+            assert!(!poly.contains_point(&Point2D::new(2, -1)));
+            // level with a vertex, outside
+            assert!(!poly.contains_point(&Point2D::new(-1, 4)));
+        }
+        // the bowtie covers its two lobes but not the gaps between them
+        assert!(bowtie.contains_point(&Point2D::new(1, 2)));
+        assert!(!bowtie.contains_point(&Point2D::new(2, 1)));
+        // rational coordinates just off an edge
+        type Q = Ratio<i32>;
+        let tri = Polygon2D::new(vec![
+            Point2D::new(Q::from(0), Q::from(0)),
+            Point2D::new(Q::from(3), Q::from(0)),
+            Point2D::new(Q::from(0), Q::from(3)),
+        ]);
+        assert!(tri.contains_point(&Point2D::new(Q::new(3, 2), Q::new(3, 2))));
+        assert!(!tri.contains_point(&Point2D::new(Q::new(3, 2), Q::new(301, 200))));
+    }
+
+    #[test]
+    fn test_convex_hull() {
+        let star = Polygon2D::new(points(&[
+            (0, 0),
+            (2, 1),
+            (4, 0),
+            (3, 2),
+            (4, 4),
+            (2, 3),
+            (0, 4),
+            (1, 2),
+        ]));
+        let hull = star.convex_hull();
+        assert_eq!(hull.vertices, points(&[(0, 0), (4, 0), (4, 4), (0, 4)]));
+        assert!(hull.is_convex() && hull.is_ccw());
+        assert!(star.vertices.iter().all(|p| hull.contains_point(p)));
+        assert_eq!(hull.convex_hull(), hull);
+    }
+}
@@ -0,0 +1,33 @@
+//! The commonly used items, for glob import.
+//!
+//! ```rust
+//! use num_rational::Rational32;
+//! use rat_trig_rs::prelude::*;
+//! let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+//! let (a, b, c) = (p(0, 0), p(4, 0), p(0, 3));
+//! assert_eq!(a.quadrance_to(&b), Rational32::from(16));
+//! assert_eq!(a.spread_at(&b, &c), Ok(Rational32::from(1)));
+//! assert_eq!(analyze(&a, &b, &c).class, TriangleClass::Right);
+//! ```
+pub use crate::analysis::{
+    analyze, are_congruent, are_congruent_points, are_similar, are_similar_triangles,
+    is_on_euler_line, similarity_ratio, TriangleClass, TriangleReport,
+};
+pub use crate::circle::{Circle2D, CirclePosition, CircleRelation, Tangency};
+pub use crate::error::{ErrorContext, GeomError, MathError, TrigResult};
+pub use crate::geometry::{
+    Line2D, Line3D, Plane3D, Point2D, Point3D, Tetrahedron3D, Triangle2D, Triangle3D, Vector2D,
+    Vector3D, Winding,
+};
+pub use crate::metric::{BilinearForm2D, BilinearForm3D};
+#[cfg(feature = "alloc")]
+pub use crate::polygon::Polygon2D;
+pub use crate::projective::ProjectivePoint2D;
+#[cfg(feature = "alloc")]
+pub use crate::region::ConvexRegion2D;
+pub use crate::segment::{Ray2D, RayHit, Segment2D};
+pub use crate::traits::{AffineOps, MetricOps, RationalTrig};
+pub use crate::transform::{
+    Affine2D, Isometry2D, Isometry3D, Reflection2D, Rotation2D, Rotation3D,
+};
+pub use crate::trigonom::{archimedes, quadrance, quadrance3d, quadrume};
@@ -0,0 +1,374 @@
+//! Points of the projective plane in homogeneous coordinates.
+//!
+//! A [`ProjectivePoint2D`] `[x : y : z]` is a nonzero triple up to scaling;
+//! `z = 0` gives the points at infinity. Lines are the existing [`Line2D`]
+//! read as `⟨a : b : c⟩`, so incidence, joins and cross-ratios are all
+//! polynomial in the coordinates and exact over the rationals.
+use crate::error::MathError;
+use crate::geometry::{Line2D, Point2D};
+use crate::logging::{degenerate, targets, Loggable};
+use core::convert::From;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// A point `[x : y : z]` of the projective plane.
+///
+/// Proportional triples describe the same point; the derived `PartialEq`
+/// compares coordinates, use [`equivalent`](ProjectivePoint2D::equivalent)
+/// to compare points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[repr(C)]
+pub struct ProjectivePoint2D<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> ProjectivePoint2D<T> {
+    /// Creates a new point from its homogeneous coordinates.
+    #[inline]
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        ProjectivePoint2D { x, y, z }
+    }
+}
+
+impl<T: From<i32>> From<Point2D<T>> for ProjectivePoint2D<T> {
+    /// Embeds the affine point `(x, y)` as `[x : y : 1]`.
+    #[inline]
+    fn from(p: Point2D<T>) -> Self {
+        ProjectivePoint2D::new(p.x, p.y, T::from(1))
+    }
+}
+
+impl<T> ProjectivePoint2D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    /// The cross product of the coordinate triples, which is the line
+    /// through two points, or the meet of two lines.
+    #[inline]
+    fn cross(&self, other: &Self) -> (T, T, T) {
+        (
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Checks whether all three coordinates vanish, which describes no
+    /// point at all.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        let zero = T::from(0);
+        self.x == zero && self.y == zero && self.z == zero
+    }
+
+    /// Checks whether the point lies on the line at infinity, `z = 0`.
+    #[inline]
+    pub fn is_at_infinity(&self) -> bool {
+        self.z == T::from(0) && !self.is_zero()
+    }
+
+    /// Checks whether `self` and `other` are the same projective point,
+    /// i.e. whether their coordinates are proportional.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::projective::ProjectivePoint2D;
+    /// let p = ProjectivePoint2D::new(1, 2, 3);
+    /// assert!(p.equivalent(&ProjectivePoint2D::new(-2, -4, -6)));
+    /// assert!(!p.equivalent(&ProjectivePoint2D::new(1, 2, 4)));
+    /// ```
+    #[inline]
+    pub fn equivalent(&self, other: &Self) -> bool {
+        let zero = T::from(0);
+        self.cross(other) == (zero, zero, zero)
+    }
+
+    /// Checks whether the point lies on `line`, i.e. `a·x + b·y + c·z = 0`.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::Line2D;
+    /// use rat_trig_rs::projective::ProjectivePoint2D;
+    /// // the direction of the line x + y = 1 is its point at infinity
+    /// let line = Line2D::new(1, 1, -1);
+    /// assert!(ProjectivePoint2D::new(1, -1, 0).lies_on(&line));
+    /// assert!(ProjectivePoint2D::new(2, -1, 1).lies_on(&line));
+    /// ```
+    #[inline]
+    pub fn lies_on(&self, line: &Line2D<T>) -> bool {
+        line.a * self.x + line.b * self.y + line.c * self.z == T::from(0)
+    }
+
+    /// The line through `self` and `other`.
+    ///
+    /// Returns `MathError::InvalidInput` if the points coincide (or either
+    /// is zero).
+    pub fn join(&self, other: &Self) -> Result<Line2D<T>, MathError>
+    where
+        T: Loggable,
+    {
+        let (a, b, c) = self.cross(other);
+        let zero = T::from(0);
+        if (a, b, c) == (zero, zero, zero) {
+            return Err(degenerate(
+                targets::PROJECTIVE,
+                "ProjectivePoint2D::join",
+                MathError::InvalidInput,
+                &(self, other),
+            ));
+        }
+        Ok(Line2D::new(a, b, c))
+    }
+
+    /// The meet of two lines, which always exists projectively: parallel
+    /// lines meet at infinity.
+    ///
+    /// Returns `MathError::InvalidInput` if the lines coincide.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::Line2D;
+    /// use rat_trig_rs::projective::ProjectivePoint2D;
+    /// let meet = ProjectivePoint2D::meet(&Line2D::new(1, 1, -1), &Line2D::new(2, 2, 3));
+    /// assert!(meet.unwrap().is_at_infinity());
+    /// ```
+    pub fn meet(l_1: &Line2D<T>, l_2: &Line2D<T>) -> Result<Self, MathError>
+    where
+        T: Loggable,
+    {
+        let (x, y, z) = ProjectivePoint2D::new(l_1.a, l_1.b, l_1.c)
+            .cross(&ProjectivePoint2D::new(l_2.a, l_2.b, l_2.c));
+        let p = ProjectivePoint2D::new(x, y, z);
+        if p.is_zero() {
+            return Err(degenerate(
+                targets::PROJECTIVE,
+                "ProjectivePoint2D::meet",
+                MathError::InvalidInput,
+                &(l_1, l_2),
+            ));
+        }
+        Ok(p)
+    }
+}
+
+impl<T> ProjectivePoint2D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// The representative with `z = 1` if the point is finite, and with the
+    /// first nonzero of `x`, `y` equal to 1 if it is at infinity.
+    ///
+    /// Returns `MathError::InvalidInput` for the zero triple.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::projective::ProjectivePoint2D;
+    /// let r = Rational32::from;
+    /// let p = ProjectivePoint2D::new(r(2), r(4), r(4)).normalized();
+    /// assert_eq!(p, Ok(ProjectivePoint2D::new(Rational32::new(1, 2), r(1), r(1))));
+    /// let q = ProjectivePoint2D::new(r(0), r(-3), r(0)).normalized();
+    /// assert_eq!(q, Ok(ProjectivePoint2D::new(r(0), r(1), r(0))));
+    /// ```
+    pub fn normalized(&self) -> Result<Self, MathError>
+    where
+        T: Loggable,
+    {
+        let zero = T::from(0);
+        let scale = [self.z, self.x, self.y]
+            .into_iter()
+            .find(|&v| v != zero)
+            .ok_or_else(|| {
+                degenerate(
+                    targets::PROJECTIVE,
+                    "ProjectivePoint2D::normalized",
+                    MathError::InvalidInput,
+                    self,
+                )
+            })?;
+        Ok(ProjectivePoint2D::new(
+            self.x / scale,
+            self.y / scale,
+            self.z / scale,
+        ))
+    }
+
+    /// The affine point `(x / z, y / z)`.
+    ///
+    /// Returns `MathError::DivisionByZero` if the point is at infinity.
+    pub fn to_point(&self) -> Result<Point2D<T>, MathError>
+    where
+        T: Loggable,
+    {
+        if self.z == T::from(0) {
+            return Err(degenerate(
+                targets::PROJECTIVE,
+                "ProjectivePoint2D::to_point",
+                MathError::DivisionByZero,
+                self,
+            ));
+        }
+        Ok(Point2D::new(self.x / self.z, self.y / self.z))
+    }
+}
+
+/// The function `cross_ratio` calculates the cross-ratio
+/// `(a, b; c, d) = ([a, c]·[b, d]) / ([a, d]·[b, c])` of four collinear
+/// projective points, where `[p, q]` is the bracket of two points measured
+/// along their common line. It does not depend on the representatives, and
+/// for finite points on a line it is `(AC·BD) / (AD·BC)` in signed lengths.
+///
+/// Arguments:
+///
+/// * `a`, `b`, `c`, `d`: The four points; `a` and `b` must differ.
+///
+/// Returns:
+///
+/// The cross-ratio, or `MathError::InvalidInput` if `a` and `b` coincide or
+/// the points are not collinear, and `MathError::DivisionByZero` if `a = d`
+/// or `b = c`.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::projective::{cross_ratio, ProjectivePoint2D};
+/// let p = |x: i32| ProjectivePoint2D::new(Rational32::from(x), Rational32::from(0), Rational32::from(1));
+/// assert_eq!(cross_ratio(&p(0), &p(1), &p(2), &p(3)), Ok(Rational32::new(4, 3)));
+/// // the point at infinity of the line conjugates 2 to the midpoint of 1 and 3
+/// let infinity = ProjectivePoint2D::new(Rational32::from(1), Rational32::from(0), Rational32::from(0));
+/// assert_eq!(cross_ratio(&p(1), &p(3), &p(2), &infinity), Ok(Rational32::from(-1)));
+/// ```
+pub fn cross_ratio<T>(
+    a: &ProjectivePoint2D<T>,
+    b: &ProjectivePoint2D<T>,
+    c: &ProjectivePoint2D<T>,
+    d: &ProjectivePoint2D<T>,
+) -> Result<T, MathError>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>
+        + Loggable,
+{
+    let err = |kind| degenerate(targets::PROJECTIVE, "cross_ratio", kind, &(a, b, c, d));
+    // the join of `a` and `b`, checked here so the rejection is logged once
+    let (l_a, l_b, l_c) = a.cross(b);
+    let zero = T::from(0);
+    if (l_a, l_b, l_c) == (zero, zero, zero) {
+        return Err(err(MathError::InvalidInput));
+    }
+    let line = Line2D::new(l_a, l_b, l_c);
+    if !c.lies_on(&line) || !d.lies_on(&line) {
+        return Err(err(MathError::InvalidInput));
+    }
+    // every bracket p × q is a multiple of the line, so compare one
+    // coordinate where the line does not vanish
+    let bracket = |p: &ProjectivePoint2D<T>, q: &ProjectivePoint2D<T>| {
+        let (u, v, w) = p.cross(q);
+        if line.a != zero {
+            u
+        } else if line.b != zero {
+            v
+        } else {
+            w
+        }
+    };
+    let denom = bracket(a, d) * bracket(b, c);
+    if denom == zero {
+        return Err(err(MathError::DivisionByZero));
+    }
+    Ok(bracket(a, c) * bracket(b, d) / denom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+
+    type Q = Ratio<i32>;
+
+    fn pp(x: i32, y: i32, z: i32) -> ProjectivePoint2D<Q> {
+        ProjectivePoint2D::new(Q::from(x), Q::from(y), Q::from(z))
+    }
+
+    #[test]
+    fn test_conversions() {
+        let p = Point2D::new(Q::new(1, 2), Q::from(-3));
+        let h = ProjectivePoint2D::from(p);
+        assert_eq!(h.to_point(), Ok(p));
+        let scaled = ProjectivePoint2D::new(h.x * Q::from(6), h.y * Q::from(6), Q::from(6));
+        assert!(scaled.equivalent(&h));
+        assert_eq!(scaled.normalized(), Ok(h));
+        assert_eq!(pp(1, 2, 0).to_point(), Err(MathError::DivisionByZero));
+        assert_eq!(pp(0, 0, 0).normalized(), Err(MathError::InvalidInput));
+        assert!(!pp(0, 0, 0).is_at_infinity());
+    }
+
+    #[test]
+    fn test_join_meet() {
+        let (a, b) = (pp(1, 2, 1), pp(3, -1, 1));
+        let line = a.join(&b).unwrap();
+        assert!(a.lies_on(&line) && b.lies_on(&line));
+        assert_eq!(a.join(&pp(2, 4, 2)), Err(MathError::InvalidInput));
+        let other = pp(0, 0, 1).join(&pp(1, 1, 1)).unwrap();
+        let meet = ProjectivePoint2D::meet(&line, &other).unwrap();
+        assert!(meet.lies_on(&line) && meet.lies_on(&other));
+        assert_eq!(
+            meet.to_point(),
+            Ok(Point2D::new(Q::new(7, 5), Q::new(7, 5)))
+        );
+        assert_eq!(
+            ProjectivePoint2D::meet(&line, &line),
+            Err(MathError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_cross_ratio() {
+        // the points 0, 1, 2, 3 on the line y = 2x + 1, rescaled freely
+        let (a, b, c, d) = (pp(0, 1, 1), pp(2, 6, 2), pp(2, 5, 1), pp(-3, -7, -1));
+        assert_eq!(cross_ratio(&a, &b, &c, &d), Ok(Q::new(4, 3)));
+        // swapping the pairs keeps it, swapping within a pair inverts it
+        assert_eq!(cross_ratio(&c, &d, &a, &b), Ok(Q::new(4, 3)));
+        assert_eq!(cross_ratio(&b, &a, &c, &d), Ok(Q::new(3, 4)));
+        // projective maps preserve it
+        let f = crate::transform::Projective2D::new(
+            [[1, 2, 0], [0, 1, 3], [1, 0, 1]].map(|r| r.map(Q::from)),
+        );
+        let image = |p: &ProjectivePoint2D<Q>| {
+            let [x, y, z] = f.apply_point(&[p.x, p.y, p.z]);
+            ProjectivePoint2D::new(x, y, z)
+        };
+        assert_eq!(
+            cross_ratio(&image(&a), &image(&b), &image(&c), &image(&d)),
+            Ok(Q::new(4, 3))
+        );
+        assert_eq!(cross_ratio(&a, &a, &c, &d), Err(MathError::InvalidInput));
+        assert_eq!(
+            cross_ratio(&a, &b, &c, &pp(1, 0, 1)),
+            Err(MathError::InvalidInput)
+        );
+        assert_eq!(cross_ratio(&a, &b, &c, &a), Err(MathError::DivisionByZero));
+    }
+}
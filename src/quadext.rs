@@ -0,0 +1,191 @@
+//! Quadratic surds `a + b·√d`.
+//!
+//! Some constructions leave the rationals by exactly one square root: the
+//! spreads of a regular pentagon lie in `ℚ(√5)`, those of a regular 16-gon
+//! in `ℚ(√2)`. [`QuadExt`] represents such numbers exactly, as a pair of
+//! coefficients over the base type together with the radicand.
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// The number `a + b·√d` in the quadratic extension of `T` by `√d`.
+///
+/// `d` should not be a square in `T`, so that the representation is unique.
+/// Arithmetic combines numbers with the same radicand and panics otherwise.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::quadext::QuadExt;
+/// let r = Rational32::from;
+/// let golden = QuadExt::new(Rational32::new(1, 2), Rational32::new(1, 2), r(5));
+/// // φ² = φ + 1
+/// assert_eq!(golden * golden, golden + QuadExt::rational(r(1), r(5)));
+/// assert_eq!(golden.norm(), r(-1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuadExt<T> {
+    pub a: T,
+    pub b: T,
+    pub d: T,
+}
+
+impl<T> QuadExt<T> {
+    /// Creates the number `a + b·√d`.
+    #[inline]
+    pub const fn new(a: T, b: T, d: T) -> Self {
+        QuadExt { a, b, d }
+    }
+}
+
+impl<T> QuadExt<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    /// Embeds `a` in the extension by `√d`.
+    #[inline]
+    pub fn rational(a: T, d: T) -> Self {
+        QuadExt::new(a, T::from(0), d)
+    }
+
+    /// Checks whether the irrational part vanishes.
+    #[inline]
+    pub fn is_rational(&self) -> bool {
+        self.b == T::from(0)
+    }
+
+    /// The conjugate `a − b·√d`.
+    #[inline]
+    pub fn conjugate(&self) -> Self {
+        QuadExt::new(self.a, T::from(0) - self.b, self.d)
+    }
+
+    /// The norm `a² − d·b²`, the product with the conjugate.
+    #[inline]
+    pub fn norm(&self) -> T {
+        self.a * self.a - self.d * self.b * self.b
+    }
+
+    /// The value as an element of `T`, given a square root in `T` for the
+    /// radicand: `a + b·√d` when `sqrt(d)` exists, or `a` when `b` or `d`
+    /// is zero.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::quadext::QuadExt;
+    /// let sqrt = |d: i32| (0..=d).find(|r| r * r == d);
+    /// assert_eq!(QuadExt::new(1, 2, 9).try_rational(sqrt), Some(7));
+    /// assert_eq!(QuadExt::new(1, 2, 8).try_rational(sqrt), None);
+    /// assert_eq!(QuadExt::new(1, 0, 8).try_rational(sqrt), Some(1));
+    /// ```
+    pub fn try_rational<F>(&self, sqrt: F) -> Option<T>
+    where
+        F: FnOnce(T) -> Option<T>,
+    {
+        let zero = T::from(0);
+        if self.b == zero || self.d == zero {
+            return Some(self.a);
+        }
+        sqrt(self.d).map(|r| self.a + self.b * r)
+    }
+
+    fn same_radicand(&self, other: &Self) -> T {
+        assert!(
+            self.d == other.d,
+            "quadratic surds with different radicands"
+        );
+        self.d
+    }
+}
+
+impl<T> Add for QuadExt<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let d = self.same_radicand(&rhs);
+        QuadExt::new(self.a + rhs.a, self.b + rhs.b, d)
+    }
+}
+
+impl<T> Sub for QuadExt<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let d = self.same_radicand(&rhs);
+        QuadExt::new(self.a - rhs.a, self.b - rhs.b, d)
+    }
+}
+
+impl<T> Mul for QuadExt<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let d = self.same_radicand(&rhs);
+        QuadExt::new(
+            self.a * rhs.a + d * self.b * rhs.b,
+            self.a * rhs.b + self.b * rhs.a,
+            d,
+        )
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for QuadExt<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        QuadExt::new(-self.a, -self.b, self.d)
+    }
+}
+
+/// Writes `a + b√d`, e.g. `5/8 + 1/8√5`.
+impl<T: fmt::Display> fmt::Display for QuadExt<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.a.fmt(f)?;
+        f.write_str(" + ")?;
+        self.b.fmt(f)?;
+        f.write_str("√")?;
+        self.d.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+
+    type Q = Ratio<i32>;
+
+    #[test]
+    fn test_arithmetic() {
+        let r = Q::from;
+        let x = QuadExt::new(r(1), r(1), r(2));
+        let y = x.conjugate();
+        assert_eq!(x * y, QuadExt::rational(x.norm(), r(2)));
+        assert_eq!(x.norm(), r(-1));
+        assert_eq!(x - x, QuadExt::rational(r(0), r(2)));
+        assert_eq!(-x + x, x - x);
+        assert!((x * y).is_rational());
+        assert_eq!(
+            QuadExt::new(Q::new(5, 8), Q::new(1, 8), r(5)).to_string(),
+            "5/8 + 1/8√5"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "different radicands")]
+    fn test_radicand_mismatch() {
+        let _ = QuadExt::new(1, 1, 2) + QuadExt::new(1, 1, 3);
+    }
+}
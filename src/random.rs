@@ -0,0 +1,221 @@
+//! Random exact directions and rotations.
+//!
+//! Every rational point on the unit circle has the form
+//!
+//! ```text
+//! ((n² − m²) / (n² + m²), 2mn / (n² + m²))
+//! ```
+//!
+//! for integers `m`, `n` not both zero (the rational parametrization with
+//! `t = m/n`, extended to `t = ∞`). The distributions here sample `m` and `n`
+//! uniformly from a box of the given *height*, so they produce exact
+//! rational directions and rotations with bounded denominators. That makes
+//! them suited to generating test isometries whose round trips must hold
+//! exactly.
+//!
+//! ```rust
+//! use num_rational::Rational32;
+//! use rand::{rngs::StdRng, Rng, SeedableRng};
+//! use rat_trig_rs::geometry::Point2D;
+//! use rat_trig_rs::random::UnitCircle;
+//! let mut rng = StdRng::seed_from_u64(7);
+//! let p: Point2D<Rational32> = rng.sample(UnitCircle::new(10));
+//! assert_eq!(p.x * p.x + p.y * p.y, Rational32::from(1));
+//! ```
+use crate::geometry::{Point2D, Vector2D};
+use crate::logging::Loggable;
+use crate::transform::{Affine2D, Isometry2D, Rotation3D};
+use core::convert::From;
+use core::ops::{Add, Div, Mul, Sub};
+use rand::distributions::Distribution;
+use rand::Rng;
+
+/// The largest supported height; it keeps `n² + m²` within an `i32`.
+pub const MAX_HEIGHT: i32 = 32767;
+
+/// Samples `(m, n)` with `|m| ≤ height`, `0 ≤ n ≤ height`, not both zero.
+fn sample_parameter<R: Rng + ?Sized>(rng: &mut R, height: i32) -> (i32, i32) {
+    loop {
+        let (m, n) = (rng.gen_range(-height..=height), rng.gen_range(0..=height));
+        if (m, n) != (0, 0) {
+            return (m, n);
+        }
+    }
+}
+
+/// The point of the unit circle with parameter `(m, n)`, as `(cos, sin)`.
+fn circle_point<T>(m: i32, n: i32) -> (T, T)
+where
+    T: Div<Output = T> + From<i32>,
+{
+    let denom = n * n + m * m;
+    (
+        T::from(n * n - m * m) / T::from(denom),
+        T::from(2 * m * n) / T::from(denom),
+    )
+}
+
+fn checked_height(height: i32) -> i32 {
+    assert!(
+        (1..=MAX_HEIGHT).contains(&height),
+        "the height must lie in 1..={}",
+        MAX_HEIGHT
+    );
+    height
+}
+
+/// A distribution of exact rational points on the unit circle
+/// `x² + y² = 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnitCircle {
+    height: i32,
+}
+
+impl UnitCircle {
+    /// Creates the distribution with parameters bounded by `height`.
+    ///
+    /// Panics unless `1 <= height <= MAX_HEIGHT`.
+    #[inline]
+    pub fn new(height: i32) -> Self {
+        UnitCircle {
+            height: checked_height(height),
+        }
+    }
+}
+
+impl<T> Distribution<Point2D<T>> for UnitCircle
+where
+    T: Div<Output = T> + From<i32>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point2D<T> {
+        let (m, n) = sample_parameter(rng, self.height);
+        let (x, y) = circle_point(m, n);
+        Point2D::new(x, y)
+    }
+}
+
+impl<T> Distribution<Vector2D<T>> for UnitCircle
+where
+    T: Div<Output = T> + From<i32>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector2D<T> {
+        let (m, n) = sample_parameter(rng, self.height);
+        let (x, y) = circle_point(m, n);
+        Vector2D::new(x, y)
+    }
+}
+
+/// A distribution of exact rational rotations.
+///
+/// Samples plane rotations about the origin, as [`Affine2D`] or
+/// [`Isometry2D`], with `(cos, sin)` drawn from [`UnitCircle`], and space
+/// rotations, as [`Rotation3D`], with integer Euler–Rodrigues parameters in
+/// `-height..=height`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RationalRotation {
+    height: i32,
+}
+
+impl RationalRotation {
+    /// Creates the distribution with parameters bounded by `height`.
+    ///
+    /// Panics unless `1 <= height <= MAX_HEIGHT`.
+    #[inline]
+    pub fn new(height: i32) -> Self {
+        RationalRotation {
+            height: checked_height(height),
+        }
+    }
+}
+
+impl<T> Distribution<Affine2D<T>> for RationalRotation
+where
+    T: Copy + Sub<Output = T> + Div<Output = T> + From<i32>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Affine2D<T> {
+        let (m, n) = sample_parameter(rng, self.height);
+        let (c, s): (T, T) = circle_point(m, n);
+        let zero = T::from(0);
+        Affine2D::new([[c, zero - s], [s, c]], Vector2D::new(zero, zero))
+    }
+}
+
+impl<T> Distribution<Isometry2D<T>> for RationalRotation
+where
+    T: Copy + Sub<Output = T> + Div<Output = T> + From<i32>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Isometry2D<T> {
+        Isometry2D::new(self.sample(rng))
+    }
+}
+
+impl<T> Distribution<Rotation3D<T>> for RationalRotation
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + PartialEq
+        + From<i32>
+        + Loggable,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Rotation3D<T> {
+        let h = self.height;
+        loop {
+            let mut param = || T::from(rng.gen_range(-h..=h));
+            if let Ok(rot) = Rotation3D::new(param(), param(), param(), param()) {
+                return rot;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Vector3D;
+    use num_rational::Ratio;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    type Q = Ratio<i32>;
+
+    #[test]
+    fn test_unit_circle() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut reached_west = false;
+        for _ in 0..500 {
+            let p: Point2D<Q> = rng.sample(UnitCircle::new(3));
+            assert_eq!(p.x * p.x + p.y * p.y, Q::from(1));
+            assert!(*p.x.denom() <= 18);
+            reached_west |= p == Point2D::new(Q::from(-1), Q::from(0));
+        }
+        // t = ∞ is included
+        assert!(reached_west);
+        let v: Vector2D<f64> = rng.sample(UnitCircle::new(MAX_HEIGHT));
+        assert!((v.x * v.x + v.y * v.y - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rotations() {
+        let mut rng = StdRng::seed_from_u64(2);
+        for _ in 0..20 {
+            let iso: Isometry2D<Q> = rng.sample(RationalRotation::new(10));
+            assert!(iso.affine.is_isometry());
+            assert!(iso.affine.is_orientation_preserving());
+
+            let rot: Rotation3D<Q> = rng.sample(RationalRotation::new(2));
+            let v = Vector3D::new(Q::from(1), Q::from(2), Q::from(-3));
+            let w = rot.apply_vector(&v);
+            let q = |u: &Vector3D<Q>| u.x * u.x + u.y * u.y + u.z * u.z;
+            assert_eq!(q(&w), q(&v));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the height must lie in")]
+    fn test_height_bounds() {
+        UnitCircle::new(0);
+    }
+}
@@ -0,0 +1,216 @@
+//! Convex regions cut out by half-planes.
+//!
+//! A [`ConvexRegion2D`] is the intersection of closed half-planes, each given
+//! by a [`Line2D`] `ax + by + c` and keeping the side where the expression is
+//! nonnegative. Membership and clipping only evaluate these expressions and
+//! compare them with zero, so they are exact over integers and rationals;
+//! this makes the region suitable for viewports and keep-out zones where a
+//! rounding error must not let a point slip across the boundary.
+use crate::geometry::{Line2D, Point2D};
+use alloc::vec::Vec;
+use core::convert::From;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// The intersection of the closed half-planes `ax + by + c >= 0`.
+///
+/// With no half-planes the region is the whole plane.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConvexRegion2D<T> {
+    pub half_planes: Vec<Line2D<T>>,
+}
+
+impl<T> ConvexRegion2D<T> {
+    /// Creates a region from its half-planes.
+    #[inline]
+    pub const fn new(half_planes: Vec<Line2D<T>>) -> Self {
+        ConvexRegion2D { half_planes }
+    }
+}
+
+impl<T> ConvexRegion2D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Creates the region bounded by a convex polygon whose vertices are
+    /// listed counterclockwise, keeping the left side of every edge.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::Point2D;
+    /// use rat_trig_rs::region::ConvexRegion2D;
+    /// let square = [(0, 0), (2, 0), (2, 2), (0, 2)].map(|(x, y)| Point2D::new(x, y));
+    /// let region = ConvexRegion2D::from_ccw_vertices(&square);
+    /// assert!(region.contains(&Point2D::new(1, 1)));
+    /// assert!(region.contains(&Point2D::new(2, 0)));
+    /// assert!(!region.contains(&Point2D::new(3, 1)));
+    /// ```
+    pub fn from_ccw_vertices(vertices: &[Point2D<T>]) -> Self {
+        let n = vertices.len();
+        let half_planes = (0..n)
+            .map(|i| {
+                let (p, q) = (&vertices[i], &vertices[(i + 1) % n]);
+                Line2D::new(p.y - q.y, q.x - p.x, p.x * q.y - p.y * q.x)
+            })
+            .collect();
+        ConvexRegion2D { half_planes }
+    }
+}
+
+impl<T> ConvexRegion2D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    /// Checks whether `p` lies in the region, boundary included.
+    #[inline]
+    pub fn contains(&self, p: &Point2D<T>) -> bool {
+        let zero = T::from(0);
+        self.half_planes.iter().all(|l| eval(l, p) >= zero)
+    }
+
+    /// The points of `points` that lie in the region, in their original
+    /// order.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::{Line2D, Point2D};
+    /// use rat_trig_rs::region::ConvexRegion2D;
+    /// // the quadrant x >= 0, y >= 0
+    /// let quadrant = ConvexRegion2D::new(vec![Line2D::new(1, 0, 0), Line2D::new(0, 1, 0)]);
+    /// let points = [(1, 2), (-1, 2), (0, 0), (3, -1)].map(|(x, y)| Point2D::new(x, y));
+    /// let inside: Vec<_> = quadrant.filter(&points).copied().collect();
+    /// assert_eq!(inside, [Point2D::new(1, 2), Point2D::new(0, 0)]);
+    /// ```
+    #[inline]
+    pub fn filter<'a>(
+        &'a self,
+        points: &'a [Point2D<T>],
+    ) -> impl Iterator<Item = &'a Point2D<T>> + 'a {
+        points.iter().filter(move |p| self.contains(p))
+    }
+}
+
+impl<T> ConvexRegion2D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>,
+{
+    /// Clips the segment from `p` to `q` to the region.
+    ///
+    /// Returns:
+    ///
+    /// The endpoints of the part of the segment inside the region, in the
+    /// direction from `p` to `q`, or `None` if the segment misses it. A
+    /// segment touching the region at a single point is clipped to that
+    /// point.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::Point2D;
+    /// use rat_trig_rs::region::ConvexRegion2D;
+    /// let r = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+    /// let square = ConvexRegion2D::from_ccw_vertices(&[r(0, 0), r(2, 0), r(2, 2), r(0, 2)]);
+    /// assert_eq!(square.clip_segment(&r(-1, 1), &r(3, 1)), Some((r(0, 1), r(2, 1))));
+    /// assert_eq!(square.clip_segment(&r(3, 0), &r(0, 3)), Some((r(2, 1), r(1, 2))));
+    /// assert_eq!(square.clip_segment(&r(3, 3), &r(4, 0)), None);
+    /// ```
+    pub fn clip_segment(&self, p: &Point2D<T>, q: &Point2D<T>) -> Option<(Point2D<T>, Point2D<T>)> {
+        let zero = T::from(0);
+        let (mut t0, mut t1) = (zero, T::from(1));
+        for l in &self.half_planes {
+            let (fp, fq) = (eval(l, p), eval(l, q));
+            match (fp >= zero, fq >= zero) {
+                (true, true) => {}
+                (false, false) => return None,
+                // the segment crosses the boundary line at t = fp / (fp - fq)
+                (false, true) => {
+                    let t = fp / (fp - fq);
+                    if t > t0 {
+                        t0 = t;
+                    }
+                }
+                (true, false) => {
+                    let t = fp / (fp - fq);
+                    if t < t1 {
+                        t1 = t;
+                    }
+                }
+            }
+            if t0 > t1 {
+                return None;
+            }
+        }
+        let at = |t: T| Point2D::new(p.x + t * (q.x - p.x), p.y + t * (q.y - p.y));
+        Some((at(t0), at(t1)))
+    }
+}
+
+/// The value of `ax + by + c` at `p`.
+#[inline]
+fn eval<T>(l: &Line2D<T>, p: &Point2D<T>) -> T
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    l.a * p.x + l.b * p.y + l.c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use num_rational::Ratio;
+
+    type Q = Ratio<i32>;
+
+    fn r(x: i32, y: i32) -> Point2D<Q> {
+        Point2D::new(Q::from(x), Q::from(y))
+    }
+
+    #[test]
+    fn test_contains() {
+        let tri = ConvexRegion2D::from_ccw_vertices(&[r(0, 0), r(4, 0), r(0, 4)]);
+        assert_eq!(tri.half_planes.len(), 3);
+        assert!(tri.contains(&r(1, 1)));
+        assert!(tri.contains(&r(2, 2)));
+        assert!(!tri.contains(&Point2D::new(Q::new(5, 2), Q::from(2))));
+        assert!(!tri.contains(&r(-1, 0)));
+        assert!(ConvexRegion2D::<Q>::default().contains(&r(100, -100)));
+        let points = [r(1, 1), r(5, 5), r(0, 4), r(-1, -1)];
+        assert_eq!(tri.filter(&points).count(), 2);
+    }
+
+    #[test]
+    fn test_clip_segment() {
+        let tri = ConvexRegion2D::from_ccw_vertices(&[r(0, 0), r(4, 0), r(0, 4)]);
+        // fully inside and reversed direction
+        assert_eq!(
+            tri.clip_segment(&r(1, 1), &r(2, 1)),
+            Some((r(1, 1), r(2, 1)))
+        );
+        assert_eq!(
+            tri.clip_segment(&r(5, 1), &r(-1, 1)),
+            Some((r(3, 1), r(0, 1)))
+        );
+        // touching the corner only
+        assert_eq!(
+            tri.clip_segment(&r(3, -1), &r(5, 1)),
+            Some((r(4, 0), r(4, 0)))
+        );
+        // passing the corner outside, though it crosses both legs' lines
+        assert_eq!(tri.clip_segment(&r(-2, 1), &r(1, -2)), None);
+        assert_eq!(tri.clip_segment(&r(5, 1), &r(1, 5)), None);
+        assert_eq!(tri.clip_segment(&r(-1, -1), &r(-2, 5)), None);
+
+        let empty = ConvexRegion2D::new(vec![Line2D::new(1, 0, -1), Line2D::new(-1, 0, 0)]);
+        assert!(!empty.contains(&Point2D::new(0, 0)));
+    }
+}
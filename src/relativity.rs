@@ -0,0 +1,215 @@
+//! Special relativity in 1+1 dimensions via the red metric.
+//!
+//! An event is a pair `(t, x)` in units where the speed of light is 1. The
+//! red quadrance `t² − x²` of a displacement is then the squared proper time
+//! along it, and the red spread between two world lines plays the role of
+//! the (squared) rapidity difference, so velocities can be composed and
+//! compared exactly without ever taking a hyperbolic tangent.
+use crate::error::MathError;
+use crate::logging::{degenerate, targets, Loggable};
+use crate::metric::BilinearForm2D;
+use core::convert::From;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// The function `interval_quadrance` calculates the red quadrance between two
+/// events, i.e. the squared spacetime interval `Δt² − Δx²`.
+///
+/// Arguments:
+///
+/// * `e_1`: The first event `(t, x)`.
+/// * `e_2`: The second event `(t, x)`.
+///
+/// Returns:
+///
+/// Positive for time-like, zero for light-like and negative for space-like
+/// separation.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::relativity::interval_quadrance;
+/// assert_eq!(interval_quadrance(&(0, 0), &(5, 3)), 16);
+/// ```
+#[inline]
+pub fn interval_quadrance<T>(e_1: &(T, T), e_2: &(T, T)) -> T
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    BilinearForm2D::red().quadrance(&(e_2.0 - e_1.0, e_2.1 - e_1.1))
+}
+
+/// The function `is_light_like` checks whether a displacement `(t, x)` is a
+/// null vector of the red metric, i.e. travels at the speed of light.
+#[inline]
+pub fn is_light_like<T>(v: &(T, T)) -> bool
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    BilinearForm2D::red().is_null(v)
+}
+
+/// The function `is_time_like` checks whether a displacement `(t, x)` has
+/// positive red quadrance.
+#[inline]
+pub fn is_time_like<T>(v: &(T, T)) -> bool
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    BilinearForm2D::red().quadrance(v) > T::from(0)
+}
+
+/// The function `is_space_like` checks whether a displacement `(t, x)` has
+/// negative red quadrance.
+#[inline]
+pub fn is_space_like<T>(v: &(T, T)) -> bool
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    BilinearForm2D::red().quadrance(v) < T::from(0)
+}
+
+/// The function `compose_velocities` adds two collinear velocities
+/// relativistically, `(u + v) / (1 + u·v)`.
+///
+/// Arguments:
+///
+/// * `u`: The velocity of a frame relative to the lab.
+/// * `v`: The velocity of an object relative to that frame.
+///
+/// Returns:
+///
+/// The velocity of the object relative to the lab, or
+/// `MathError::DivisionByZero` if `u·v = −1` (opposite light speeds).
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::relativity::compose_velocities;
+/// let half = Rational32::new(1, 2);
+/// assert_eq!(compose_velocities(&half, &half), Ok(Rational32::new(4, 5)));
+/// ```
+pub fn compose_velocities<T>(u: &T, v: &T) -> Result<T, MathError>
+where
+    T: Copy
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>
+        + Loggable,
+{
+    let denom = T::from(1) + *u * *v;
+    if denom == T::from(0) {
+        return Err(degenerate(
+            targets::RELATIVITY,
+            "compose_velocities",
+            MathError::DivisionByZero,
+            &(u, v),
+        ));
+    }
+    Ok((*u + *v) / denom)
+}
+
+/// The function `relative_velocity` calculates the velocity of `v` as seen
+/// from a frame moving at `u`, `(v − u) / (1 − u·v)`.
+///
+/// Returns `MathError::DivisionByZero` if `u·v = 1`.
+pub fn relative_velocity<T>(u: &T, v: &T) -> Result<T, MathError>
+where
+    T: Copy
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>
+        + Loggable,
+{
+    let denom = T::from(1) - *u * *v;
+    if denom == T::from(0) {
+        return Err(degenerate(
+            targets::RELATIVITY,
+            "relative_velocity",
+            MathError::DivisionByZero,
+            &(u, v),
+        ));
+    }
+    Ok((*v - *u) / denom)
+}
+
+/// The function `world_line_spread` calculates the red spread between the
+/// world lines of two inertial observers with velocities `u` and `v`.
+///
+/// If `w` is their relative velocity, the spread is `−w² / (1 − w²)`, so it
+/// encodes the rapidity difference rationally.
+///
+/// Returns:
+///
+/// The red spread, or `MathError::NullVector` if either velocity is the
+/// speed of light.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::relativity::world_line_spread;
+/// let s = world_line_spread(&Rational32::from(0), &Rational32::new(3, 5));
+/// assert_eq!(s, Ok(Rational32::new(-9, 16)));
+/// ```
+pub fn world_line_spread<T>(u: &T, v: &T) -> Result<T, MathError>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>
+        + Loggable,
+{
+    let one = T::from(1);
+    BilinearForm2D::red().spread(&(one, *u), &(one, *v))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+
+    type Q = Ratio<i32>;
+
+    #[test]
+    fn test_classification() {
+        assert!(is_light_like(&(3, -3)));
+        assert!(is_time_like(&(5, 3)));
+        assert!(is_space_like(&(1, 2)));
+        assert!(!is_time_like(&(2, 2)));
+        assert_eq!(interval_quadrance(&(1, 1), &(4, 1)), 9);
+    }
+
+    #[test]
+    fn test_velocity_composition() {
+        let (u, v) = (Q::new(1, 3), Q::new(1, 2));
+        let w = compose_velocities(&u, &v).unwrap();
+        assert_eq!(w, Q::new(5, 7));
+        assert_eq!(relative_velocity(&u, &w), Ok(v));
+        // light speed is invariant
+        assert_eq!(compose_velocities(&u, &Q::from(1)), Ok(Q::from(1)));
+        assert_eq!(
+            compose_velocities(&Q::from(1), &Q::from(-1)),
+            Err(MathError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_world_line_spread() {
+        let (u, v) = (Q::new(1, 3), Q::new(1, 2));
+        let w = relative_velocity(&u, &v).unwrap();
+        let expected = -w * w / (Q::from(1) - w * w);
+        assert_eq!(world_line_spread(&u, &v), Ok(expected));
+        assert_eq!(
+            world_line_spread(&u, &Q::from(1)),
+            Err(MathError::NullVector)
+        );
+    }
+}
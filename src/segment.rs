@@ -0,0 +1,419 @@
+//! Closed line segments and rays in the plane.
+//!
+//! A [`Segment2D`] is the set of points between its two endpoints. Incidence
+//! and intersection tests are sign tests on twists, and intersection points
+//! are found by Cramer's rule, so everything here is exact over integers
+//! (for the predicates) and rationals (for the points).
+//!
+//! A [`Ray2D`] reports its hits by the parameter `t` along its direction
+//! vector rather than by distance, so ray casting needs no square roots.
+use crate::geometry::{Line2D, Point2D, Vector2D};
+use crate::traits::AffineOps;
+use core::convert::From;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// The closed segment from `p1` to `p2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Segment2D<T> {
+    pub p1: Point2D<T>,
+    pub p2: Point2D<T>,
+}
+
+impl<T> Segment2D<T> {
+    /// Creates a segment from its endpoints.
+    #[inline]
+    pub const fn new(p1: Point2D<T>, p2: Point2D<T>) -> Self {
+        Segment2D { p1, p2 }
+    }
+}
+
+impl<T> Segment2D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    /// The quadrance between the endpoints.
+    #[inline]
+    pub fn quadrance(&self) -> T {
+        (self.p2 - self.p1).quadrance()
+    }
+
+    /// Checks whether `p` lies on the segment, endpoints included.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::Point2D;
+    /// use rat_trig_rs::segment::Segment2D;
+    /// let s = Segment2D::new(Point2D::new(0, 0), Point2D::new(4, 2));
+    /// assert!(s.contains_point(&Point2D::new(2, 1)));
+    /// assert!(s.contains_point(&Point2D::new(4, 2)));
+    /// assert!(!s.contains_point(&Point2D::new(6, 3)));
+    /// assert!(!s.contains_point(&Point2D::new(2, 2)));
+    /// ```
+    #[inline]
+    pub fn contains_point(&self, p: &Point2D<T>) -> bool {
+        orientation(&self.p1, &self.p2, p) == 0 && on_segment(&self.p1, &self.p2, p)
+    }
+
+    /// Checks whether the two segments share at least one point.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::Point2D;
+    /// use rat_trig_rs::segment::Segment2D;
+    /// let p = Point2D::new;
+    /// let s = Segment2D::new(p(0, 0), p(4, 4));
+    /// assert!(s.intersects(&Segment2D::new(p(0, 4), p(4, 0))));
+    /// assert!(s.intersects(&Segment2D::new(p(4, 4), p(5, 0))));
+    /// assert!(!s.intersects(&Segment2D::new(p(1, 0), p(5, 3))));
+    /// ```
+    #[inline]
+    pub fn intersects(&self, other: &Self) -> bool {
+        segments_intersect(&self.p1, &self.p2, &other.p1, &other.p2)
+    }
+}
+
+impl<T> Segment2D<T>
+where
+    T: Copy + Add<Output = T> + Div<Output = T> + From<i32>,
+{
+    /// The midpoint of the segment.
+    #[inline]
+    pub fn midpoint(&self) -> Point2D<T> {
+        self.p1.midpoint(&self.p2)
+    }
+}
+
+impl<T> Segment2D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>,
+{
+    /// The point the two segments have in common.
+    ///
+    /// Returns:
+    ///
+    /// The unique common point, or `None` if the segments are disjoint or
+    /// overlap along a piece of positive length; [`intersects`] tells these
+    /// cases apart.
+    ///
+    /// [`intersects`]: Segment2D::intersects
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::Point2D;
+    /// use rat_trig_rs::segment::Segment2D;
+    /// let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+    /// let s = Segment2D::new(p(0, 0), p(3, 1));
+    /// let hit = s.intersection_point(&Segment2D::new(p(0, 1), p(2, 0)));
+    /// assert_eq!(hit, Some(Point2D::new(Rational32::new(6, 5), Rational32::new(2, 5))));
+    /// // collinear, meeting end to end
+    /// assert_eq!(s.intersection_point(&Segment2D::new(p(3, 1), p(6, 2))), Some(p(3, 1)));
+    /// // collinear and overlapping
+    /// assert_eq!(s.intersection_point(&Segment2D::new(p(-3, -1), p(6, 2))), None);
+    /// ```
+    pub fn intersection_point(&self, other: &Self) -> Option<Point2D<T>> {
+        let (zero, one) = (T::from(0), T::from(1));
+        let (d_1, d_2) = (self.p2 - self.p1, other.p2 - other.p1);
+        let w = other.p1 - self.p1;
+        let denom = d_1.cross(&d_2);
+        if denom != zero {
+            let t = w.cross(&d_2) / denom;
+            let u = w.cross(&d_1) / denom;
+            let within = |t: T| zero <= t && t <= one;
+            return (within(t) && within(u)).then(|| self.p1 + d_1 * t);
+        }
+        // parallel or degenerate: the common points are bounded by endpoints
+        let mut common = [
+            (other.contains_point(&self.p1), self.p1),
+            (other.contains_point(&self.p2), self.p2),
+            (self.contains_point(&other.p1), other.p1),
+            (self.contains_point(&other.p2), other.p2),
+        ]
+        .into_iter()
+        .filter_map(|(on, p)| on.then_some(p));
+        let first = common.next()?;
+        common.all(|p| p == first).then_some(first)
+    }
+}
+
+/// The ray `origin + t·direction` for `t >= 0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ray2D<T> {
+    pub origin: Point2D<T>,
+    pub direction: Vector2D<T>,
+}
+
+/// Where a [`Ray2D`] hits something: `point = origin + t·direction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RayHit<T> {
+    /// The parameter of the hit along the direction vector, `t >= 0`.
+    pub t: T,
+    /// The point hit.
+    pub point: Point2D<T>,
+}
+
+impl<T> Ray2D<T> {
+    /// Creates a ray from its origin and direction.
+    #[inline]
+    pub const fn new(origin: Point2D<T>, direction: Vector2D<T>) -> Self {
+        Ray2D { origin, direction }
+    }
+}
+
+impl<T> Ray2D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>,
+{
+    /// The point at parameter `t`.
+    #[inline]
+    pub fn at(&self, t: T) -> Point2D<T> {
+        self.origin + self.direction * t
+    }
+
+    #[inline]
+    fn hit(&self, t: T) -> RayHit<T> {
+        RayHit {
+            t,
+            point: self.at(t),
+        }
+    }
+
+    /// Casts the ray against the line `a·x + b·y + c = 0`.
+    ///
+    /// Returns:
+    ///
+    /// The hit, or `None` if the ray points away from the line or runs
+    /// parallel to it. A ray starting on the line hits it at `t = 0`.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::{Line2D, Point2D, Vector2D};
+    /// use rat_trig_rs::segment::Ray2D;
+    /// let r = Rational32::from;
+    /// let ray = Ray2D::new(Point2D::new(r(0), r(0)), Vector2D::new(r(2), r(1)));
+    /// // the line x = 3
+    /// let hit = ray.intersects_line(&Line2D::new(r(1), r(0), r(-3))).unwrap();
+    /// assert_eq!(hit.t, Rational32::new(3, 2));
+    /// assert_eq!(hit.point, Point2D::new(r(3), Rational32::new(3, 2)));
+    /// assert!(ray.intersects_line(&Line2D::new(r(1), r(0), r(3))).is_none());
+    /// ```
+    pub fn intersects_line(&self, line: &Line2D<T>) -> Option<RayHit<T>> {
+        let zero = T::from(0);
+        let f = line.a * self.origin.x + line.b * self.origin.y + line.c;
+        let rate = line.normal().dot(&self.direction);
+        if rate == zero {
+            return (f == zero).then(|| self.hit(zero));
+        }
+        let t = (zero - f) / rate;
+        (t >= zero).then(|| self.hit(t))
+    }
+
+    /// Casts the ray against a closed segment.
+    ///
+    /// Returns:
+    ///
+    /// The first hit, or `None` if the ray misses. When the ray runs along
+    /// the segment, the hit is where it enters it.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::{Point2D, Vector2D};
+    /// use rat_trig_rs::segment::{Ray2D, Segment2D};
+    /// let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+    /// let ray = Ray2D::new(p(0, 0), Vector2D::new(Rational32::from(1), Rational32::from(1)));
+    /// let wall = Segment2D::new(p(4, 0), p(0, 4));
+    /// let hit = ray.intersects_segment(&wall).unwrap();
+    /// assert_eq!((hit.t, hit.point), (Rational32::from(2), p(2, 2)));
+    /// assert!(ray.intersects_segment(&Segment2D::new(p(4, 0), p(4, 3))).is_none());
+    /// ```
+    pub fn intersects_segment(&self, segment: &Segment2D<T>) -> Option<RayHit<T>> {
+        let (zero, one) = (T::from(0), T::from(1));
+        let d = self.direction;
+        let e = segment.p2 - segment.p1;
+        let w = segment.p1 - self.origin;
+        let denom = d.cross(&e);
+        if denom != zero {
+            let t = w.cross(&e) / denom;
+            let u = w.cross(&d) / denom;
+            return (t >= zero && zero <= u && u <= one).then(|| self.hit(t));
+        }
+        // parallel, or the ray is a point
+        let q_d = d.quadrance();
+        if q_d == zero {
+            return segment.contains_point(&self.origin).then(|| self.hit(zero));
+        }
+        if w.cross(&d) != zero {
+            return None;
+        }
+        // collinear: the segment covers the parameters between its ends
+        let t_1 = w.dot(&d) / q_d;
+        let t_2 = (segment.p2 - self.origin).dot(&d) / q_d;
+        let (lo, hi) = if t_1 <= t_2 { (t_1, t_2) } else { (t_2, t_1) };
+        (hi >= zero).then(|| self.hit(if lo > zero { lo } else { zero }))
+    }
+}
+
+/// The sign of a twist as `-1`, `0` or `1`.
+pub(crate) fn orientation<T>(a: &Point2D<T>, b: &Point2D<T>, c: &Point2D<T>) -> i32
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    let twist = a.twist(b, c);
+    let zero = T::from(0);
+    if twist > zero {
+        1
+    } else if twist < zero {
+        -1
+    } else {
+        0
+    }
+}
+
+/// Checks whether `c`, known to be collinear with `a` and `b`, lies on the
+/// closed segment `ab`.
+pub(crate) fn on_segment<T: PartialOrd>(a: &Point2D<T>, b: &Point2D<T>, c: &Point2D<T>) -> bool {
+    let between = |u: &T, v: &T, w: &T| (u <= w && w <= v) || (v <= w && w <= u);
+    between(&a.x, &b.x, &c.x) && between(&a.y, &b.y, &c.y)
+}
+
+/// Checks whether the closed segments `p1p2` and `q1q2` share a point.
+pub(crate) fn segments_intersect<T>(
+    p1: &Point2D<T>,
+    p2: &Point2D<T>,
+    q1: &Point2D<T>,
+    q2: &Point2D<T>,
+) -> bool
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    let (d1, d2) = (orientation(q1, q2, p1), orientation(q1, q2, p2));
+    let (d3, d4) = (orientation(p1, p2, q1), orientation(p1, p2, q2));
+    if d1 * d2 < 0 && d3 * d4 < 0 {
+        return true;
+    }
+    (d1 == 0 && on_segment(q1, q2, p1))
+        || (d2 == 0 && on_segment(q1, q2, p2))
+        || (d3 == 0 && on_segment(p1, p2, q1))
+        || (d4 == 0 && on_segment(p1, p2, q2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+
+    type Q = Ratio<i32>;
+
+    fn p(x: i32, y: i32) -> Point2D<Q> {
+        Point2D::new(Q::from(x), Q::from(y))
+    }
+
+    #[test]
+    fn test_measures() {
+        let s = Segment2D::new(p(1, 2), p(4, 6));
+        assert_eq!(s.quadrance(), Q::from(25));
+        assert_eq!(s.midpoint(), Point2D::new(Q::new(5, 2), Q::from(4)));
+        assert!(s.contains_point(&s.midpoint()));
+    }
+
+    #[test]
+    fn test_intersection_point() {
+        let s = Segment2D::new(p(0, 0), p(4, 0));
+        // crossing, touching at an endpoint, and missing
+        assert_eq!(
+            s.intersection_point(&Segment2D::new(p(1, -1), p(3, 3))),
+            Some(Point2D::new(Q::new(3, 2), Q::from(0)))
+        );
+        assert_eq!(
+            s.intersection_point(&Segment2D::new(p(2, 0), p(2, 5))),
+            Some(p(2, 0))
+        );
+        assert_eq!(
+            s.intersection_point(&Segment2D::new(p(5, -1), p(5, 1))),
+            None
+        );
+        assert!(!s.intersects(&Segment2D::new(p(5, -1), p(5, 1))));
+        // parallel, and collinear but apart
+        assert_eq!(
+            s.intersection_point(&Segment2D::new(p(0, 1), p(4, 1))),
+            None
+        );
+        assert_eq!(
+            s.intersection_point(&Segment2D::new(p(5, 0), p(6, 0))),
+            None
+        );
+        // overlapping: they intersect, but not in a single point
+        let overlap = Segment2D::new(p(2, 0), p(6, 0));
+        assert!(s.intersects(&overlap));
+        assert_eq!(s.intersection_point(&overlap), None);
+        // point segments
+        let dot = Segment2D::new(p(3, 0), p(3, 0));
+        assert_eq!(s.intersection_point(&dot), Some(p(3, 0)));
+        assert_eq!(dot.intersection_point(&dot), Some(p(3, 0)));
+        assert_eq!(
+            dot.intersection_point(&Segment2D::new(p(1, 1), p(1, 1))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_ray_casting() {
+        let v = |x: i32, y: i32| Vector2D::new(Q::from(x), Q::from(y));
+        let ray = Ray2D::new(p(1, 1), v(3, 0));
+        let wall = Segment2D::new(p(4, -2), p(4, 2));
+        let hit = ray.intersects_segment(&wall).unwrap();
+        assert_eq!((hit.t, hit.point), (Q::from(1), p(4, 1)));
+        // behind the origin, and grazing an endpoint
+        assert!(Ray2D::new(p(5, 1), v(3, 0))
+            .intersects_segment(&wall)
+            .is_none());
+        let hit = Ray2D::new(p(1, 2), v(1, 0)).intersects_segment(&wall);
+        assert_eq!(hit.map(|h| h.point), Some(p(4, 2)));
+        // running along the segment enters at its nearer end
+        let along = Ray2D::new(p(4, -5), v(0, 2));
+        assert_eq!(
+            along.intersects_segment(&wall).map(|h| h.t),
+            Some(Q::new(3, 2))
+        );
+        let inside = Ray2D::new(p(4, 0), v(0, -1));
+        assert_eq!(
+            inside.intersects_segment(&wall).map(|h| h.t),
+            Some(Q::from(0))
+        );
+        assert!(Ray2D::new(p(4, 3), v(0, 1))
+            .intersects_segment(&wall)
+            .is_none());
+        // a ray of zero direction only hits what it starts on
+        let stuck = Ray2D::new(p(4, 0), v(0, 0));
+        assert_eq!(
+            stuck.intersects_segment(&wall).map(|h| h.t),
+            Some(Q::from(0))
+        );
+        assert!(stuck
+            .intersects_line(&Line2D::new(Q::from(1), Q::from(0), Q::from(0)))
+            .is_none());
+        let line = Line2D::new(Q::from(0), Q::from(1), Q::from(-1));
+        assert_eq!(ray.intersects_line(&line).map(|h| h.t), Some(Q::from(0)));
+    }
+}
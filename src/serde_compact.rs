@@ -0,0 +1,200 @@
+//! Compact serde representations for the geometry types.
+//!
+//! With the `serde` feature, the geometry types derive `Serialize` and
+//! `Deserialize` in struct form, e.g. `{"x": 3, "y": 4}`. For large point
+//! clouds that is needlessly verbose, so the modules here provide an array
+//! form instead, selectable per field with `#[serde(with = ...)]`:
+//!
+//! * points and vectors as `[x, y]` / `[x, y, z]`,
+//! * triangles as nested arrays `[[x, y], [x, y], [x, y]]`,
+//! * sequences of points as arrays of arrays.
+//!
+//! ```rust
+//! use rat_trig_rs::geometry::Point2D;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Marker {
+//!     #[serde(with = "rat_trig_rs::serde_compact::point2d")]
+//!     position: Point2D<i32>,
+//! }
+//!
+//! let json = serde_json::to_string(&Marker { position: Point2D::new(3, 4) }).unwrap();
+//! assert_eq!(json, r#"{"position":[3,4]}"#);
+//! ```
+
+macro_rules! compact_module {
+    ($name:ident, $ty:ident, $repr:ty, $to:expr, $from:expr) => {
+        #[doc = concat!("Serializes a [`", stringify!($ty), "`](crate::geometry::", stringify!($ty), ") in array form.")]
+        pub mod $name {
+            use crate::geometry::*;
+            use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            /// Serializes the value in array form.
+            pub fn serialize<T, S>(value: &$ty<T>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: Copy + Serialize,
+                S: Serializer,
+            {
+                let to: fn(&$ty<T>) -> $repr = $to;
+                to(value).serialize(serializer)
+            }
+
+            /// Deserializes the value from array form.
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<$ty<T>, D::Error>
+            where
+                T: Copy + Deserialize<'de>,
+                D: Deserializer<'de>,
+            {
+                let from: fn($repr) -> $ty<T> = $from;
+                <$repr>::deserialize(deserializer).map(from)
+            }
+        }
+    };
+}
+
+compact_module!(point2d, Point2D, [T; 2], |p| [p.x, p.y], |[x, y]| {
+    Point2D::new(x, y)
+});
+compact_module!(vector2d, Vector2D, [T; 2], |v| [v.x, v.y], |[x, y]| {
+    Vector2D::new(x, y)
+});
+compact_module!(point3d, Point3D, [T; 3], |p| [p.x, p.y, p.z], |[x, y, z]| {
+    Point3D::new(x, y, z)
+});
+compact_module!(
+    vector3d,
+    Vector3D,
+    [T; 3],
+    |v| [v.x, v.y, v.z],
+    |[x, y, z]| { Vector3D::new(x, y, z) }
+);
+compact_module!(
+    triangle2d,
+    Triangle2D,
+    [[T; 2]; 3],
+    |t| [[t.p1.x, t.p1.y], [t.p2.x, t.p2.y], [t.p3.x, t.p3.y]],
+    |[a, b, c]| Triangle2D::new(
+        Point2D::new(a[0], a[1]),
+        Point2D::new(b[0], b[1]),
+        Point2D::new(c[0], c[1])
+    )
+);
+compact_module!(
+    triangle3d,
+    Triangle3D,
+    [[T; 3]; 3],
+    |t| [
+        [t.p1.x, t.p1.y, t.p1.z],
+        [t.p2.x, t.p2.y, t.p2.z],
+        [t.p3.x, t.p3.y, t.p3.z]
+    ],
+    |[a, b, c]| Triangle3D::new(
+        Point3D::new(a[0], a[1], a[2]),
+        Point3D::new(b[0], b[1], b[2]),
+        Point3D::new(c[0], c[1], c[2])
+    )
+);
+
+/// Serializes a `Vec` of [`Point2D`](crate::geometry::Point2D) as an array
+/// of `[x, y]` arrays.
+#[cfg(feature = "alloc")]
+pub mod points2d {
+    use crate::geometry::Point2D;
+    use alloc::vec::Vec;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes the points in array form.
+    pub fn serialize<T, S>(points: &[Point2D<T>], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Copy + Serialize,
+        S: Serializer,
+    {
+        serializer.collect_seq(points.iter().map(|p| [p.x, p.y]))
+    }
+
+    /// Deserializes the points from array form.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<Point2D<T>>, D::Error>
+    where
+        T: Copy + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let raw = Vec::<[T; 2]>::deserialize(deserializer)?;
+        Ok(raw.into_iter().map(|[x, y]| Point2D::new(x, y)).collect())
+    }
+}
+
+/// Serializes a `Vec` of [`Point3D`](crate::geometry::Point3D) as an array
+/// of `[x, y, z]` arrays.
+#[cfg(feature = "alloc")]
+pub mod points3d {
+    use crate::geometry::Point3D;
+    use alloc::vec::Vec;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes the points in array form.
+    pub fn serialize<T, S>(points: &[Point3D<T>], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Copy + Serialize,
+        S: Serializer,
+    {
+        serializer.collect_seq(points.iter().map(|p| [p.x, p.y, p.z]))
+    }
+
+    /// Deserializes the points from array form.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<Point3D<T>>, D::Error>
+    where
+        T: Copy + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let raw = Vec::<[T; 3]>::deserialize(deserializer)?;
+        Ok(raw
+            .into_iter()
+            .map(|[x, y, z]| Point3D::new(x, y, z))
+            .collect())
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::geometry::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Scene {
+        #[serde(with = "super::point2d")]
+        origin: Point2D<i32>,
+        #[serde(with = "super::vector3d")]
+        up: Vector3D<i32>,
+        #[serde(with = "super::triangle2d")]
+        face: Triangle2D<f64>,
+        #[serde(with = "super::points2d")]
+        cloud: Vec<Point2D<i32>>,
+    }
+
+    #[test]
+    fn test_compact_round_trip() {
+        let scene = Scene {
+            origin: Point2D::new(1, 2),
+            up: Vector3D::new(0, 0, 1),
+            face: Triangle2D::new(
+                Point2D::new(0.0, 0.0),
+                Point2D::new(1.5, 0.0),
+                Point2D::new(0.0, 2.0),
+            ),
+            cloud: vec![Point2D::new(3, 4), Point2D::new(-1, 0)],
+        };
+        let json = serde_json::to_string(&scene).unwrap();
+        assert_eq!(
+            json,
+            r#"{"origin":[1,2],"up":[0,0,1],"face":[[0.0,0.0],[1.5,0.0],[0.0,2.0]],"cloud":[[3,4],[-1,0]]}"#
+        );
+        assert_eq!(serde_json::from_str::<Scene>(&json).unwrap(), scene);
+    }
+
+    #[test]
+    fn test_default_struct_form() {
+        let json = serde_json::to_string(&Point2D::new(3, 4)).unwrap();
+        assert_eq!(json, r#"{"x":3,"y":4}"#);
+    }
+}
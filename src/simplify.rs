@@ -0,0 +1,154 @@
+//! Polyline simplification with a quadrance tolerance.
+//!
+//! [`simplify_polyline`] is the Douglas–Peucker algorithm with distances
+//! replaced by quadrances. The deviation of a point from a segment is
+//! compared with the tolerance after clearing the denominator `Q(a, b)`, so
+//! over integers and rationals every decision is exact, and over `f64` no
+//! square root is taken.
+use crate::geometry::Point2D;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::From;
+use core::ops::{Add, Mul, Sub};
+
+/// The quadrance from `p` to the closed segment `ab`, as a fraction
+/// `(numerator, denominator)` with a positive denominator that depends only
+/// on the segment.
+fn segment_deviation<T>(p: &Point2D<T>, a: &Point2D<T>, b: &Point2D<T>) -> (T, T)
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    let zero = T::from(0);
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let (px, py) = (p.x - a.x, p.y - a.y);
+    let q_ab = dx * dx + dy * dy;
+    if q_ab == zero {
+        return (px * px + py * py, T::from(1));
+    }
+    let dot = px * dx + py * dy;
+    let num = if dot <= zero {
+        (px * px + py * py) * q_ab
+    } else if dot >= q_ab {
+        let (qx, qy) = (p.x - b.x, p.y - b.y);
+        (qx * qx + qy * qy) * q_ab
+    } else {
+        // the foot of the perpendicular lies inside the segment
+        let cross = px * dy - py * dx;
+        cross * cross
+    };
+    (num, q_ab)
+}
+
+/// The function `simplify_polyline` simplifies a polyline with the
+/// Douglas–Peucker algorithm.
+///
+/// Arguments:
+///
+/// * `points`: The vertices of the polyline, in order.
+/// * `tolerance`: The largest quadrance (squared distance) a dropped vertex
+///   may have from the simplified polyline.
+///
+/// Returns:
+///
+/// The retained vertices, in order. The endpoints are always kept, and every
+/// dropped vertex lies within quadrance `tolerance` of the segment that
+/// replaced it; a vertex exactly at the tolerance is dropped.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::geometry::Point2D;
+/// use rat_trig_rs::simplify::simplify_polyline;
+/// let track = [(0, 0), (1, 1), (2, 0), (3, 0), (4, 5), (5, 0)].map(|(x, y)| Point2D::new(x, y));
+/// let kept = simplify_polyline(&track, 1);
+/// assert_eq!(kept, [(0, 0), (3, 0), (4, 5), (5, 0)].map(|(x, y)| Point2D::new(x, y)));
+/// ```
+pub fn simplify_polyline<T>(points: &[Point2D<T>], tolerance: T) -> Vec<Point2D<T>>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    let n = points.len();
+    if n < 3 {
+        return points.to_vec();
+    }
+    let mut keep = vec![false; n];
+    keep[0] = true;
+    keep[n - 1] = true;
+    let mut stack = vec![(0, n - 1)];
+    while let Some((first, last)) = stack.pop() {
+        let (a, b) = (&points[first], &points[last]);
+        let mut farthest: Option<(usize, T, T)> = None;
+        for (i, p) in points.iter().enumerate().take(last).skip(first + 1) {
+            let (num, den) = segment_deviation(p, a, b);
+            // the denominator is shared by all points against this segment
+            if farthest.iter().all(|&(_, best, _)| num > best) {
+                farthest = Some((i, num, den));
+            }
+        }
+        if let Some((i, num, den)) = farthest {
+            if num > tolerance * den {
+                keep[i] = true;
+                stack.push((first, i));
+                stack.push((i, last));
+            }
+        }
+    }
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(p, k)| k.then_some(*p))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+
+    type Q = Ratio<i32>;
+
+    fn points(coords: &[(i32, i32)]) -> Vec<Point2D<i32>> {
+        coords.iter().map(|&(x, y)| Point2D::new(x, y)).collect()
+    }
+
+    #[test]
+    fn test_segment_deviation() {
+        let (a, b) = (Point2D::new(0, 0), Point2D::new(4, 0));
+        assert_eq!(segment_deviation(&Point2D::new(2, 3), &a, &b), (9 * 16, 16));
+        assert_eq!(
+            segment_deviation(&Point2D::new(-1, 1), &a, &b),
+            (2 * 16, 16)
+        );
+        assert_eq!(segment_deviation(&Point2D::new(6, 1), &a, &b), (5 * 16, 16));
+        assert_eq!(segment_deviation(&Point2D::new(1, 1), &a, &a), (2, 1));
+    }
+
+    #[test]
+    fn test_simplify_polyline() {
+        let line = points(&[(0, 0), (1, 0), (2, 0), (3, 0)]);
+        assert_eq!(simplify_polyline(&line, 0), points(&[(0, 0), (3, 0)]));
+        let zigzag = points(&[(0, 0), (1, 1), (2, 0), (3, 1), (4, 0)]);
+        // every vertex is exactly at quadrance 1, which is within tolerance
+        assert_eq!(simplify_polyline(&zigzag, 1), points(&[(0, 0), (4, 0)]));
+        assert_eq!(simplify_polyline(&zigzag, 0), zigzag);
+        // a closed loop keeps the vertex farthest from its endpoints
+        let ring = points(&[(0, 0), (2, 0), (2, 2), (0, 0)]);
+        assert_eq!(
+            simplify_polyline(&ring, 4),
+            points(&[(0, 0), (2, 2), (0, 0)])
+        );
+        assert_eq!(simplify_polyline(&points(&[(0, 0), (5, 5)]), 0).len(), 2);
+        assert!(simplify_polyline::<i32>(&[], 0).is_empty());
+    }
+
+    #[test]
+    fn test_rational_and_float() {
+        let r = |x: i32, y: i32| Point2D::new(Q::from(x), Q::from(y));
+        let bump = [r(0, 0), Point2D::new(Q::from(1), Q::new(1, 10)), r(2, 0)];
+        assert_eq!(simplify_polyline(&bump, Q::new(1, 100)).len(), 2);
+        assert_eq!(simplify_polyline(&bump, Q::new(1, 101)).len(), 3);
+        let bump = [(0.0, 0.0), (1.0, 0.5), (2.0, 0.0)].map(|(x, y)| Point2D::new(x, y));
+        assert_eq!(simplify_polyline(&bump, 0.25).len(), 2);
+        assert_eq!(simplify_polyline(&bump, 0.24).len(), 3);
+    }
+}
@@ -0,0 +1,421 @@
+//! Triangle solvers.
+//!
+//! Each solver takes a rational analogue of a classical congruence case
+//! (SSS, SAS, ASA) and returns the whole triangle as a [`TriangleSolution`]:
+//! its quadrances, spreads, quadrea and shape. Over the rationals the
+//! results are exact.
+use crate::analysis::TriangleClass;
+use crate::error::MathError;
+use crate::logging::{degenerate, targets, Loggable};
+use crate::quadext::QuadExt;
+use crate::trigonom::{archimedes, cross_law_solve};
+use core::convert::From;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// A solved triangle. Spread `sᵢ` is at the vertex opposite the side of
+/// quadrance `qᵢ`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TriangleSolution<T> {
+    /// `(q₁, q₂, q₃)`.
+    pub quadrances: (T, T, T),
+    /// `(s₁, s₂, s₃)`.
+    pub spreads: (T, T, T),
+    /// The quadrea `A = 16·area²`.
+    pub quadrea: T,
+    /// The shape of the triangle.
+    pub class: TriangleClass,
+}
+
+/// Up to two triangles solving an ambiguous case, see [`solve_sas`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TriangleSolutions<T> {
+    solutions: [Option<TriangleSolution<T>>; 2],
+}
+
+impl<T: PartialEq> TriangleSolutions<T> {
+    fn new(first: Option<TriangleSolution<T>>, second: Option<TriangleSolution<T>>) -> Self {
+        let second = if second == first { None } else { second };
+        match first {
+            Some(_) => TriangleSolutions {
+                solutions: [first, second],
+            },
+            None => TriangleSolutions {
+                solutions: [second, None],
+            },
+        }
+    }
+}
+
+impl<T> TriangleSolutions<T> {
+    /// The number of distinct solutions.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.solutions.iter().flatten().count()
+    }
+
+    /// Checks whether there is no solution.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.solutions[0].is_none()
+    }
+
+    /// The solutions, in the order documented by the solver.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &TriangleSolution<T>> {
+        self.solutions.iter().flatten()
+    }
+}
+
+/// The function `solve_sss` solves a triangle given its three quadrances.
+///
+/// Arguments:
+///
+/// * `q_1`, `q_2`, `q_3`: The quadrances of the sides.
+///
+/// Returns:
+///
+/// The solution, with spreads `s₁ = A / (4·q₂·q₃)` (and cyclically) from the
+/// quadrea `A`. Three collinear points of distinct positions give a
+/// `Degenerate` solution with zero spreads. Returns
+/// `MathError::NegativeQuadrance` if a quadrance is negative,
+/// `MathError::InvalidInput` if `A < 0` (no triangle has these sides) and
+/// `MathError::DegenerateTriangle` if a quadrance is zero.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::analysis::TriangleClass;
+/// use rat_trig_rs::solver::solve_sss;
+/// let r = Rational32::new;
+/// let t = solve_sss(&r(25, 1), &r(9, 1), &r(16, 1)).unwrap();
+/// assert_eq!(t.spreads, (r(1, 1), r(9, 25), r(16, 25)));
+/// assert_eq!(t.quadrea, r(576, 1));
+/// assert_eq!(t.class, TriangleClass::Right);
+/// ```
+pub fn solve_sss<T>(q_1: &T, q_2: &T, q_3: &T) -> Result<TriangleSolution<T>, MathError>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>
+        + Loggable,
+{
+    let (zero, four) = (T::from(0), T::from(4));
+    let (q_1, q_2, q_3) = (*q_1, *q_2, *q_3);
+    if q_1 < zero || q_2 < zero || q_3 < zero {
+        return Err(degenerate(
+            targets::SOLVER,
+            "solve_sss",
+            MathError::NegativeQuadrance,
+            &(q_1, q_2, q_3),
+        ));
+    }
+    if q_1 == zero || q_2 == zero || q_3 == zero {
+        return Err(degenerate(
+            targets::SOLVER,
+            "solve_sss",
+            MathError::DegenerateTriangle,
+            &(q_1, q_2, q_3),
+        ));
+    }
+    let quadrea = archimedes(&q_1, &q_2, &q_3);
+    if quadrea < zero {
+        return Err(degenerate(
+            targets::SOLVER,
+            "solve_sss",
+            MathError::InvalidInput,
+            &(q_1, q_2, q_3),
+        ));
+    }
+    let class = if quadrea == zero {
+        TriangleClass::Degenerate
+    } else {
+        TriangleClass::of_proper(&q_1, &q_2, &q_3)
+    };
+    Ok(TriangleSolution {
+        quadrances: (q_1, q_2, q_3),
+        spreads: (
+            quadrea / (four * q_2 * q_3),
+            quadrea / (four * q_1 * q_3),
+            quadrea / (four * q_1 * q_2),
+        ),
+        quadrea,
+        class,
+    })
+}
+
+/// The function `solve_sas` solves a triangle given two quadrances and the
+/// spread between them.
+///
+/// The cross law gives `q₃ = q₁ + q₂ ± √D` with `D = 4·q₁·q₂·(1 − s₃)`. A
+/// spread does not tell an angle from its supplement, so both signs give a
+/// triangle with the requested data.
+///
+/// Arguments:
+///
+/// * `q_1`, `q_2`: The quadrances of two sides.
+/// * `s_3`: The spread between them.
+/// * `sqrt`: The square root in `T`, or `None` where it does not exist.
+///
+/// Returns:
+///
+/// The solutions, the smaller `q₃` (the acute angle) first; there is one
+/// when `s₃ = 1`. Returns `MathError::InvalidInput` unless `0 <= s₃ <= 1`,
+/// `MathError::NotRepresentable` if `sqrt(D)` fails, and the errors of
+/// [`solve_sss`] otherwise.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::solver::solve_sas;
+/// let r = Rational32::new;
+/// let sqrt = |d: Rational32| (0..100).map(Rational32::from).find(|&x| x * x == d);
+/// // sides 5 and 5 with spread 16/25
+/// let solutions = solve_sas(&r(25, 1), &r(25, 1), &r(16, 25), sqrt).unwrap();
+/// let q_3: Vec<_> = solutions.iter().map(|t| t.quadrances.2).collect();
+/// assert_eq!(q_3, [r(20, 1), r(80, 1)]);
+/// ```
+pub fn solve_sas<T, F>(
+    q_1: &T,
+    q_2: &T,
+    s_3: &T,
+    sqrt: F,
+) -> Result<TriangleSolutions<T>, MathError>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>
+        + Loggable,
+    F: Fn(T) -> Option<T>,
+{
+    if *s_3 < T::from(0) || *s_3 > T::from(1) {
+        return Err(degenerate(
+            targets::SOLVER,
+            "solve_sas",
+            MathError::InvalidInput,
+            &(q_1, q_2, s_3),
+        ));
+    }
+    let [near, far] = cross_law_solve(q_1, q_2, s_3)?;
+    let (Some(near), Some(far)) = (near.try_rational(&sqrt), far.try_rational(&sqrt)) else {
+        return Err(degenerate(
+            targets::SOLVER,
+            "solve_sas",
+            MathError::NotRepresentable,
+            &(q_1, q_2, s_3),
+        ));
+    };
+    let near = solve_sss(q_1, q_2, &near)?;
+    let far = solve_sss(q_1, q_2, &far)?;
+    Ok(TriangleSolutions::new(Some(near), Some(far)))
+}
+
+/// The function `solve_asa` solves a triangle given two spreads and one
+/// quadrance.
+///
+/// The triple spread formula, as a quadratic in the third spread, gives
+/// `s₃ = s₁ + s₂ − 2·s₁·s₂ ± √D` with `D = 4·s₁·s₂·(1 − s₁)·(1 − s₂)`: the
+/// two signs are the spreads of the sum and of the difference of the
+/// angles. The spread law `q₁/s₁ = q₂/s₂ = q₃/s₃` then gives the sides.
+///
+/// Arguments:
+///
+/// * `s_1`, `s_2`: The spreads at two vertices.
+/// * `q_3`: The quadrance of the side between those vertices.
+/// * `sqrt`: The square root in `T`, or `None` where it does not exist.
+///
+/// Returns:
+///
+/// The nondegenerate solutions, the one with the smaller `s₃` first; a root
+/// with `s₃ = 0` has no triangle and is skipped. Returns
+/// `MathError::InvalidInput` unless `0 < s₁, s₂ <= 1` and `q₃ > 0`, and
+/// `MathError::NotRepresentable` if `sqrt(D)` fails.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::solver::solve_asa;
+/// let r = Rational32::new;
+/// let sqrt = |d: Rational32| {
+///     let root = |k: i32| (0..=k).find(|x| x * x == k);
+///     Some(Rational32::new(root(*d.numer())?, root(*d.denom())?))
+/// };
+/// // two spreads of 3/4 on a unit side: only the equilateral triangle
+/// let solutions = solve_asa(&r(3, 4), &r(3, 4), &r(1, 1), sqrt).unwrap();
+/// assert_eq!(solutions.len(), 1);
+/// let t = solutions.iter().next().unwrap();
+/// assert_eq!(t.quadrances, (r(1, 1), r(1, 1), r(1, 1)));
+/// ```
+pub fn solve_asa<T, F>(
+    s_1: &T,
+    s_2: &T,
+    q_3: &T,
+    sqrt: F,
+) -> Result<TriangleSolutions<T>, MathError>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>
+        + Loggable,
+    F: Fn(T) -> Option<T>,
+{
+    let (zero, one, two, four) = (T::from(0), T::from(1), T::from(2), T::from(4));
+    let (s_1, s_2, q_3) = (*s_1, *s_2, *q_3);
+    let spread = |s: T| zero < s && s <= one;
+    if !(spread(s_1) && spread(s_2) && q_3 > zero) {
+        return Err(degenerate(
+            targets::SOLVER,
+            "solve_asa",
+            MathError::InvalidInput,
+            &(s_1, s_2, q_3),
+        ));
+    }
+    let disc = four * s_1 * s_2 * (one - s_1) * (one - s_2);
+    let root = QuadExt::new(s_1 + s_2 - two * s_1 * s_2, one, disc);
+    let (Some(low), Some(high)) = (
+        root.conjugate().try_rational(&sqrt),
+        root.try_rational(&sqrt),
+    ) else {
+        return Err(degenerate(
+            targets::SOLVER,
+            "solve_asa",
+            MathError::NotRepresentable,
+            &(s_1, s_2, q_3),
+        ));
+    };
+    let solve = |s_3: T| {
+        if s_3 == zero {
+            return Ok(None);
+        }
+        let ratio = q_3 / s_3;
+        solve_sss(&(ratio * s_1), &(ratio * s_2), &q_3).map(Some)
+    };
+    Ok(TriangleSolutions::new(solve(low)?, solve(high)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::analyze;
+    use crate::geometry::Point2D;
+    use num_rational::Ratio;
+
+    type Q = Ratio<i32>;
+
+    #[test]
+    fn test_solve_sss() {
+        let p = |x: i32, y: i32| Point2D::new(Q::from(x), Q::from(y));
+        for (a, b, c) in [
+            (p(0, 0), p(3, 1), p(1, 2)),
+            (p(0, 0), p(5, 0), p(1, 1)),
+            (p(-1, 0), p(1, 0), p(0, 2)),
+        ] {
+            let report = analyze(&a, &b, &c);
+            let (q_1, q_2, q_3) = report.quadrances;
+            let t = solve_sss(&q_1, &q_2, &q_3).unwrap();
+            assert_eq!(Some(t.spreads), report.spreads);
+            assert_eq!(t.quadrea, report.quadrea);
+            assert_eq!(t.class, report.class);
+        }
+        let flat = solve_sss(&Q::from(1), &Q::from(4), &Q::from(9)).unwrap();
+        assert_eq!(flat.class, TriangleClass::Degenerate);
+        assert_eq!(flat.spreads, (Q::from(0), Q::from(0), Q::from(0)));
+        assert_eq!(
+            solve_sss(&Q::from(1), &Q::from(1), &Q::from(9)),
+            Err(MathError::InvalidInput)
+        );
+        assert_eq!(
+            solve_sss(&Q::from(0), &Q::from(1), &Q::from(1)),
+            Err(MathError::DegenerateTriangle)
+        );
+        assert_eq!(
+            solve_sss(&Q::from(1), &Q::from(-1), &Q::from(1)),
+            Err(MathError::NegativeQuadrance)
+        );
+    }
+
+    #[test]
+    fn test_solve_sas() {
+        let sqrt = |d: Q| (0..1000).map(Q::from).find(|&x| x * x == d);
+        // the triangle (0, 0), (3, 1), (1, 2) and its obtuse twin
+        let solutions = solve_sas(&Q::from(5), &Q::from(10), &Q::new(1, 2), sqrt).unwrap();
+        assert_eq!(solutions.len(), 2);
+        let mut it = solutions.iter();
+        let (near, far) = (it.next().unwrap(), it.next().unwrap());
+        assert_eq!(near.quadrances, (Q::from(5), Q::from(10), Q::from(5)));
+        assert_eq!(near.class, TriangleClass::Right);
+        assert_eq!(far.quadrances.2, Q::from(25));
+        assert_eq!(far.class, TriangleClass::Obtuse);
+        for t in solutions.iter() {
+            assert_eq!(t.spreads.2, Q::new(1, 2));
+        }
+        // a right angle has a single solution
+        let right = solve_sas(&Q::from(9), &Q::from(16), &Q::from(1), sqrt).unwrap();
+        assert_eq!(right.len(), 1);
+        assert_eq!(right.iter().next().unwrap().quadrances.2, Q::from(25));
+        assert_eq!(
+            solve_sas(&Q::from(1), &Q::from(1), &Q::new(1, 2), sqrt),
+            Err(MathError::NotRepresentable)
+        );
+        assert_eq!(
+            solve_sas(&Q::from(1), &Q::from(1), &Q::from(2), sqrt),
+            Err(MathError::InvalidInput)
+        );
+        assert_eq!(
+            solve_sas(&Q::from(0), &Q::from(1), &Q::new(1, 2), sqrt),
+            Err(MathError::DegenerateTriangle)
+        );
+    }
+
+    #[test]
+    fn test_solve_asa() {
+        let sqrt = |d: Q| {
+            let (n, m) = (*d.numer(), *d.denom());
+            let root = |k: i32| (0..=k).find(|x| x * x == k);
+            Some(Q::new(root(n)?, root(m)?))
+        };
+        // the 3-4-5 triangle from its acute spreads at the hypotenuse,
+        // together with the triangle whose angles differ instead
+        let (s_1, s_2) = (Q::new(9, 25), Q::new(16, 25));
+        let solutions = solve_asa(&s_1, &s_2, &Q::from(25), sqrt).unwrap();
+        assert_eq!(solutions.len(), 2);
+        let mut it = solutions.iter();
+        let (diff, sum) = (it.next().unwrap(), it.next().unwrap());
+        assert_eq!(sum.quadrances, (Q::from(9), Q::from(16), Q::from(25)));
+        assert_eq!(sum.spreads, (s_1, s_2, Q::from(1)));
+        assert_eq!(diff.spreads.2, Q::new(49, 625));
+        assert_eq!(diff.class, TriangleClass::Obtuse);
+        // a right angle next to any spread
+        let solutions = solve_asa(&Q::from(1), &Q::new(1, 2), &Q::from(2), sqrt).unwrap();
+        assert_eq!(solutions.len(), 1);
+        assert_eq!(
+            solutions.iter().next().unwrap().quadrances,
+            (Q::from(4), Q::from(2), Q::from(2))
+        );
+        assert_eq!(
+            solve_asa(&Q::new(1, 3), &Q::new(1, 2), &Q::from(1), sqrt),
+            Err(MathError::NotRepresentable)
+        );
+        assert_eq!(
+            solve_asa(&Q::from(0), &Q::new(1, 2), &Q::from(1), sqrt),
+            Err(MathError::InvalidInput)
+        );
+        assert!(solve_asa(&Q::from(1), &Q::from(1), &Q::from(1), sqrt)
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,209 @@
+//! Spread polynomials, the rational analogue of multiple-angle formulas.
+//!
+//! If a line makes spread `s` with another, rotating it by the same angle
+//! `n` times gives the spread `Sₙ(s)`. The spread polynomials satisfy
+//!
+//! ```text
+//! S₀ = 0,  S₁ = s,  Sₙ₊₁ = 2·(1 − 2s)·Sₙ − Sₙ₋₁ + 2s
+//! ```
+//!
+//! and `Sₙ(sin² θ) = sin²(nθ)`. They compose, `Sₙ ∘ Sₘ = Sₙₘ`, and have
+//! integer coefficients, so everything here works over any commutative ring
+//! with `From<i32>`.
+use core::convert::From;
+use core::ops::{Add, Mul, Sub};
+
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// The values `S₀(s), S₁(s), S₂(s), ...`, see [`spread_polys`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpreadPolys<T> {
+    s: T,
+    prev: T,
+    curr: T,
+    started: bool,
+}
+
+/// The function `spread_polys` iterates over the values of the spread
+/// polynomials at `s`, starting from `S₀(s) = 0`.
+///
+/// The iterator never ends; take as many terms as needed.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::spread_poly::spread_polys;
+/// let r = Rational32::new;
+/// // the spread 3/4 of 60°: 120°, 180°, 240°, ... give 3/4, 0, 3/4, ...
+/// let values: Vec<_> = spread_polys(r(3, 4)).take(5).collect();
+/// assert_eq!(values, [r(0, 1), r(3, 4), r(3, 4), r(0, 1), r(3, 4)]);
+/// ```
+#[inline]
+pub fn spread_polys<T>(s: T) -> SpreadPolys<T>
+where
+    T: Copy + From<i32>,
+{
+    SpreadPolys {
+        s,
+        prev: s,
+        curr: T::from(0),
+        started: false,
+    }
+}
+
+impl<T> Iterator for SpreadPolys<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<i32>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        // Step the recurrence only when the next term is asked for, so that
+        // no term past the last one returned is ever computed (and so can't
+        // overflow a bounded `T`).
+        if self.started {
+            let two = T::from(2);
+            let next = two * (T::from(1) - two * self.s) * self.curr - self.prev + two * self.s;
+            self.prev = self.curr;
+            self.curr = next;
+        }
+        self.started = true;
+        Some(self.curr)
+    }
+}
+
+/// The function `spread_poly` evaluates the spread polynomial `Sₙ` at `s`
+/// by the recurrence, in `n` steps.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::spread_poly::spread_poly;
+/// let s = Rational32::new(1, 4);
+/// // S₃(s) = s·(3 − 4s)²
+/// assert_eq!(spread_poly(3, &s), s * (Rational32::from(3) - Rational32::from(4) * s).pow(2));
+/// ```
+#[inline]
+pub fn spread_poly<T>(n: usize, s: &T) -> T
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<i32>,
+{
+    spread_polys(*s).nth(n).unwrap_or_else(|| T::from(0))
+}
+
+/// The function `spread_poly_coeffs` calculates the coefficients of `Sₙ`,
+/// lowest degree first.
+///
+/// `Sₙ` has degree `n`, so the result has `n + 1` entries (just `[0]` for
+/// `n = 0`).
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::spread_poly::spread_poly_coeffs;
+/// assert_eq!(spread_poly_coeffs::<i64>(2), [0, 4, -4]);
+/// assert_eq!(spread_poly_coeffs::<i64>(3), [0, 9, -24, 16]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn spread_poly_coeffs<T>(n: usize) -> Vec<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<i32>,
+{
+    let (zero, two, four) = (T::from(0), T::from(2), T::from(4));
+    let mut prev = vec![zero];
+    let mut curr = vec![zero, T::from(1)];
+    if n == 0 {
+        return prev;
+    }
+    for _ in 1..n {
+        // 2·(1 − 2s)·Sₙ − Sₙ₋₁ + 2s
+        let mut next = vec![zero; curr.len() + 1];
+        for (k, &c) in curr.iter().enumerate() {
+            next[k] = next[k] + two * c;
+            next[k + 1] = next[k + 1] - four * c;
+        }
+        for (k, &c) in prev.iter().enumerate() {
+            next[k] = next[k] - c;
+        }
+        next[1] = next[1] + two;
+        prev = curr;
+        curr = next;
+    }
+    curr
+}
+
+/// The function `horner` evaluates a polynomial, given by its coefficients
+/// lowest degree first, by Horner's rule.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::spread_poly::horner;
+/// assert_eq!(horner(&[1, 2, 3], &2), 1 + 2 * 2 + 3 * 4);
+/// assert_eq!(horner::<i32>(&[], &5), 0);
+/// ```
+#[inline]
+pub fn horner<T>(coeffs: &[T], s: &T) -> T
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + From<i32>,
+{
+    coeffs.iter().rev().fold(T::from(0), |acc, &c| acc * *s + c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+
+    type Q = Ratio<i32>;
+
+    #[test]
+    fn test_spread_polys() {
+        // the spread 1/2 of 45° cycles through 1/2, 1, 1/2, 0
+        let s = Q::new(1, 2);
+        let values: Vec<_> = spread_polys(s).skip(1).take(4).collect();
+        assert_eq!(values, [s, Q::from(1), s, Q::from(0)]);
+        assert_eq!(spread_poly(0, &s), Q::from(0));
+        assert_eq!(spread_poly(1, &s), s);
+        // sin²(nθ) in floats
+        let theta: f64 = 0.3;
+        let s = theta.sin().powi(2);
+        for n in 0..8 {
+            let expected = (n as f64 * theta).sin().powi(2);
+            assert!((spread_poly(n, &s) - expected).abs() < 1e-12);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_coeffs_and_composition() {
+        let s = Q::new(1, 5);
+        for n in 0..8 {
+            let coeffs = spread_poly_coeffs::<Q>(n);
+            assert_eq!(coeffs.len(), n + 1);
+            assert_eq!(horner(&coeffs, &s), spread_poly(n, &s));
+        }
+        // Sₙ ∘ Sₘ = Sₙₘ, here over the integers
+        let s: i64 = 3;
+        for (n, m) in [(2, 3), (3, 2), (4, 2)] {
+            assert_eq!(spread_poly(n, &spread_poly(m, &s)), spread_poly(n * m, &s));
+        }
+        assert_eq!(spread_poly_coeffs::<i64>(4), [0, 16, -80, 128, -64]);
+    }
+
+    #[test]
+    fn test_spread_poly_no_lookahead_overflow() {
+        // S₁₂(2) fits in an i32 but S₁₃(2) doesn't; only the terms asked for
+        // may be computed
+        assert_eq!(spread_poly::<i32>(12, &2), -384_199_200);
+        let values: Vec<i32> = spread_polys(2).take(13).collect();
+        assert_eq!(values[..4], [0, 2, -8, 50]);
+        assert_eq!(values[12], -384_199_200);
+    }
+}
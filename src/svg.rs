@@ -0,0 +1,362 @@
+//! SVG rendering of geometric primitives for debugging.
+//!
+//! An [`SvgScene`] collects points, segments, lines, triangles, circles,
+//! polygons and text labels in world coordinates, then renders them into a
+//! standalone SVG document fitted to their bounding box, with the y-axis
+//! pointing up. Coordinates are converted to `f64` only for drawing; labels
+//! for quadrances and spreads are computed exactly in `T` and written with
+//! its `Display` implementation, so a rational spread shows up as `9/25`.
+//!
+//! ```rust
+//! use num_rational::Rational32;
+//! use rat_trig_rs::geometry::{Point2D, Triangle2D};
+//! use rat_trig_rs::svg::SvgScene;
+//! let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+//! let mut scene = SvgScene::new();
+//! scene
+//!     .triangle_with_measures(&Triangle2D::new(p(0, 0), p(4, 0), p(0, 3)))
+//!     .line(&(Rational32::from(1), Rational32::from(-1), Rational32::from(0)));
+//! let svg = scene.to_svg_string();
+//! assert!(svg.starts_with("<svg"));
+//! assert!(svg.contains("s = 9/25"));
+//! ```
+use crate::geometry::{Point2D, Triangle2D};
+use crate::logging::Loggable;
+use crate::traits::{MetricOps, RationalTrig};
+use core::convert::From;
+use core::fmt::{Display, Write};
+use core::ops::{Add, Div, Mul, Sub};
+use num_traits::ToPrimitive;
+use std::path::Path;
+
+/// The size in pixels of the longer side of the drawing area.
+const CANVAS: f64 = 640.0;
+/// The margin in pixels around the drawing area.
+const MARGIN: f64 = 24.0;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Element {
+    Point(f64, f64),
+    Segment(f64, f64, f64, f64),
+    /// The line `a·x + b·y + c = 0`, clipped to the scene when rendered.
+    Line(f64, f64, f64),
+    Circle(f64, f64, f64),
+    Polygon(Vec<(f64, f64)>),
+    Label(f64, f64, String),
+}
+
+/// A collection of primitives to be rendered as SVG.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SvgScene {
+    elements: Vec<Element>,
+}
+
+/// Converts a coordinate for drawing; unrepresentable values are drawn at 0.
+#[inline]
+fn to_f64<T: ToPrimitive>(value: &T) -> f64 {
+    value.to_f64().unwrap_or(0.0)
+}
+
+#[inline]
+fn point_f64<T: ToPrimitive>(p: &Point2D<T>) -> (f64, f64) {
+    (to_f64(&p.x), to_f64(&p.y))
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+impl SvgScene {
+    /// Creates an empty scene.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Checks whether nothing has been added yet.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Adds a point, drawn as a dot.
+    pub fn point<T: ToPrimitive>(&mut self, p: &Point2D<T>) -> &mut Self {
+        let (x, y) = point_f64(p);
+        self.elements.push(Element::Point(x, y));
+        self
+    }
+
+    /// Adds a text label anchored at `p`.
+    pub fn label<T: ToPrimitive>(&mut self, p: &Point2D<T>, text: &str) -> &mut Self {
+        let (x, y) = point_f64(p);
+        self.elements.push(Element::Label(x, y, text.into()));
+        self
+    }
+
+    /// Adds the segment from `a` to `b`.
+    pub fn segment<T: ToPrimitive>(&mut self, a: &Point2D<T>, b: &Point2D<T>) -> &mut Self {
+        let ((x_1, y_1), (x_2, y_2)) = (point_f64(a), point_f64(b));
+        self.elements.push(Element::Segment(x_1, y_1, x_2, y_2));
+        self
+    }
+
+    /// Adds the segment from `a` to `b`, labelled with its quadrance at the
+    /// midpoint.
+    pub fn segment_with_quadrance<T>(&mut self, a: &Point2D<T>, b: &Point2D<T>) -> &mut Self
+    where
+        T: Copy
+            + ToPrimitive
+            + Display
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + PartialEq
+            + From<i32>,
+    {
+        let ((x_1, y_1), (x_2, y_2)) = (point_f64(a), point_f64(b));
+        self.segment(a, b);
+        self.elements.push(Element::Label(
+            (x_1 + x_2) / 2.0,
+            (y_1 + y_2) / 2.0,
+            format!("Q = {}", a.quadrance_to(b)),
+        ));
+        self
+    }
+
+    /// Adds the line `a·x + b·y + c = 0` given as `(a, b, c)`. Lines are
+    /// clipped to the bounding box of the other elements; a line with
+    /// `a = b = 0` is ignored.
+    pub fn line<T: ToPrimitive>(&mut self, line: &(T, T, T)) -> &mut Self {
+        let (a, b, c) = (to_f64(&line.0), to_f64(&line.1), to_f64(&line.2));
+        if a != 0.0 || b != 0.0 {
+            self.elements.push(Element::Line(a, b, c));
+        }
+        self
+    }
+
+    /// Adds the outline of a triangle.
+    pub fn triangle<T: ToPrimitive>(&mut self, tri: &Triangle2D<T>) -> &mut Self {
+        let vertices = vec![point_f64(&tri.p1), point_f64(&tri.p2), point_f64(&tri.p3)];
+        self.elements.push(Element::Polygon(vertices));
+        self
+    }
+
+    /// Adds the outline of a triangle, labelled with the quadrance of each
+    /// side and the spread at each vertex. Spreads at a vertex with a
+    /// coincident neighbour are left out.
+    pub fn triangle_with_measures<T>(&mut self, tri: &Triangle2D<T>) -> &mut Self
+    where
+        T: Copy
+            + ToPrimitive
+            + Display
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + PartialEq
+            + From<i32>
+            + Loggable,
+    {
+        self.triangle(tri);
+        let vertices = [tri.p1, tri.p2, tri.p3];
+        for i in 0..3 {
+            let (a, b, c) = (&vertices[i], &vertices[(i + 1) % 3], &vertices[(i + 2) % 3]);
+            let ((x_b, y_b), (x_c, y_c)) = (point_f64(b), point_f64(c));
+            self.elements.push(Element::Label(
+                (x_b + x_c) / 2.0,
+                (y_b + y_c) / 2.0,
+                format!("Q = {}", b.quadrance_to(c)),
+            ));
+            if let Ok(s) = a.spread_at(b, c) {
+                self.label(a, &format!("s = {}", s));
+            }
+        }
+        self
+    }
+
+    /// Adds the circle with the given center and quadrance (squared radius).
+    pub fn circle<T: ToPrimitive>(&mut self, center: &Point2D<T>, quadrance: &T) -> &mut Self {
+        let (x, y) = point_f64(center);
+        self.elements
+            .push(Element::Circle(x, y, to_f64(quadrance).max(0.0).sqrt()));
+        self
+    }
+
+    /// Adds a closed polygon through the given vertices.
+    pub fn polygon<T: ToPrimitive>(&mut self, vertices: &[Point2D<T>]) -> &mut Self {
+        self.elements
+            .push(Element::Polygon(vertices.iter().map(point_f64).collect()));
+        self
+    }
+
+    /// The bounding box `(min_x, min_y, max_x, max_y)` of all elements
+    /// except lines, or the square `[-1, 1]²` if there are none.
+    fn bounds(&self) -> (f64, f64, f64, f64) {
+        let mut bounds: Option<(f64, f64, f64, f64)> = None;
+        let mut include = |x: f64, y: f64| {
+            bounds = Some(match bounds {
+                None => (x, y, x, y),
+                Some((x_0, y_0, x_1, y_1)) => (x_0.min(x), y_0.min(y), x_1.max(x), y_1.max(y)),
+            });
+        };
+        for element in &self.elements {
+            match element {
+                Element::Point(x, y) | Element::Label(x, y, _) => include(*x, *y),
+                Element::Segment(x_1, y_1, x_2, y_2) => {
+                    include(*x_1, *y_1);
+                    include(*x_2, *y_2);
+                }
+                Element::Circle(x, y, r) => {
+                    include(x - r, y - r);
+                    include(x + r, y + r);
+                }
+                Element::Polygon(vertices) => vertices.iter().for_each(|&(x, y)| include(x, y)),
+                Element::Line(..) => {}
+            }
+        }
+        let (x_0, y_0, x_1, y_1) = bounds.unwrap_or((-1.0, -1.0, 1.0, 1.0));
+        // pad degenerate boxes so that the scale stays finite
+        let pad_x = if x_1 > x_0 { 0.0 } else { 1.0 };
+        let pad_y = if y_1 > y_0 { 0.0 } else { 1.0 };
+        (x_0 - pad_x, y_0 - pad_y, x_1 + pad_x, y_1 + pad_y)
+    }
+
+    /// Renders the scene as a standalone SVG document.
+    pub fn to_svg_string(&self) -> String {
+        let (x_0, y_0, x_1, y_1) = self.bounds();
+        let scale = CANVAS / (x_1 - x_0).max(y_1 - y_0);
+        let width = (x_1 - x_0) * scale + 2.0 * MARGIN;
+        let height = (y_1 - y_0) * scale + 2.0 * MARGIN;
+        let sx = |x: f64| (x - x_0) * scale + MARGIN;
+        let sy = |y: f64| (y_1 - y) * scale + MARGIN;
+
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{:.0}" height="{:.0}" viewBox="0 0 {:.2} {:.2}">"#,
+            width, height, width, height
+        );
+        let _ = writeln!(
+            out,
+            r#"<g fill="none" stroke="black" stroke-width="1.5" font-family="monospace" font-size="12">"#
+        );
+        for element in &self.elements {
+            let _ = match element {
+                Element::Point(x, y) => writeln!(
+                    out,
+                    r#"<circle cx="{:.2}" cy="{:.2}" r="3" fill="black"/>"#,
+                    sx(*x),
+                    sy(*y)
+                ),
+                Element::Segment(x_1, y_1, x_2, y_2) => writeln!(
+                    out,
+                    r#"<line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}"/>"#,
+                    sx(*x_1),
+                    sy(*y_1),
+                    sx(*x_2),
+                    sy(*y_2)
+                ),
+                Element::Line(a, b, c) => {
+                    let (p, q) = if b.abs() >= a.abs() {
+                        let y = |x: f64| -(a * x + c) / b;
+                        ((x_0, y(x_0)), (x_1, y(x_1)))
+                    } else {
+                        let x = |y: f64| -(b * y + c) / a;
+                        ((x(y_0), y_0), (x(y_1), y_1))
+                    };
+                    writeln!(
+                        out,
+                        r#"<line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke="gray" stroke-dasharray="6 4"/>"#,
+                        sx(p.0),
+                        sy(p.1),
+                        sx(q.0),
+                        sy(q.1)
+                    )
+                }
+                Element::Circle(x, y, r) => writeln!(
+                    out,
+                    r#"<circle cx="{:.2}" cy="{:.2}" r="{:.2}"/>"#,
+                    sx(*x),
+                    sy(*y),
+                    r * scale
+                ),
+                Element::Polygon(vertices) => {
+                    let points: Vec<String> = vertices
+                        .iter()
+                        .map(|&(x, y)| format!("{:.2},{:.2}", sx(x), sy(y)))
+                        .collect();
+                    writeln!(out, r#"<polygon points="{}"/>"#, points.join(" "))
+                }
+                Element::Label(x, y, text) => writeln!(
+                    out,
+                    r#"<text x="{:.2}" y="{:.2}" fill="blue" stroke="none">{}</text>"#,
+                    sx(*x) + 4.0,
+                    sy(*y) - 4.0,
+                    escape(text)
+                ),
+            };
+        }
+        out.push_str("</g>\n</svg>\n");
+        out
+    }
+
+    /// Writes the rendered scene to a file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.to_svg_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+
+    type Q = Ratio<i32>;
+
+    #[test]
+    fn test_render_elements() {
+        let mut scene = SvgScene::new();
+        assert!(scene.is_empty());
+        scene
+            .point(&Point2D::new(0, 0))
+            .segment_with_quadrance(&Point2D::new(0, 0), &Point2D::new(3, 4))
+            .circle(&Point2D::new(0, 0), &25)
+            .polygon(&[Point2D::new(0, 0), Point2D::new(1, 0), Point2D::new(0, 1)])
+            .line(&(1, 1, -2))
+            .line(&(0, 0, 1))
+            .label(&Point2D::new(1, 1), "a < b & c");
+        let svg = scene.to_svg_string();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<circle").count(), 2);
+        assert_eq!(svg.matches("<line").count(), 2); // the null line is dropped
+        assert!(svg.contains(">Q = 25</text>"));
+        assert!(svg.contains(">a &lt; b &amp; c</text>"));
+        // the bounding box is the circle, [-5, 5]², so the origin is centred
+        assert!(svg.contains(r#"<circle cx="344.00" cy="344.00" r="3""#));
+    }
+
+    #[test]
+    fn test_triangle_measures() {
+        let p = |x: i32, y: i32| Point2D::new(Q::from(x), Q::from(y));
+        let mut scene = SvgScene::new();
+        scene.triangle_with_measures(&Triangle2D::new(p(0, 0), p(4, 0), p(0, 3)));
+        let svg = scene.to_svg_string();
+        for text in [
+            "Q = 25",
+            "Q = 9",
+            "Q = 16",
+            "s = 1",
+            "s = 9/25",
+            "s = 16/25",
+        ] {
+            assert!(svg.contains(&format!(">{}</text>", text)), "{}", text);
+        }
+        // a degenerate triangle still renders, without the undefined spreads
+        let mut scene = SvgScene::new();
+        scene.triangle_with_measures(&Triangle2D::new(p(0, 0), p(0, 0), p(1, 1)));
+        assert_eq!(scene.to_svg_string().matches("s = ").count(), 1);
+    }
+}
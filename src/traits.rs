@@ -0,0 +1,360 @@
+//! Affine and metrical operations as separate layers.
+//!
+//! Rational trigonometry distinguishes between what can be said about points
+//! using only the affine structure of the plane (collinearity, ratios along a
+//! line, barycentric coordinates, the signed area or *twist*) and what
+//! additionally needs a quadratic form (quadrance, spread).
+//!
+//! [`AffineOps`] collects the former and only needs ring operations on the
+//! coordinates. [`MetricOps`] builds on it and takes the quadratic form as an
+//! explicit [`BilinearForm2D`], so the same code runs unchanged in the blue,
+//! red or green geometry, or any other metric.
+//!
+//! [`RationalTrig`] is a shorthand layer for the common blue measurements,
+//! implemented for `(x, y)` tuples, [`Point2D`] and [`Vector2D`], so they
+//! read as method calls instead of free functions.
+use crate::error::MathError;
+use crate::geometry::{Line2D, Point2D, Vector2D};
+use crate::logging::{degenerate, targets, Loggable};
+use crate::metric::BilinearForm2D;
+use crate::trigonom::quadrance;
+use core::convert::From;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// Operations that only use the affine structure of the plane.
+pub trait AffineOps<T>: Sized
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    /// The twist of the triangle `self, b, c`: twice its signed area,
+    /// positive when the vertices are in counterclockwise order.
+    fn twist(&self, b: &Self, c: &Self) -> T;
+
+    /// The point `self + t·(other − self)`.
+    fn affine_combination(&self, other: &Self, t: T) -> Self;
+
+    /// Checks whether `self`, `b` and `c` lie on a common line.
+    #[inline]
+    fn is_collinear(&self, b: &Self, c: &Self) -> bool {
+        self.twist(b, c) == T::from(0)
+    }
+
+    /// The affine ratio `r` with `c = self + r·(b − self)` for collinear
+    /// points.
+    ///
+    /// Returns `MathError::DivisionByZero` if `self == b` and
+    /// `MathError::InvalidInput` if `c` is not on the line through `self`
+    /// and `b`.
+    fn affine_ratio(&self, b: &Self, c: &Self) -> Result<T, MathError>
+    where
+        T: Div<Output = T> + Loggable;
+
+    /// The barycentric coordinates `(λ₁, λ₂, λ₃)` of `self` with respect to
+    /// the triangle `a, b, c`, so that `self = λ₁·a + λ₂·b + λ₃·c` and
+    /// `λ₁ + λ₂ + λ₃ = 1`.
+    ///
+    /// Returns `MathError::DegenerateTriangle` if the triangle is degenerate.
+    fn barycentric(&self, a: &Self, b: &Self, c: &Self) -> Result<(T, T, T), MathError>
+    where
+        T: Div<Output = T>,
+        Self: Loggable,
+    {
+        let total = a.twist(b, c);
+        if total == T::from(0) {
+            return Err(degenerate(
+                targets::TRAITS,
+                "barycentric",
+                MathError::DegenerateTriangle,
+                &(self, a, b, c),
+            ));
+        }
+        Ok((
+            self.twist(b, c) / total,
+            a.twist(self, c) / total,
+            a.twist(b, self) / total,
+        ))
+    }
+}
+
+/// Operations that additionally need a quadratic form.
+///
+/// The `*_in` methods take the form explicitly; `spread_at` uses the blue
+/// (Euclidean) form. The blue quadrance is [`RationalTrig::quadrance_to`].
+pub trait MetricOps<T>: AffineOps<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    /// The quadrance between `self` and `other` in the given form.
+    fn quadrance_in(&self, other: &Self, form: &BilinearForm2D<T>) -> T;
+
+    /// The spread at vertex `self` between the rays towards `b` and `c` in
+    /// the given form.
+    ///
+    /// Returns `MathError::NullVector` if either ray is null.
+    fn spread_in(&self, b: &Self, c: &Self, form: &BilinearForm2D<T>) -> Result<T, MathError>
+    where
+        T: Div<Output = T> + Loggable;
+
+    /// The (blue) spread at vertex `self` between the rays towards `b` and
+    /// `c`.
+    #[inline]
+    fn spread_at(&self, b: &Self, c: &Self) -> Result<T, MathError>
+    where
+        T: Div<Output = T> + Loggable,
+    {
+        self.spread_in(b, c, &BilinearForm2D::blue())
+    }
+}
+
+impl<T> AffineOps<T> for Point2D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    #[inline]
+    fn twist(&self, b: &Self, c: &Self) -> T {
+        (b.x - self.x) * (c.y - self.y) - (b.y - self.y) * (c.x - self.x)
+    }
+
+    #[inline]
+    fn affine_combination(&self, other: &Self, t: T) -> Self {
+        Point2D::new(
+            self.x + t * (other.x - self.x),
+            self.y + t * (other.y - self.y),
+        )
+    }
+
+    fn affine_ratio(&self, b: &Self, c: &Self) -> Result<T, MathError>
+    where
+        T: Div<Output = T> + Loggable,
+    {
+        if !self.is_collinear(b, c) {
+            return Err(degenerate(
+                targets::TRAITS,
+                "affine_ratio",
+                MathError::InvalidInput,
+                &(self, b, c),
+            ));
+        }
+        if b.x != self.x {
+            Ok((c.x - self.x) / (b.x - self.x))
+        } else if b.y != self.y {
+            Ok((c.y - self.y) / (b.y - self.y))
+        } else {
+            Err(degenerate(
+                targets::TRAITS,
+                "affine_ratio",
+                MathError::DivisionByZero,
+                &(self, b, c),
+            ))
+        }
+    }
+}
+
+impl<T> MetricOps<T> for Point2D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    #[inline]
+    fn quadrance_in(&self, other: &Self, form: &BilinearForm2D<T>) -> T {
+        form.quadrance(&(other.x - self.x, other.y - self.y))
+    }
+
+    fn spread_in(&self, b: &Self, c: &Self, form: &BilinearForm2D<T>) -> Result<T, MathError>
+    where
+        T: Div<Output = T> + Loggable,
+    {
+        let u = (b.x - self.x, b.y - self.y);
+        let v = (c.x - self.x, c.y - self.y);
+        form.spread(&u, &v)
+    }
+}
+
+/// Blue measurements as methods on plane coordinates.
+///
+/// Implemented for `(x, y)` tuples, [`Point2D`] and [`Vector2D`]. Points
+/// and vectors are both read as coordinates, so `spread_with` on points is
+/// the spread between their position vectors.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::geometry::{Line2D, Point2D, Vector2D};
+/// use rat_trig_rs::traits::RationalTrig;
+/// let r = |n: i32| Rational32::from(n);
+/// assert_eq!((r(1), r(1)).quadrance_to(&(r(4), r(5))), r(25));
+/// let (u, v) = (Vector2D::new(r(1), r(0)), Vector2D::new(r(1), r(1)));
+/// assert_eq!(u.spread_with(&v), Ok(Rational32::new(1, 2)));
+/// let line = Line2D::new(r(3), r(4), r(0));
+/// assert_eq!(Point2D::new(r(4), r(-3)).quadrance_to_line(&line), Ok(r(0)));
+/// assert_eq!(Point2D::new(r(3), r(4)).quadrance_to_line(&line), Ok(r(25)));
+/// ```
+pub trait RationalTrig<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    /// The quadrance between `self` and `other`.
+    fn quadrance_to(&self, other: &Self) -> T;
+
+    /// The spread between `self` and `other` as vectors.
+    ///
+    /// Returns `MathError::NullVector` if either is zero.
+    fn spread_with(&self, other: &Self) -> Result<T, MathError>
+    where
+        T: Div<Output = T> + Loggable;
+
+    /// The quadrance from `self` to its foot on the line
+    /// `ax + by + c = 0`, `(a·x + b·y + c)² / (a² + b²)`.
+    ///
+    /// Returns `MathError::NullLine` if `a² + b² = 0`.
+    fn quadrance_to_line(&self, line: &Line2D<T>) -> Result<T, MathError>
+    where
+        T: Div<Output = T> + Loggable;
+}
+
+impl<T> RationalTrig<T> for (T, T)
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    #[inline]
+    fn quadrance_to(&self, other: &Self) -> T {
+        quadrance(&(other.0 - self.0, other.1 - self.1))
+    }
+
+    #[inline]
+    fn spread_with(&self, other: &Self) -> Result<T, MathError>
+    where
+        T: Div<Output = T> + Loggable,
+    {
+        BilinearForm2D::blue().spread(self, other)
+    }
+
+    fn quadrance_to_line(&self, line: &Line2D<T>) -> Result<T, MathError>
+    where
+        T: Div<Output = T> + Loggable,
+    {
+        let norm = quadrance(&(line.a, line.b));
+        if norm == T::from(0) {
+            return Err(degenerate(
+                targets::TRAITS,
+                "quadrance_to_line",
+                MathError::NullLine,
+                line,
+            ));
+        }
+        let value = line.a * self.0 + line.b * self.1 + line.c;
+        Ok(value * value / norm)
+    }
+}
+
+macro_rules! impl_rational_trig {
+    ($($ty:ident),*) => {
+        $(
+            impl<T> RationalTrig<T> for $ty<T>
+            where
+                T: Copy
+                    + Add<Output = T>
+                    + Sub<Output = T>
+                    + Mul<Output = T>
+                    + PartialEq
+                    + From<i32>,
+            {
+                #[inline]
+                fn quadrance_to(&self, other: &Self) -> T {
+                    (self.x, self.y).quadrance_to(&(other.x, other.y))
+                }
+
+                #[inline]
+                fn spread_with(&self, other: &Self) -> Result<T, MathError>
+                where
+                    T: Div<Output = T> + Loggable,
+                {
+                    (self.x, self.y).spread_with(&(other.x, other.y))
+                }
+
+                #[inline]
+                fn quadrance_to_line(&self, line: &Line2D<T>) -> Result<T, MathError>
+                where
+                    T: Div<Output = T> + Loggable,
+                {
+                    (self.x, self.y).quadrance_to_line(line)
+                }
+            }
+        )*
+    };
+}
+
+impl_rational_trig!(Point2D, Vector2D);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+
+    type Q = Ratio<i32>;
+
+    fn p(x: i32, y: i32) -> Point2D<Q> {
+        Point2D::new(Q::from(x), Q::from(y))
+    }
+
+    #[test]
+    fn test_affine_ops_integers() {
+        // only ring operations are needed for the affine layer
+        let (a, b, c) = (Point2D::new(0, 0), Point2D::new(4, 0), Point2D::new(0, 3));
+        assert_eq!(a.twist(&b, &c), 12);
+        assert_eq!(a.twist(&c, &b), -12);
+        assert!(a.is_collinear(&b, &Point2D::new(8, 0)));
+        assert_eq!(a.affine_combination(&b, 2), Point2D::new(8, 0));
+    }
+
+    #[test]
+    fn test_affine_ratio_barycentric() {
+        let (a, b) = (p(1, 1), p(3, 5));
+        assert_eq!(a.affine_ratio(&b, &p(2, 3)), Ok(Q::new(1, 2)));
+        assert_eq!(a.affine_ratio(&b, &p(2, 4)), Err(MathError::InvalidInput));
+        assert_eq!(a.affine_ratio(&a, &a), Err(MathError::DivisionByZero));
+
+        let (a, b, c) = (p(0, 0), p(4, 0), p(0, 4));
+        let (l1, l2, l3) = p(1, 2).barycentric(&a, &b, &c).unwrap();
+        assert_eq!((l1, l2, l3), (Q::new(1, 4), Q::new(1, 4), Q::new(1, 2)));
+        assert_eq!(
+            p(1, 2).barycentric(&a, &b, &p(8, 0)),
+            Err(MathError::DegenerateTriangle)
+        );
+    }
+
+    #[test]
+    fn test_metric_ops() {
+        let (a, b, c) = (p(0, 0), p(3, 0), p(3, 3));
+        assert_eq!(a.quadrance_to(&c), Q::from(18));
+        assert_eq!(a.spread_at(&b, &c), Ok(Q::new(1, 2)));
+        let red = BilinearForm2D::red();
+        assert_eq!(a.quadrance_in(&c, &red), Q::from(0));
+        assert_eq!(a.spread_in(&b, &c, &red), Err(MathError::NullVector));
+    }
+
+    #[test]
+    fn test_rational_trig() {
+        assert_eq!((1, 2).quadrance_to(&(4, 6)), 25);
+        assert_eq!(Point2D::new(1, 2).quadrance_to(&Point2D::new(4, 6)), 25);
+        let (u, v) = (
+            Vector2D::new(Q::from(3), Q::from(4)),
+            Vector2D::new(Q::from(4), Q::from(-3)),
+        );
+        assert_eq!(u.spread_with(&v), Ok(Q::from(1)));
+        assert_eq!(
+            u.spread_with(&Vector2D::default()),
+            Err(MathError::NullVector)
+        );
+        assert_eq!(p(2, 0).spread_with(&p(1, 1)), Ok(Q::new(1, 2)));
+
+        let line = Line2D::new(Q::from(1), Q::from(1), Q::from(-2));
+        assert_eq!(p(0, 0).quadrance_to_line(&line), Ok(Q::from(2)));
+        assert_eq!(
+            (Q::from(1), Q::from(1)).quadrance_to_line(&line),
+            Ok(Q::from(0))
+        );
+        let null = Line2D::new(Q::from(0), Q::from(0), Q::from(1));
+        assert_eq!(p(0, 0).quadrance_to_line(&null), Err(MathError::NullLine));
+    }
+}
@@ -0,0 +1,1908 @@
+//! Exact transformations of the plane and of space.
+//!
+//! Transformations are stored as matrices over the coefficient type, so
+//! composing and inverting them over the rationals never loses precision.
+//! This also means questions like "does this map preserve quadrance?" can be
+//! answered symbolically instead of by sampling with a tolerance.
+use crate::error::MathError;
+use crate::geometry::{Line2D, Point2D, Point3D, Triangle2D, Vector2D, Vector3D};
+use crate::linalg::{adjugate3, adjugate4, det2, inverse2, mat_mul, mat_vec_mul, transpose, Mat2};
+use crate::logging::{degenerate, targets, Loggable};
+use crate::segment::Segment2D;
+use crate::trigonom::{quadrance, quadrance3d};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
+use core::convert::From;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// An affine map of the plane, `p ↦ M·p + t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Affine2D<T> {
+    /// The linear part `M`, stored row-major.
+    pub matrix: [[T; 2]; 2],
+    /// The translation part `t`.
+    pub translation: Vector2D<T>,
+}
+
+impl<T> Affine2D<T> {
+    /// Creates an affine map from its linear part and translation.
+    #[inline]
+    pub const fn new(matrix: [[T; 2]; 2], translation: Vector2D<T>) -> Self {
+        Affine2D {
+            matrix,
+            translation,
+        }
+    }
+}
+
+impl<T> Affine2D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    /// The identity map.
+    #[inline]
+    pub fn identity() -> Self {
+        let (zero, one) = (T::from(0), T::from(1));
+        Affine2D::new([[one, zero], [zero, one]], Vector2D::new(zero, zero))
+    }
+
+    /// The translation `p ↦ p + v`.
+    #[inline]
+    pub fn translation(v: Vector2D<T>) -> Self {
+        Affine2D::new(Affine2D::identity().matrix, v)
+    }
+
+    /// The scaling `(x, y) ↦ (s_x·x, s_y·y)` about the origin.
+    #[inline]
+    pub fn scaling(s_x: T, s_y: T) -> Self {
+        let zero = T::from(0);
+        Affine2D::new([[s_x, zero], [zero, s_y]], Vector2D::new(zero, zero))
+    }
+
+    /// The shear `(x, y) ↦ (x + k_x·y, y + k_y·x)`; take one factor zero
+    /// for a shear along an axis.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::Point2D;
+    /// use rat_trig_rs::transform::Affine2D;
+    /// let shear = Affine2D::shear(2, 0);
+    /// assert_eq!(shear.apply(&Point2D::new(1, 3)), Point2D::new(7, 3));
+    /// assert_eq!(shear.determinant(), 1);
+    /// ```
+    #[inline]
+    pub fn shear(k_x: T, k_y: T) -> Self {
+        let (zero, one) = (T::from(0), T::from(1));
+        Affine2D::new([[one, k_x], [k_y, one]], Vector2D::new(zero, zero))
+    }
+
+    /// The horizontal shear `(x, y) ↦ (x + k·y, y)`, fixing the x-axis.
+    #[inline]
+    pub fn shear_x(k: T) -> Self {
+        Affine2D::shear(k, T::from(0))
+    }
+
+    /// The vertical shear `(x, y) ↦ (x, y + k·x)`, fixing the y-axis.
+    #[inline]
+    pub fn shear_y(k: T) -> Self {
+        Affine2D::shear(T::from(0), k)
+    }
+
+    /// Applies the linear part only, as is appropriate for displacement
+    /// vectors.
+    #[inline]
+    pub fn apply_vector(&self, v: &Vector2D<T>) -> Vector2D<T> {
+        let m = &self.matrix;
+        Vector2D::new(m[0][0] * v.x + m[0][1] * v.y, m[1][0] * v.x + m[1][1] * v.y)
+    }
+
+    /// Applies the map to a point.
+    #[inline]
+    pub fn apply(&self, p: &Point2D<T>) -> Point2D<T> {
+        let v = self.apply_vector(&Vector2D::new(p.x, p.y));
+        Point2D::new(v.x + self.translation.x, v.y + self.translation.y)
+    }
+
+    /// Returns the map `self ∘ other`, i.e. `other` is applied first.
+    pub fn compose(&self, other: &Self) -> Self {
+        let (a, b) = (&self.matrix, &other.matrix);
+        let entry = |i: usize, j: usize| a[i][0] * b[0][j] + a[i][1] * b[1][j];
+        let matrix = [[entry(0, 0), entry(0, 1)], [entry(1, 0), entry(1, 1)]];
+        Affine2D::new(
+            matrix,
+            self.apply_vector(&other.translation) + self.translation,
+        )
+    }
+
+    /// The determinant of the linear part. Its sign tells whether the map
+    /// preserves orientation; its square is the factor by which quadrea is
+    /// scaled.
+    #[inline]
+    pub fn determinant(&self) -> T {
+        det2(&self.matrix)
+    }
+
+    /// Checks whether the map is the identity.
+    #[inline]
+    pub fn is_identity(&self) -> bool {
+        *self == Affine2D::identity()
+    }
+
+    /// Checks whether the map preserves (blue) quadrance exactly, i.e. whether
+    /// the linear part satisfies `Mᵀ·M = I`.
+    pub fn is_isometry(&self) -> bool {
+        let m = &self.matrix;
+        let (zero, one) = (T::from(0), T::from(1));
+        m[0][0] * m[0][0] + m[1][0] * m[1][0] == one
+            && m[0][1] * m[0][1] + m[1][1] * m[1][1] == one
+            && m[0][0] * m[0][1] + m[1][0] * m[1][1] == zero
+    }
+
+    /// The factor `k` by which the map scales every quadrance, if it is a
+    /// similarity: `Mᵀ·M = k·I` with `k ≠ 0`. Similarities preserve spreads,
+    /// and isometries are the case `k = 1`.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::Vector2D;
+    /// use rat_trig_rs::transform::Affine2D;
+    /// // rotate by the 3-4-5 triangle and scale by 5
+    /// let f = Affine2D::new([[3, -4], [4, 3]], Vector2D::new(1, 0));
+    /// assert_eq!(f.quadrance_scale(), Some(25));
+    /// assert_eq!(Affine2D::scaling(1, 2).quadrance_scale(), None);
+    /// ```
+    pub fn quadrance_scale(&self) -> Option<T> {
+        let m = &self.matrix;
+        let zero = T::from(0);
+        let k = m[0][0] * m[0][0] + m[1][0] * m[1][0];
+        let similar = k != zero
+            && m[0][1] * m[0][1] + m[1][1] * m[1][1] == k
+            && m[0][0] * m[0][1] + m[1][0] * m[1][1] == zero;
+        similar.then_some(k)
+    }
+
+    /// Checks whether the map is a similarity, scaling all quadrances by
+    /// the same nonzero factor and hence preserving all spreads; see
+    /// [`quadrance_scale`](Affine2D::quadrance_scale).
+    #[inline]
+    pub fn is_similarity(&self) -> bool {
+        self.quadrance_scale().is_some()
+    }
+}
+
+impl<T> Affine2D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd + From<i32>,
+{
+    /// Checks whether the map preserves orientation (positive determinant).
+    #[inline]
+    pub fn is_orientation_preserving(&self) -> bool {
+        self.determinant() > T::from(0)
+    }
+}
+
+impl<T> Affine2D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// The rotation about the origin with half-turn parameter `t`; see
+    /// [`Rotation2D::from_parameter`].
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::Point2D;
+    /// use rat_trig_rs::transform::Affine2D;
+    /// let r = Rational32::from;
+    /// let quarter_turn = Affine2D::rotation(r(1));
+    /// assert_eq!(quarter_turn.apply(&Point2D::new(r(2), r(1))), Point2D::new(r(-1), r(2)));
+    /// ```
+    #[inline]
+    pub fn rotation(t: T) -> Self {
+        Rotation2D::from_parameter(t).to_affine()
+    }
+
+    /// The unique affine map sending the vertices of `src` to those of
+    /// `dst`, vertex by vertex, found by solving the linear system on the
+    /// edge vectors from `p1` exactly.
+    ///
+    /// Returns `MathError::DegenerateTriangle` if `src` is degenerate. A
+    /// degenerate `dst` is fine and gives a singular map.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::{Point2D, Triangle2D};
+    /// use rat_trig_rs::transform::Affine2D;
+    /// let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+    /// let src = Triangle2D::new(p(0, 0), p(1, 0), p(0, 1));
+    /// let dst = Triangle2D::new(p(2, 1), p(4, 1), p(3, 4));
+    /// let f = Affine2D::from_triangles(&src, &dst).unwrap();
+    /// assert_eq!(f.apply(&p(1, 1)), p(5, 4));
+    /// ```
+    pub fn from_triangles(src: &Triangle2D<T>, dst: &Triangle2D<T>) -> Result<Self, MathError>
+    where
+        T: Loggable,
+    {
+        let columns = |tri: &Triangle2D<T>| {
+            let (u, v) = (tri.p2 - tri.p1, tri.p3 - tri.p1);
+            Mat2::new([[u.x, v.x], [u.y, v.y]])
+        };
+        let inv = columns(src).try_inverse().map_err(|_| {
+            degenerate(
+                targets::TRANSFORM,
+                "Affine2D::from_triangles",
+                MathError::DegenerateTriangle,
+                src,
+            )
+        })?;
+        let matrix = (columns(dst) * inv).rows;
+        let mut result = Affine2D::new(matrix, Vector2D::new(T::from(0), T::from(0)));
+        let moved = result.apply(&src.p1);
+        result.translation = dst.p1 - moved;
+        Ok(result)
+    }
+
+    /// Computes the exact inverse map.
+    ///
+    /// Returns `MathError::DivisionByZero` if the linear part is singular.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::{Point2D, Vector2D};
+    /// use rat_trig_rs::transform::Affine2D;
+    /// let r = |x: i32| Rational32::from(x);
+    /// let f = Affine2D::new([[r(2), r(1)], [r(1), r(1)]], Vector2D::new(r(3), r(-1)));
+    /// let p = Point2D::new(r(5), r(7));
+    /// assert_eq!(f.try_inverse().unwrap().apply(&f.apply(&p)), p);
+    /// ```
+    pub fn try_inverse(&self) -> Result<Self, MathError>
+    where
+        T: Loggable,
+    {
+        let inv = inverse2(&self.matrix)?;
+        let mut result = Affine2D::new(inv, Vector2D::new(T::from(0), T::from(0)));
+        let t = result.apply_vector(&self.translation);
+        result.translation = Vector2D::new(-t.x, -t.y);
+        Ok(result)
+    }
+}
+
+/// The reflection of the plane in a line.
+///
+/// The map is determined by the line, which is stored as given; its matrix
+/// `I − 2·n·nᵀ / Q(n)` for the normal `n = (a, b)` has rational entries, so
+/// reflections stay exact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Reflection2D<T> {
+    pub line: Line2D<T>,
+}
+
+impl<T> Reflection2D<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    /// Creates the reflection in `line`.
+    ///
+    /// Returns `MathError::NullLine` if `a = b = 0`.
+    pub fn in_line(line: Line2D<T>) -> Result<Self, MathError>
+    where
+        T: Loggable,
+    {
+        if quadrance(&(line.a, line.b)) == T::from(0) {
+            return Err(degenerate(
+                targets::TRANSFORM,
+                "Reflection2D::in_line",
+                MathError::NullLine,
+                &line,
+            ));
+        }
+        Ok(Reflection2D { line })
+    }
+}
+
+impl<T> Reflection2D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// The reflection as an [`Affine2D`].
+    pub fn to_affine(&self) -> Affine2D<T> {
+        let (a, b, c) = (self.line.a, self.line.b, self.line.c);
+        let n = quadrance(&(a, b));
+        let k = T::from(2) / n;
+        let zero = T::from(0);
+        Affine2D::new(
+            [
+                [(b * b - a * a) / n, zero - k * a * b],
+                [zero - k * a * b, (a * a - b * b) / n],
+            ],
+            Vector2D::new(zero - k * a * c, zero - k * b * c),
+        )
+    }
+
+    /// Reflects a point in the line.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::{Line2D, Point2D};
+    /// use rat_trig_rs::transform::Reflection2D;
+    /// let r = Rational32::from;
+    /// // the line x + y = 2
+    /// let refl = Reflection2D::in_line(Line2D::new(r(1), r(1), r(-2))).unwrap();
+    /// assert_eq!(refl.apply(&Point2D::new(r(0), r(0))), Point2D::new(r(2), r(2)));
+    /// assert_eq!(refl.apply(&Point2D::new(r(3), r(-1))), Point2D::new(r(3), r(-1)));
+    /// ```
+    #[inline]
+    pub fn apply(&self, p: &Point2D<T>) -> Point2D<T> {
+        self.to_affine().apply(p)
+    }
+
+    /// Returns the map `self ∘ other`, i.e. `other` is applied first. The
+    /// product of two reflections is a rotation (lines meeting) or a
+    /// translation (parallel lines), and `r ∘ r` is the identity.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::{Line2D, Point2D};
+    /// use rat_trig_rs::transform::Reflection2D;
+    /// let x_axis = Reflection2D::in_line(Line2D::new(0, 1, 0)).unwrap();
+    /// let y_axis = Reflection2D::in_line(Line2D::new(1, 0, 0)).unwrap();
+    /// assert!(x_axis.compose(&x_axis).is_identity());
+    /// let half_turn = x_axis.compose(&y_axis);
+    /// assert_eq!(half_turn.apply(&Point2D::new(2, 3)), Point2D::new(-2, -3));
+    /// ```
+    #[inline]
+    pub fn compose(&self, other: &Self) -> Affine2D<T> {
+        self.to_affine().compose(&other.to_affine())
+    }
+}
+
+/// A rotation of the plane given by parameters `(a, b)`.
+///
+/// The parameters form a complex number `a + b·i` that need not have unit
+/// norm: the rotation matrix is the integral matrix returned by
+/// [`Rotation2D::matrix`] divided by the shared denominator `a² + b²`, and
+/// the rotation turns by twice the "angle" of `a + b·i`. This is the planar
+/// case of [`Rotation3D`], and every rational rotation arises this way.
+///
+/// Parameters that differ by a nonzero scalar factor describe the same
+/// rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rotation2D<T> {
+    pub a: T,
+    pub b: T,
+}
+
+impl<T> Rotation2D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    /// Creates a rotation from its parameters.
+    ///
+    /// Returns `MathError::InvalidInput` if both parameters vanish.
+    pub fn new(a: T, b: T) -> Result<Self, MathError>
+    where
+        T: Loggable,
+    {
+        let rot = Rotation2D { a, b };
+        if rot.norm() == T::from(0) {
+            return Err(degenerate(
+                targets::TRANSFORM,
+                "Rotation2D::new",
+                MathError::InvalidInput,
+                &(a, b),
+            ));
+        }
+        Ok(rot)
+    }
+
+    /// Creates the rotation with half-turn parameter `t`, whose matrix is
+    /// `((1 − t², −2t), (2t, 1 − t²)) / (1 + t²)`. Every rotation except the
+    /// half turn has exactly one such parameter, rational when the matrix
+    /// is.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::Vector2D;
+    /// use rat_trig_rs::transform::Rotation2D;
+    /// let r = Rational32::from;
+    /// // t = 1/2 turns (1, 0) to (3/5, 4/5)
+    /// let rot = Rotation2D::from_parameter(Rational32::new(1, 2));
+    /// let v = rot.apply_vector(&Vector2D::new(r(1), r(0)));
+    /// assert_eq!(v, Vector2D::new(Rational32::new(3, 5), Rational32::new(4, 5)));
+    /// ```
+    #[inline]
+    pub fn from_parameter(t: T) -> Self {
+        Rotation2D {
+            a: T::from(1),
+            b: t,
+        }
+    }
+
+    /// The identity rotation.
+    #[inline]
+    pub fn identity() -> Self {
+        Rotation2D::from_parameter(T::from(0))
+    }
+
+    /// The shared denominator `a² + b²`.
+    #[inline]
+    pub fn norm(&self) -> T {
+        self.a * self.a + self.b * self.b
+    }
+
+    /// The rotation matrix scaled by [`Rotation2D::norm`], row-major.
+    pub fn matrix(&self) -> [[T; 2]; 2] {
+        let (a, b) = (self.a, self.b);
+        let two = T::from(2);
+        [
+            [a * a - b * b, T::from(0) - two * a * b],
+            [two * a * b, a * a - b * b],
+        ]
+    }
+
+    /// Returns the rotation `self ∘ other`, i.e. `other` is applied first.
+    ///
+    /// This is the complex product of the parameters, so the result is exact
+    /// and its norm is the product of the two norms.
+    pub fn compose(&self, other: &Self) -> Self {
+        Rotation2D {
+            a: self.a * other.a - self.b * other.b,
+            b: self.a * other.b + self.b * other.a,
+        }
+    }
+
+    /// Checks whether two parameter sets describe the same rotation, i.e.
+    /// whether they are proportional.
+    #[inline]
+    pub fn same_rotation(&self, other: &Self) -> bool {
+        self.a * other.b == self.b * other.a
+    }
+}
+
+impl<T> Rotation2D<T>
+where
+    T: Copy + Neg<Output = T>,
+{
+    /// The inverse rotation, given by the conjugate parameters.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        Rotation2D {
+            a: self.a,
+            b: -self.b,
+        }
+    }
+}
+
+impl<T> Rotation2D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// The rotation as an [`Affine2D`] with zero translation.
+    pub fn to_affine(&self) -> Affine2D<T> {
+        let n = self.norm();
+        let zero = T::from(0);
+        Affine2D::new(
+            self.matrix().map(|row| row.map(|x| x / n)),
+            Vector2D::new(zero, zero),
+        )
+    }
+
+    /// Rotates a vector.
+    #[inline]
+    pub fn apply_vector(&self, v: &Vector2D<T>) -> Vector2D<T> {
+        self.to_affine().apply_vector(v)
+    }
+
+    /// Rotates a point about the origin.
+    #[inline]
+    pub fn apply(&self, p: &Point2D<T>) -> Point2D<T> {
+        self.to_affine().apply(p)
+    }
+}
+
+/// A rotation of space given by Euler–Rodrigues parameters `(a, b, c, d)`.
+///
+/// The parameters form a quaternion `a + b·i + c·j + d·k` that need not have
+/// unit norm: the rotation matrix is the integral matrix returned by
+/// [`Rotation3D::matrix`] divided by the shared denominator
+/// `a² + b² + c² + d²`. Every rotation with a rational matrix arises this
+/// way from rational parameters, so rotations stay exact without any trig.
+///
+/// Parameters that differ by a nonzero scalar factor describe the same
+/// rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rotation3D<T> {
+    pub a: T,
+    pub b: T,
+    pub c: T,
+    pub d: T,
+}
+
+impl<T> Rotation3D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    /// Creates a rotation from its Euler–Rodrigues parameters.
+    ///
+    /// Returns `MathError::InvalidInput` if all four parameters vanish.
+    pub fn new(a: T, b: T, c: T, d: T) -> Result<Self, MathError>
+    where
+        T: Loggable,
+    {
+        let rot = Rotation3D { a, b, c, d };
+        if rot.norm() == T::from(0) {
+            return Err(degenerate(
+                targets::TRANSFORM,
+                "Rotation3D::new",
+                MathError::InvalidInput,
+                &(a, b, c, d),
+            ));
+        }
+        Ok(rot)
+    }
+
+    /// The identity rotation.
+    #[inline]
+    pub fn identity() -> Self {
+        let zero = T::from(0);
+        Rotation3D {
+            a: T::from(1),
+            b: zero,
+            c: zero,
+            d: zero,
+        }
+    }
+
+    /// The shared denominator `a² + b² + c² + d²`.
+    #[inline]
+    pub fn norm(&self) -> T {
+        self.a * self.a + self.b * self.b + self.c * self.c + self.d * self.d
+    }
+
+    /// The rotation matrix scaled by [`Rotation3D::norm`], row-major.
+    pub fn matrix(&self) -> [[T; 3]; 3] {
+        let (a, b, c, d) = (self.a, self.b, self.c, self.d);
+        let two = T::from(2);
+        [
+            [
+                a * a + b * b - c * c - d * d,
+                two * (b * c - a * d),
+                two * (b * d + a * c),
+            ],
+            [
+                two * (b * c + a * d),
+                a * a - b * b + c * c - d * d,
+                two * (c * d - a * b),
+            ],
+            [
+                two * (b * d - a * c),
+                two * (c * d + a * b),
+                a * a - b * b - c * c + d * d,
+            ],
+        ]
+    }
+
+    /// Returns the rotation `self ∘ other`, i.e. `other` is applied first.
+    ///
+    /// This is the quaternion product of the parameters, so the result is
+    /// exact and its norm is the product of the two norms.
+    pub fn compose(&self, other: &Self) -> Self {
+        let (a1, b1, c1, d1) = (self.a, self.b, self.c, self.d);
+        let (a2, b2, c2, d2) = (other.a, other.b, other.c, other.d);
+        Rotation3D {
+            a: a1 * a2 - b1 * b2 - c1 * c2 - d1 * d2,
+            b: a1 * b2 + b1 * a2 + c1 * d2 - d1 * c2,
+            c: a1 * c2 - b1 * d2 + c1 * a2 + d1 * b2,
+            d: a1 * d2 + b1 * c2 - c1 * b2 + d1 * a2,
+        }
+    }
+
+    /// Checks whether two parameter sets describe the same rotation, i.e.
+    /// whether they are proportional.
+    pub fn same_rotation(&self, other: &Self) -> bool {
+        let p = [self.a, self.b, self.c, self.d];
+        let q = [other.a, other.b, other.c, other.d];
+        (0..4).all(|i| (0..4).all(|j| p[i] * q[j] == p[j] * q[i]))
+    }
+}
+
+impl<T> Rotation3D<T>
+where
+    T: Copy + Neg<Output = T>,
+{
+    /// The inverse rotation, given by the conjugate parameters.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        Rotation3D {
+            a: self.a,
+            b: -self.b,
+            c: -self.c,
+            d: -self.d,
+        }
+    }
+}
+
+impl<T> Rotation3D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// Rotates a vector.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use rat_trig_rs::geometry::Vector3D;
+    /// use rat_trig_rs::transform::Rotation3D;
+    /// // quarter turn about the z-axis
+    /// let rot = Rotation3D::new(1, 0, 0, 1).unwrap();
+    /// assert_eq!(rot.apply_vector(&Vector3D::new(1, 0, 5)), Vector3D::new(0, 1, 5));
+    /// ```
+    pub fn apply_vector(&self, v: &Vector3D<T>) -> Vector3D<T> {
+        let m = self.matrix();
+        let n = self.norm();
+        Vector3D::new(
+            (m[0][0] * v.x + m[0][1] * v.y + m[0][2] * v.z) / n,
+            (m[1][0] * v.x + m[1][1] * v.y + m[1][2] * v.z) / n,
+            (m[2][0] * v.x + m[2][1] * v.y + m[2][2] * v.z) / n,
+        )
+    }
+
+    /// Rotates a point about the origin.
+    #[inline]
+    pub fn apply(&self, p: &Point3D<T>) -> Point3D<T> {
+        let v = self.apply_vector(&Vector3D::new(p.x, p.y, p.z));
+        Point3D::new(v.x, v.y, v.z)
+    }
+}
+
+/// A projective transformation of the plane, acting on homogeneous
+/// coordinates `[x : y : w]` by a 3×3 matrix.
+///
+/// Homogeneous coordinates are defined up to a nonzero scalar, so points and
+/// lines can be transformed without any division; only converting back to a
+/// [`Point2D`] divides by `w`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Projective2D<T> {
+    /// The homogeneous matrix, stored row-major.
+    pub matrix: [[T; 3]; 3],
+}
+
+impl<T> Projective2D<T> {
+    /// Creates a projective map from its homogeneous matrix.
+    #[inline]
+    pub const fn new(matrix: [[T; 3]; 3]) -> Self {
+        Projective2D { matrix }
+    }
+}
+
+impl<T> Projective2D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<i32>,
+{
+    /// The identity map.
+    pub fn identity() -> Self {
+        let (zero, one) = (T::from(0), T::from(1));
+        Projective2D::new([[one, zero, zero], [zero, one, zero], [zero, zero, one]])
+    }
+
+    /// Embeds an affine map as a homogeneous matrix.
+    pub fn from_affine(affine: &Affine2D<T>) -> Self {
+        let (m, t) = (&affine.matrix, &affine.translation);
+        let (zero, one) = (T::from(0), T::from(1));
+        Projective2D::new([
+            [m[0][0], m[0][1], t.x],
+            [m[1][0], m[1][1], t.y],
+            [zero, zero, one],
+        ])
+    }
+
+    /// Returns the map `self ∘ other`, i.e. `other` is applied first.
+    #[inline]
+    pub fn compose(&self, other: &Self) -> Self {
+        Projective2D::new(mat_mul(&self.matrix, &other.matrix))
+    }
+
+    /// Transforms a point given in homogeneous coordinates `[x, y, w]`.
+    #[inline]
+    pub fn apply_point(&self, p: &[T; 3]) -> [T; 3] {
+        mat_vec_mul(&self.matrix, p)
+    }
+
+    /// Transforms a line given by homogeneous coefficients `[a, b, c]` of
+    /// `ax + by + cw = 0`, so that incidence with transformed points is
+    /// preserved. Lines map by the transposed adjugate, so this is exact
+    /// even over the integers.
+    #[inline]
+    pub fn apply_line(&self, l: &[T; 3]) -> [T; 3]
+    where
+        T: Neg<Output = T>,
+    {
+        mat_vec_mul(&transpose(&adjugate3(&self.matrix)), l)
+    }
+}
+
+impl<T> Projective2D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// Transforms an affine point.
+    ///
+    /// Returns `MathError::DivisionByZero` if the image lies at infinity.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::Point2D;
+    /// use rat_trig_rs::transform::Projective2D;
+    /// let r = |x: i32| Rational32::from(x);
+    /// // perspective division by x + 1
+    /// let f = Projective2D::new([[r(1), r(0), r(0)], [r(0), r(1), r(0)], [r(1), r(0), r(1)]]);
+    /// assert_eq!(f.apply(&Point2D::new(r(1), r(4))), Ok(Point2D::new(Rational32::new(1, 2), r(2))));
+    /// assert!(f.apply(&Point2D::new(r(-1), r(0))).is_err());
+    /// ```
+    pub fn apply(&self, p: &Point2D<T>) -> Result<Point2D<T>, MathError>
+    where
+        T: Loggable,
+    {
+        let [x, y, w] = self.apply_point(&[p.x, p.y, T::from(1)]);
+        if w == T::from(0) {
+            return Err(degenerate(
+                targets::TRANSFORM,
+                "Projective2D::apply",
+                MathError::DivisionByZero,
+                &(self, p),
+            ));
+        }
+        Ok(Point2D::new(x / w, y / w))
+    }
+
+    /// Extracts the affine part of the map.
+    ///
+    /// Returns `MathError::InvalidInput` if the map is not affine (its last
+    /// row is not proportional to `[0, 0, 1]`) and `MathError::DivisionByZero`
+    /// if the last row vanishes.
+    pub fn affine_part(&self) -> Result<Affine2D<T>, MathError>
+    where
+        T: Loggable,
+    {
+        let m = &self.matrix;
+        let zero = T::from(0);
+        if m[2][0] != zero || m[2][1] != zero {
+            return Err(degenerate(
+                targets::TRANSFORM,
+                "Projective2D::affine_part",
+                MathError::InvalidInput,
+                self,
+            ));
+        }
+        let w = m[2][2];
+        if w == zero {
+            return Err(degenerate(
+                targets::TRANSFORM,
+                "Projective2D::affine_part",
+                MathError::DivisionByZero,
+                self,
+            ));
+        }
+        Ok(Affine2D::new(
+            [[m[0][0] / w, m[0][1] / w], [m[1][0] / w, m[1][1] / w]],
+            Vector2D::new(m[0][2] / w, m[1][2] / w),
+        ))
+    }
+}
+
+/// A projective transformation of space, acting on homogeneous coordinates
+/// `[x : y : z : w]` by a 4×4 matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Projective3D<T> {
+    /// The homogeneous matrix, stored row-major.
+    pub matrix: [[T; 4]; 4],
+}
+
+impl<T> Projective3D<T> {
+    /// Creates a projective map from its homogeneous matrix.
+    #[inline]
+    pub const fn new(matrix: [[T; 4]; 4]) -> Self {
+        Projective3D { matrix }
+    }
+}
+
+impl<T> Projective3D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<i32>,
+{
+    /// The identity map.
+    pub fn identity() -> Self {
+        let (zero, one) = (T::from(0), T::from(1));
+        let mut matrix = [[zero; 4]; 4];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[i] = one;
+        }
+        Projective3D::new(matrix)
+    }
+
+    /// Builds the affine map `p ↦ M·p + t` as a homogeneous matrix.
+    pub fn from_affine(m: &[[T; 3]; 3], t: &Vector3D<T>) -> Self {
+        let (zero, one) = (T::from(0), T::from(1));
+        Projective3D::new([
+            [m[0][0], m[0][1], m[0][2], t.x],
+            [m[1][0], m[1][1], m[1][2], t.y],
+            [m[2][0], m[2][1], m[2][2], t.z],
+            [zero, zero, zero, one],
+        ])
+    }
+
+    /// Embeds a rotation as a homogeneous matrix. The shared denominator of
+    /// the rotation becomes the homogeneous weight, so no division occurs.
+    pub fn from_rotation(rot: &Rotation3D<T>) -> Self
+    where
+        T: PartialEq,
+    {
+        let m = rot.matrix();
+        let zero = T::from(0);
+        Projective3D::new([
+            [m[0][0], m[0][1], m[0][2], zero],
+            [m[1][0], m[1][1], m[1][2], zero],
+            [m[2][0], m[2][1], m[2][2], zero],
+            [zero, zero, zero, rot.norm()],
+        ])
+    }
+
+    /// Returns the map `self ∘ other`, i.e. `other` is applied first.
+    #[inline]
+    pub fn compose(&self, other: &Self) -> Self {
+        Projective3D::new(mat_mul(&self.matrix, &other.matrix))
+    }
+
+    /// Transforms a point given in homogeneous coordinates `[x, y, z, w]`.
+    #[inline]
+    pub fn apply_point(&self, p: &[T; 4]) -> [T; 4] {
+        mat_vec_mul(&self.matrix, p)
+    }
+
+    /// Transforms a plane given by homogeneous coefficients `[a, b, c, d]` of
+    /// `ax + by + cz + dw = 0`, preserving incidence with transformed points.
+    #[inline]
+    pub fn apply_plane(&self, plane: &[T; 4]) -> [T; 4]
+    where
+        T: Neg<Output = T>,
+    {
+        mat_vec_mul(&transpose(&adjugate4(&self.matrix)), plane)
+    }
+}
+
+impl<T> Projective3D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// Transforms an affine point.
+    ///
+    /// Returns `MathError::DivisionByZero` if the image lies at infinity.
+    pub fn apply(&self, p: &Point3D<T>) -> Result<Point3D<T>, MathError>
+    where
+        T: Loggable,
+    {
+        let [x, y, z, w] = self.apply_point(&[p.x, p.y, p.z, T::from(1)]);
+        if w == T::from(0) {
+            return Err(degenerate(
+                targets::TRANSFORM,
+                "Projective3D::apply",
+                MathError::DivisionByZero,
+                &(self, p),
+            ));
+        }
+        Ok(Point3D::new(x / w, y / w, z / w))
+    }
+
+    /// Extracts the affine part `(M, t)` of the map.
+    ///
+    /// Returns `MathError::InvalidInput` if the map is not affine and
+    /// `MathError::DivisionByZero` if the last row vanishes.
+    pub fn affine_part(&self) -> Result<([[T; 3]; 3], Vector3D<T>), MathError>
+    where
+        T: Loggable,
+    {
+        let m = &self.matrix;
+        let zero = T::from(0);
+        if m[3][0] != zero || m[3][1] != zero || m[3][2] != zero {
+            return Err(degenerate(
+                targets::TRANSFORM,
+                "Projective3D::affine_part",
+                MathError::InvalidInput,
+                self,
+            ));
+        }
+        let w = m[3][3];
+        if w == zero {
+            return Err(degenerate(
+                targets::TRANSFORM,
+                "Projective3D::affine_part",
+                MathError::DivisionByZero,
+                self,
+            ));
+        }
+        let mut linear = [[zero; 3]; 3];
+        for (i, row) in linear.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                *entry = m[i][j] / w;
+            }
+        }
+        Ok((linear, Vector3D::new(m[0][3] / w, m[1][3] / w, m[2][3] / w)))
+    }
+}
+
+/// Coordinate types for which [`Isometry2D::apply_to_slice`] and
+/// [`Isometry3D::apply_to_slice`] verify rigidity in debug builds.
+///
+/// The verification compares quadrances with `==`, which is only meaningful
+/// when the ring operations are exact. Integers and rationals set `EXACT`
+/// to `true`; floating-point types set it to `false` and skip the check.
+pub trait ExactCoordinate {
+    /// Whether `+`, `−` and `·` are exact, so a rigid motion preserves
+    /// quadrances bit for bit.
+    const EXACT: bool;
+}
+
+macro_rules! impl_exact_coordinate {
+    ($exact:expr; $($ty:ty),*) => {
+        $(
+            impl ExactCoordinate for $ty {
+                const EXACT: bool = $exact;
+            }
+        )*
+    };
+}
+
+impl_exact_coordinate!(true; i8, i16, i32, i64, i128, isize);
+impl_exact_coordinate!(false; f32, f64);
+
+impl<T: ExactCoordinate> ExactCoordinate for num_rational::Ratio<T> {
+    const EXACT: bool = T::EXACT;
+}
+
+/// Maximum number of point pairs re-checked by `apply_to_slice` and
+/// `apply_to_slice_checked`.
+const VERIFY_SAMPLES: usize = 8;
+
+/// Quadrances between up to `VERIFY_SAMPLES` pairs of consecutive points,
+/// spread evenly over the slice. Unused slots are left at zero.
+fn sampled_quadrances<P, T>(points: &[P], quad: impl Fn(&P, &P) -> T) -> [T; VERIFY_SAMPLES]
+where
+    T: Copy + From<i32>,
+{
+    let mut result = [T::from(0); VERIFY_SAMPLES];
+    if points.len() >= 2 {
+        let step = (points.len() / VERIFY_SAMPLES).max(1);
+        let pairs = (0..points.len() - 1).step_by(step);
+        for (slot, i) in result.iter_mut().zip(pairs) {
+            *slot = quad(&points[i], &points[i + 1]);
+        }
+    }
+    result
+}
+
+/// A rigid motion of the plane: an affine map whose linear part preserves
+/// quadrance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Isometry2D<T> {
+    pub affine: Affine2D<T>,
+}
+
+impl<T> Isometry2D<T> {
+    /// Wraps an affine map without checking that it is rigid.
+    #[inline]
+    pub const fn new(affine: Affine2D<T>) -> Self {
+        Isometry2D { affine }
+    }
+}
+
+impl<T> Isometry2D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    /// Wraps an affine map, returning `MathError::InvalidInput` unless it is
+    /// exactly an isometry.
+    pub fn try_new(affine: Affine2D<T>) -> Result<Self, MathError>
+    where
+        T: Loggable,
+    {
+        if !affine.is_isometry() {
+            return Err(degenerate(
+                targets::TRANSFORM,
+                "Isometry2D::try_new",
+                MathError::InvalidInput,
+                &affine,
+            ));
+        }
+        Ok(Isometry2D { affine })
+    }
+
+    /// Applies the motion to a point.
+    #[inline]
+    pub fn apply(&self, p: &Point2D<T>) -> Point2D<T> {
+        self.affine.apply(p)
+    }
+
+    /// Applies the motion to every point of a slice in place.
+    ///
+    /// In debug builds, for [exact](ExactCoordinate) coordinates, the
+    /// quadrances between a sample of point pairs are compared before and
+    /// after, panicking if the motion turned out not to be rigid (e.g. an
+    /// unchecked [`Isometry2D::new`] of a shear). Use
+    /// [`Isometry2D::apply_to_slice_checked`] to get an error instead.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::{Point2D, Vector2D};
+    /// use rat_trig_rs::transform::{Affine2D, Isometry2D};
+    /// let r = |x: i32| Rational32::from(x);
+    /// let quarter_turn = Affine2D::new([[r(0), r(-1)], [r(1), r(0)]], Vector2D::new(r(1), r(0)));
+    /// let iso = Isometry2D::try_new(quarter_turn).unwrap();
+    /// let mut points = [Point2D::new(r(1), r(0)), Point2D::new(r(0), r(2))];
+    /// iso.apply_to_slice(&mut points);
+    /// assert_eq!(points, [Point2D::new(r(1), r(1)), Point2D::new(r(-1), r(0))]);
+    /// ```
+    pub fn apply_to_slice(&self, points: &mut [Point2D<T>])
+    where
+        T: ExactCoordinate,
+    {
+        let quad = |p: &Point2D<T>, q: &Point2D<T>| quadrance(&(p.x - q.x, p.y - q.y));
+        let before = (cfg!(debug_assertions) && T::EXACT).then(|| sampled_quadrances(points, quad));
+        self.move_slice(points);
+        if let Some(before) = before {
+            debug_assert!(
+                before == sampled_quadrances(points, quad),
+                "Isometry2D::apply_to_slice: the motion does not preserve quadrance"
+            );
+        }
+    }
+
+    fn move_slice(&self, points: &mut [Point2D<T>]) {
+        for p in points.iter_mut() {
+            *p = self.affine.apply(p);
+        }
+    }
+
+    /// Applies the motion to every point of a slice in place, then compares
+    /// the quadrances between a sample of point pairs with their values
+    /// before the move.
+    ///
+    /// The comparison is exact, so this is meant for exact types such as
+    /// rationals, where it catches an unchecked [`Isometry2D::new`] that is
+    /// not rigid. Returns `MathError::InvalidInput` if a sampled quadrance
+    /// changed; the points are moved either way.
+    pub fn apply_to_slice_checked(&self, points: &mut [Point2D<T>]) -> Result<(), MathError>
+    where
+        T: Loggable,
+    {
+        let quad = |p: &Point2D<T>, q: &Point2D<T>| quadrance(&(p.x - q.x, p.y - q.y));
+        let before = sampled_quadrances(points, quad);
+        self.move_slice(points);
+        if before != sampled_quadrances(points, quad) {
+            return Err(degenerate(
+                targets::TRANSFORM,
+                "Isometry2D::apply_to_slice_checked",
+                MathError::InvalidInput,
+                self,
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<T> Isometry2D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// The rotation followed by a translation taking `src` onto `dst`, with
+    /// `src.p1 ↦ dst.p1` and `src.p2 ↦ dst.p2`.
+    ///
+    /// With `u`, `v` the directions of the segments and `Q` their common
+    /// quadrance, the rotation has entries `u·v / Q` and `(u × v) / Q`, so it
+    /// is rational whenever the endpoints are: no square root is needed.
+    ///
+    /// Returns `MathError::InvalidInput` if the segments have different
+    /// quadrances or are single points.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use rat_trig_rs::geometry::Point2D;
+    /// use rat_trig_rs::segment::Segment2D;
+    /// use rat_trig_rs::transform::Isometry2D;
+    /// let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+    /// let src = Segment2D::new(p(0, 0), p(5, 0));
+    /// let dst = Segment2D::new(p(1, 1), p(4, 5));
+    /// let iso = Isometry2D::from_segments(&src, &dst).unwrap();
+    /// assert_eq!(iso.apply(&p(5, 0)), p(4, 5));
+    /// assert_eq!(iso.apply(&p(0, 5)), p(-3, 4));
+    /// ```
+    pub fn from_segments(src: &Segment2D<T>, dst: &Segment2D<T>) -> Result<Self, MathError>
+    where
+        T: Loggable,
+    {
+        let (u, v) = (src.p2 - src.p1, dst.p2 - dst.p1);
+        let q = u.quadrance();
+        if q == T::from(0) || v.quadrance() != q {
+            return Err(degenerate(
+                targets::TRANSFORM,
+                "Isometry2D::from_segments",
+                MathError::InvalidInput,
+                &(src, dst),
+            ));
+        }
+        let (c, s) = (u.dot(&v) / q, u.cross(&v) / q);
+        let mut affine = Affine2D::new(
+            [[c, T::from(0) - s], [s, c]],
+            Vector2D::new(T::from(0), T::from(0)),
+        );
+        affine.translation = dst.p1 - affine.apply(&src.p1);
+        Ok(Isometry2D { affine })
+    }
+}
+
+/// A rigid motion of space, `p ↦ M·p + t` with `Mᵀ·M = I`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Isometry3D<T> {
+    /// The linear part `M`, stored row-major.
+    pub matrix: [[T; 3]; 3],
+    /// The translation part `t`.
+    pub translation: Vector3D<T>,
+}
+
+impl<T> Isometry3D<T> {
+    /// Creates a motion from its parts without checking that it is rigid.
+    #[inline]
+    pub const fn new(matrix: [[T; 3]; 3], translation: Vector3D<T>) -> Self {
+        Isometry3D {
+            matrix,
+            translation,
+        }
+    }
+}
+
+impl<T> Isometry3D<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    /// Creates a motion from its parts, returning `MathError::InvalidInput`
+    /// unless `Mᵀ·M = I` holds exactly.
+    pub fn try_new(matrix: [[T; 3]; 3], translation: Vector3D<T>) -> Result<Self, MathError>
+    where
+        T: Loggable,
+    {
+        let (zero, one) = (T::from(0), T::from(1));
+        let gram = mat_mul(&transpose(&matrix), &matrix);
+        let is_identity =
+            (0..3).all(|i| (0..3).all(|j| gram[i][j] == if i == j { one } else { zero }));
+        if !is_identity {
+            return Err(degenerate(
+                targets::TRANSFORM,
+                "Isometry3D::try_new",
+                MathError::InvalidInput,
+                &matrix,
+            ));
+        }
+        Ok(Isometry3D::new(matrix, translation))
+    }
+
+    /// Applies the motion to a point.
+    #[inline]
+    pub fn apply(&self, p: &Point3D<T>) -> Point3D<T> {
+        let [x, y, z] = mat_vec_mul(&self.matrix, &[p.x, p.y, p.z]);
+        let t = &self.translation;
+        Point3D::new(x + t.x, y + t.y, z + t.z)
+    }
+
+    /// Applies the motion to every point of a slice in place, with the same
+    /// debug-mode verification as [`Isometry2D::apply_to_slice`].
+    pub fn apply_to_slice(&self, points: &mut [Point3D<T>])
+    where
+        T: ExactCoordinate,
+    {
+        let quad = |p: &Point3D<T>, q: &Point3D<T>| quadrance3d(&(p.x - q.x, p.y - q.y, p.z - q.z));
+        let before = (cfg!(debug_assertions) && T::EXACT).then(|| sampled_quadrances(points, quad));
+        self.move_slice(points);
+        if let Some(before) = before {
+            debug_assert!(
+                before == sampled_quadrances(points, quad),
+                "Isometry3D::apply_to_slice: the motion does not preserve quadrance"
+            );
+        }
+    }
+
+    fn move_slice(&self, points: &mut [Point3D<T>]) {
+        for p in points.iter_mut() {
+            *p = self.apply(p);
+        }
+    }
+
+    /// Applies the motion to every point of a slice in place, with the same
+    /// exact verification as [`Isometry2D::apply_to_slice_checked`].
+    pub fn apply_to_slice_checked(&self, points: &mut [Point3D<T>]) -> Result<(), MathError>
+    where
+        T: Loggable,
+    {
+        let quad = |p: &Point3D<T>, q: &Point3D<T>| quadrance3d(&(p.x - q.x, p.y - q.y, p.z - q.z));
+        let before = sampled_quadrances(points, quad);
+        self.move_slice(points);
+        if before != sampled_quadrances(points, quad) {
+            return Err(degenerate(
+                targets::TRANSFORM,
+                "Isometry3D::apply_to_slice_checked",
+                MathError::InvalidInput,
+                self,
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<T> Isometry3D<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    /// Creates the motion that rotates by `rot` and then translates by `t`.
+    pub fn from_rotation(rot: &Rotation3D<T>, translation: Vector3D<T>) -> Self {
+        let n = rot.norm();
+        Isometry3D::new(rot.matrix().map(|row| row.map(|x| x / n)), translation)
+    }
+}
+
+/// The two isometries (one proper, one improper) taking `a0 ↦ b0` and
+/// `a1 ↦ b1`, assuming both segments have the same nonzero quadrance `q`.
+#[cfg(feature = "alloc")]
+fn isometries_between<T>(
+    a0: &Point2D<T>,
+    a1: &Point2D<T>,
+    b0: &Point2D<T>,
+    b1: &Point2D<T>,
+    q: T,
+) -> [Affine2D<T>; 2]
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    let (ux, uy) = (a1.x - a0.x, a1.y - a0.y);
+    let (vx, vy) = (b1.x - b0.x, b1.y - b0.y);
+    let (dot, cross) = ((ux * vx + uy * vy) / q, (ux * vy - uy * vx) / q);
+    let (c, s) = ((ux * vx - uy * vy) / q, (uy * vx + ux * vy) / q);
+    let zero = Vector2D::new(T::from(0), T::from(0));
+    [[[dot, -cross], [cross, dot]], [[c, s], [s, -c]]].map(|m| {
+        let mut f = Affine2D::new(m, zero);
+        let image = f.apply(a0);
+        f.translation = Vector2D::new(b0.x - image.x, b0.y - image.y);
+        f
+    })
+}
+
+/// Checks whether `f` maps the multiset `src` onto the multiset `dst`.
+#[cfg(feature = "alloc")]
+fn maps_onto<T>(f: &Affine2D<T>, src: &[Point2D<T>], dst: &[Point2D<T>]) -> bool
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialEq + From<i32>,
+{
+    let mut used = vec![false; dst.len()];
+    src.iter().all(|p| {
+        let image = f.apply(p);
+        match (0..dst.len()).find(|&j| !used[j] && dst[j] == image) {
+            Some(j) => {
+                used[j] = true;
+                true
+            }
+            None => false,
+        }
+    })
+}
+
+/// The function `find_isometry` recovers the isometry relating two congruent
+/// point sets, without knowing which point corresponds to which.
+///
+/// A first source point is matched against every target point whose sorted
+/// multiset of quadrances to the rest of its set agrees; a second point then
+/// fixes the candidate rotation and reflection exactly, and each candidate is
+/// verified against the whole set.
+///
+/// Arguments:
+///
+/// * `src`: The points before the motion.
+/// * `dst`: The points after the motion, in any order.
+///
+/// Returns:
+///
+/// An isometry `f` with `f(src) = dst` as multisets, or
+/// `MathError::InvalidInput` if the sets are not congruent.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::geometry::Point2D;
+/// use rat_trig_rs::transform::find_isometry;
+/// let p = |x: i32, y: i32| Point2D::new(Rational32::from(x), Rational32::from(y));
+/// let src = [p(0, 0), p(4, 0), p(0, 3)];
+/// let dst = [p(1, 1), p(1, -3), p(-2, 1)];
+/// let iso = find_isometry(&src, &dst).unwrap();
+/// assert_eq!(iso.apply(&p(4, 0)), p(1, -3));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn find_isometry<T>(src: &[Point2D<T>], dst: &[Point2D<T>]) -> Result<Isometry2D<T>, MathError>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + PartialOrd
+        + From<i32>
+        + Loggable,
+{
+    if src.len() != dst.len() {
+        return Err(degenerate(
+            targets::TRANSFORM,
+            "find_isometry",
+            MathError::InvalidInput,
+            &(src, dst),
+        ));
+    }
+    let Some(a0) = src.first() else {
+        return Ok(Isometry2D::new(Affine2D::identity()));
+    };
+    let quad = |p: &Point2D<T>, q: &Point2D<T>| quadrance(&(p.x - q.x, p.y - q.y));
+    let profile = |anchor: &Point2D<T>, points: &[Point2D<T>]| {
+        let mut qs: Vec<T> = points.iter().map(|p| quad(anchor, p)).collect();
+        qs.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        qs
+    };
+    let src_profile = profile(a0, src);
+    let zero = T::from(0);
+    let a1 = src.iter().find(|p| quad(a0, p) != zero);
+
+    for b0 in dst.iter().filter(|b0| profile(b0, dst) == src_profile) {
+        let Some(a1) = a1 else {
+            // all source points coincide
+            let mut f = Affine2D::identity();
+            f.translation = Vector2D::new(b0.x - a0.x, b0.y - a0.y);
+            return Ok(Isometry2D::new(f));
+        };
+        let q = quad(a0, a1);
+        for b1 in dst.iter().filter(|b1| quad(b0, b1) == q) {
+            for f in isometries_between(a0, a1, b0, b1, q) {
+                if maps_onto(&f, src, dst) {
+                    return Ok(Isometry2D::new(f));
+                }
+            }
+        }
+    }
+    Err(degenerate(
+        targets::TRANSFORM,
+        "find_isometry",
+        MathError::InvalidInput,
+        &(src, dst),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+
+    type Q = Ratio<i32>;
+
+    fn q(n: i32) -> Q {
+        Q::from_integer(n)
+    }
+
+    fn rotation_3_4_5() -> Affine2D<Q> {
+        let (c, s) = (Q::new(3, 5), Q::new(4, 5));
+        Affine2D::new([[c, -s], [s, c]], Vector2D::new(q(1), q(2)))
+    }
+
+    #[test]
+    fn test_apply_compose() {
+        let f = Affine2D::new([[2, 0], [0, 3]], Vector2D::new(1, 1));
+        let g = Affine2D::new([[0, -1], [1, 0]], Vector2D::new(0, 5));
+        let p = Point2D::new(4, -2);
+        assert_eq!(f.apply(&p), Point2D::new(9, -5));
+        assert_eq!(f.compose(&g).apply(&p), f.apply(&g.apply(&p)));
+        assert_eq!(Affine2D::identity().compose(&f), f);
+    }
+
+    #[test]
+    fn test_isometry_checks() {
+        let rot = rotation_3_4_5();
+        assert!(rot.is_isometry());
+        assert!(rot.is_orientation_preserving());
+        assert_eq!(rot.determinant(), q(1));
+
+        let refl = Affine2D::new([[q(1), q(0)], [q(0), q(-1)]], Vector2D::new(q(0), q(0)));
+        assert!(refl.is_isometry());
+        assert!(!refl.is_orientation_preserving());
+
+        let scale = Affine2D::new([[q(2), q(0)], [q(0), q(2)]], Vector2D::new(q(0), q(0)));
+        assert!(!scale.is_isometry());
+        assert!(scale.is_orientation_preserving());
+    }
+
+    #[test]
+    fn test_try_inverse() {
+        let rot = rotation_3_4_5();
+        let inv = rot.try_inverse().unwrap();
+        assert_eq!(rot.compose(&inv), Affine2D::identity());
+        assert_eq!(inv.compose(&rot), Affine2D::identity());
+
+        let singular = Affine2D::new([[q(1), q(2)], [q(2), q(4)]], Vector2D::new(q(0), q(0)));
+        assert_eq!(singular.try_inverse(), Err(MathError::DivisionByZero));
+    }
+
+    #[test]
+    fn test_affine2d_constructors() {
+        let p = Point2D::new(q(3), q(-2));
+        let shift = Affine2D::translation(Vector2D::new(q(1), q(5)));
+        assert_eq!(shift.apply(&p), Point2D::new(q(4), q(3)));
+        let scale = Affine2D::scaling(q(2), Q::new(1, 2));
+        assert_eq!(scale.apply(&p), Point2D::new(q(6), q(-1)));
+        assert_eq!(
+            scale.try_inverse(),
+            Ok(Affine2D::scaling(Q::new(1, 2), q(2)))
+        );
+        let shear = Affine2D::shear(q(1), q(1));
+        assert_eq!(shear.try_inverse(), Err(MathError::DivisionByZero));
+        let rot = Affine2D::rotation(Q::new(1, 2));
+        assert_eq!(rot.matrix, rotation_3_4_5().matrix);
+        // rotate about (1, 5) by conjugating with the translation
+        let about = shift.compose(&rot).compose(&shift.try_inverse().unwrap());
+        assert_eq!(
+            about.apply(&Point2D::new(q(1), q(5))),
+            Point2D::new(q(1), q(5))
+        );
+        assert!(about.is_isometry());
+    }
+
+    #[test]
+    fn test_similarity() {
+        use crate::traits::{MetricOps, RationalTrig};
+        let f = Affine2D::rotation(Q::new(1, 3))
+            .compose(&Affine2D::scaling(q(2), q(2)))
+            .compose(&Affine2D::translation(Vector2D::new(q(-1), q(4))));
+        assert!(f.is_similarity() && !f.is_isometry());
+        let k = f.quadrance_scale().unwrap();
+        assert_eq!(k, q(4));
+        let (a, b, c) = (
+            Point2D::new(q(0), q(0)),
+            Point2D::new(q(3), q(1)),
+            Point2D::new(q(-1), q(2)),
+        );
+        let (fa, fb, fc) = (f.apply(&a), f.apply(&b), f.apply(&c));
+        assert_eq!(fa.quadrance_to(&fb), k * a.quadrance_to(&b));
+        assert_eq!(fa.spread_at(&fb, &fc), a.spread_at(&b, &c));
+        // reflections are similarities too, but shears are not
+        let mirror = Affine2D::scaling(q(-1), q(1));
+        assert_eq!(mirror.quadrance_scale(), Some(q(1)));
+        assert!(!Affine2D::shear(q(1), q(0)).is_similarity());
+        assert!(!Affine2D::scaling(q(0), q(0)).is_similarity());
+    }
+
+    #[test]
+    fn test_from_triangles() {
+        let p = |x: i32, y: i32| Point2D::new(q(x), q(y));
+        let src = Triangle2D::new(p(1, 2), p(4, -1), p(0, 5));
+        let f = rotation_3_4_5();
+        let dst = Triangle2D::new(f.apply(&src.p1), f.apply(&src.p2), f.apply(&src.p3));
+        assert_eq!(Affine2D::from_triangles(&src, &dst), Ok(f));
+        let g = Affine2D::from_triangles(&dst, &src).unwrap();
+        assert!(g.compose(&f).is_identity());
+        // a flat target collapses the plane onto a line
+        let flat = Triangle2D::new(p(0, 0), p(1, 1), p(2, 2));
+        let h = Affine2D::from_triangles(&src, &flat).unwrap();
+        assert_eq!(h.determinant(), q(0));
+        assert_eq!(h.apply(&src.p3), p(2, 2));
+        assert_eq!(
+            Affine2D::from_triangles(&flat, &src),
+            Err(MathError::DegenerateTriangle)
+        );
+    }
+
+    #[test]
+    fn test_shear_and_scaling_spreads() {
+        use crate::traits::MetricOps;
+        let p = |x: i32, y: i32| Point2D::new(q(x), q(y));
+        let (a, b, c) = (p(0, 0), p(4, 0), p(0, 3));
+        let spreads = |f: &Affine2D<Q>| {
+            let (a, b, c) = (f.apply(&a), f.apply(&b), f.apply(&c));
+            [
+                a.spread_at(&b, &c),
+                b.spread_at(&c, &a),
+                c.spread_at(&a, &b),
+            ]
+            .map(Result::unwrap)
+        };
+        let original = spreads(&Affine2D::identity());
+        assert_eq!(original, [q(1), Q::new(9, 25), Q::new(16, 25)]);
+        // uniform scaling keeps every spread
+        assert_eq!(spreads(&Affine2D::scaling(q(3), q(3))), original);
+        // anisotropic scaling keeps the spread of the axis-aligned right
+        // angle but not the others
+        let stretched = spreads(&Affine2D::scaling(q(1), q(2)));
+        assert_eq!(stretched, [q(1), Q::new(9, 13), Q::new(4, 13)]);
+        // a shear along the x-axis fixes b but tilts the side ac
+        let sheared = Affine2D::shear_x(q(1));
+        assert_eq!(sheared.apply(&c), p(3, 3));
+        assert_eq!(sheared.determinant(), q(1));
+        assert_eq!(
+            spreads(&sheared),
+            [Q::new(1, 2), Q::new(9, 10), Q::new(4, 5)]
+        );
+        let vertical = Affine2D::shear_y(q(1));
+        assert_eq!(vertical.apply(&b), p(4, 4));
+        assert_eq!(
+            vertical.compose(&Affine2D::shear_y(q(-1))),
+            Affine2D::identity()
+        );
+    }
+
+    #[test]
+    fn test_isometry_from_segments() {
+        let p = |x: i32, y: i32| Point2D::new(q(x), q(y));
+        let src = Segment2D::new(p(1, 2), p(2, 9));
+        let dst = Segment2D::new(p(-3, 0), p(2, -5));
+        let iso = Isometry2D::from_segments(&src, &dst).unwrap();
+        assert_eq!(iso.apply(&src.p1), dst.p1);
+        assert_eq!(iso.apply(&src.p2), dst.p2);
+        assert!(iso.affine.is_isometry() && iso.affine.is_orientation_preserving());
+        // a point off the segment keeps its quadrances to both ends
+        let x = p(4, -1);
+        let y = iso.apply(&x);
+        let quad = |a: &Point2D<Q>, b: &Point2D<Q>| (*b - *a).quadrance();
+        assert_eq!(quad(&y, &dst.p1), quad(&x, &src.p1));
+        assert_eq!(quad(&y, &dst.p2), quad(&x, &src.p2));
+        let identity = Isometry2D::from_segments(&src, &src).unwrap();
+        assert!(identity.affine.is_identity());
+        assert_eq!(
+            Isometry2D::from_segments(&src, &Segment2D::new(p(0, 0), p(7, 0))),
+            Err(MathError::InvalidInput)
+        );
+        let point = Segment2D::new(p(1, 1), p(1, 1));
+        assert_eq!(
+            Isometry2D::from_segments(&point, &point),
+            Err(MathError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_reflection2d() {
+        assert_eq!(
+            Reflection2D::in_line(Line2D::new(q(0), q(0), q(1))),
+            Err(MathError::NullLine)
+        );
+        // the line 3x - y + 1 = 0 through (0, 1) and (1, 4)
+        let line = Line2D::new(q(3), q(-1), q(1));
+        let refl = Reflection2D::in_line(line).unwrap();
+        assert_eq!(
+            refl.apply(&Point2D::new(q(1), q(4))),
+            Point2D::new(q(1), q(4))
+        );
+        let p = Point2D::new(q(2), q(-3));
+        let image = refl.apply(&p);
+        assert_eq!(image, Point2D::new(q(-4), q(-1)));
+        assert!(refl.compose(&refl).is_identity());
+        let f = refl.to_affine();
+        assert!(f.is_isometry() && !f.is_orientation_preserving());
+        // reflections in lines through the origin at a spread of 1/2 compose
+        // to a quarter turn
+        let diagonal = Reflection2D::in_line(Line2D::new(q(1), q(-1), q(0))).unwrap();
+        let x_axis = Reflection2D::in_line(Line2D::new(q(0), q(1), q(0))).unwrap();
+        let turn = diagonal.compose(&x_axis);
+        assert_eq!(turn, Rotation2D::from_parameter(q(1)).to_affine());
+        // parallel lines compose to a translation by twice their distance
+        let shifted = Reflection2D::in_line(Line2D::new(q(0), q(1), q(-3))).unwrap();
+        let shift = shifted.compose(&x_axis);
+        assert_eq!(
+            shift,
+            Affine2D::new(Affine2D::identity().matrix, Vector2D::new(q(0), q(6)))
+        );
+    }
+
+    #[test]
+    fn test_rotation2d() {
+        assert_eq!(Rotation2D::new(0, 0), Err(MathError::InvalidInput));
+        let rot = Rotation2D::from_parameter(Q::new(1, 2));
+        assert_eq!(
+            rot.to_affine(),
+            Affine2D::new(rotation_3_4_5().matrix, Vector2D::new(q(0), q(0)))
+        );
+        assert!(rot.to_affine().is_isometry() && rot.to_affine().is_orientation_preserving());
+        let p = Point2D::new(q(2), Q::new(-1, 3));
+        assert_eq!(rot.inverse().apply(&rot.apply(&p)), p);
+        assert!(rot
+            .compose(&rot.inverse())
+            .same_rotation(&Rotation2D::identity()));
+        // scaled parameters, and composition as the product
+        let other = Rotation2D::new(q(2), q(-6)).unwrap();
+        assert!(other.same_rotation(&Rotation2D::from_parameter(q(-3))));
+        let v = Vector2D::new(q(5), q(1));
+        assert_eq!(
+            rot.compose(&other).apply_vector(&v),
+            rot.apply_vector(&other.apply_vector(&v))
+        );
+        // t = 1 is a quarter turn, so two of them make the half turn
+        let quarter = Rotation2D::from_parameter(q(1));
+        assert_eq!(quarter.apply_vector(&v), Vector2D::new(q(-1), q(5)));
+        assert_eq!(
+            quarter.compose(&quarter).apply_vector(&v),
+            Vector2D::new(q(-5), q(-1))
+        );
+    }
+
+    #[test]
+    fn test_rotation3d() {
+        assert_eq!(Rotation3D::new(0, 0, 0, 0), Err(MathError::InvalidInput));
+
+        let rot = Rotation3D::new(q(1), q(2), q(3), q(4)).unwrap();
+        assert_eq!(rot.norm(), q(30));
+        let v = Vector3D::new(q(1), q(-2), Q::new(1, 2));
+        let w = rot.apply_vector(&v);
+        let quad = |v: &Vector3D<Q>| v.x * v.x + v.y * v.y + v.z * v.z;
+        assert_eq!(quad(&w), quad(&v));
+        assert_eq!(rot.inverse().apply_vector(&w), v);
+
+        let p = Point3D::new(q(2), q(0), q(1));
+        assert_eq!(rot.inverse().apply(&rot.apply(&p)), p);
+        assert!(rot
+            .compose(&rot.inverse())
+            .same_rotation(&Rotation3D::identity()));
+    }
+
+    #[test]
+    fn test_rotation3d_compose() {
+        let r1 = Rotation3D::new(q(1), q(0), q(0), q(1)).unwrap();
+        let r2 = Rotation3D::new(q(2), q(1), q(0), q(0)).unwrap();
+        let v = Vector3D::new(q(3), q(1), q(-1));
+        let composed = r1.compose(&r2);
+        assert_eq!(composed.norm(), r1.norm() * r2.norm());
+        assert_eq!(
+            composed.apply_vector(&v),
+            r1.apply_vector(&r2.apply_vector(&v))
+        );
+        // two quarter turns make a half turn
+        let half = r1.compose(&r1);
+        assert!(half.same_rotation(&Rotation3D::new(q(0), q(0), q(0), q(1)).unwrap()));
+    }
+
+    fn dot<const N: usize>(a: &[i32; N], b: &[i32; N]) -> i32 {
+        a.iter().zip(b).map(|(x, y)| x * y).sum()
+    }
+
+    #[test]
+    fn test_projective2d() {
+        let f = Projective2D::new([[2, 1, 0], [0, 1, 3], [1, 0, 1]]);
+        let g = Projective2D::new([[1, 0, 1], [1, 1, 0], [0, 2, 1]]);
+        let p = [1, 2, 1];
+        assert_eq!(
+            f.compose(&g).apply_point(&p),
+            f.apply_point(&g.apply_point(&p))
+        );
+        // the line x - 2y + 3w = 0 passes through p
+        let l = [1, -2, 3];
+        assert_eq!(dot(&l, &p), 0);
+        assert_eq!(dot(&f.apply_line(&l), &f.apply_point(&p)), 0);
+        assert_eq!(Projective2D::identity().compose(&f), f);
+    }
+
+    #[test]
+    fn test_projective2d_affine_part() {
+        let affine = rotation_3_4_5();
+        let proj = Projective2D::from_affine(&affine);
+        assert_eq!(proj.affine_part(), Ok(affine));
+        let p = Point2D::new(q(3), Q::new(1, 3));
+        assert_eq!(proj.apply(&p), Ok(affine.apply(&p)));
+
+        let scaled = Projective2D::new(proj.matrix.map(|row| row.map(|x| x * q(7))));
+        assert_eq!(scaled.affine_part(), Ok(affine));
+
+        let persp = Projective2D::new([[q(1), q(0), q(0)], [q(0), q(1), q(0)], [q(1), q(0), q(1)]]);
+        assert_eq!(persp.affine_part(), Err(MathError::InvalidInput));
+    }
+
+    #[test]
+    fn test_projective3d() {
+        let rot = Rotation3D::new(q(1), q(2), q(3), q(4)).unwrap();
+        let proj = Projective3D::from_rotation(&rot);
+        let p = Point3D::new(q(1), q(-1), q(2));
+        assert_eq!(proj.apply(&p), Ok(rot.apply(&p)));
+        let (m, t) = proj.affine_part().unwrap();
+        assert_eq!(t, Vector3D::new(q(0), q(0), q(0)));
+        assert_eq!(m[0][0], rot.matrix()[0][0] / rot.norm());
+
+        let f = Projective3D::new([[1, 0, 2, 0], [0, 1, 0, 1], [3, 0, 1, 0], [0, 1, 0, 2]]);
+        // the plane x + y + z - 3w = 0 passes through (1, 1, 1)
+        let (plane, pt) = ([1, 1, 1, -3], [1, 1, 1, 1]);
+        assert_eq!(dot(&f.apply_plane(&plane), &f.apply_point(&pt)), 0);
+        let g =
+            Projective3D::from_affine(&[[1, 0, 0], [0, 2, 0], [0, 0, 1]], &Vector3D::new(1, 2, 3));
+        assert_eq!(g.apply_point(&pt), [2, 4, 4, 1]);
+        assert_eq!(Projective3D::identity().compose(&g), g);
+    }
+
+    #[test]
+    fn test_isometry2d_apply_to_slice() {
+        let iso = Isometry2D::try_new(rotation_3_4_5()).unwrap();
+        let original = [
+            Point2D::new(q(0), q(0)),
+            Point2D::new(q(5), q(0)),
+            Point2D::new(Q::new(1, 2), q(-3)),
+        ];
+        let mut points = original;
+        iso.apply_to_slice(&mut points);
+        for (p, o) in points.iter().zip(original.iter()) {
+            assert_eq!(*p, iso.apply(o));
+        }
+        let mut checked = original;
+        assert_eq!(iso.apply_to_slice_checked(&mut checked), Ok(()));
+        assert_eq!(checked, points);
+        let scale = Affine2D::new([[q(2), q(0)], [q(0), q(2)]], Vector2D::new(q(0), q(0)));
+        assert_eq!(Isometry2D::try_new(scale), Err(MathError::InvalidInput));
+    }
+
+    #[test]
+    fn test_isometry2d_detects_non_rigid() {
+        let shear = Affine2D::new([[1, 1], [0, 1]], Vector2D::new(0, 0));
+        let mut points = [Point2D::new(0, 0), Point2D::new(0, 1)];
+        assert_eq!(
+            Isometry2D::new(shear).apply_to_slice_checked(&mut points),
+            Err(MathError::InvalidInput)
+        );
+        assert_eq!(points[1], Point2D::new(1, 1));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "does not preserve quadrance")]
+    fn test_isometry2d_apply_to_slice_non_rigid() {
+        let shear = Affine2D::new([[1, 1], [0, 1]], Vector2D::new(0, 0));
+        let mut points = [Point2D::new(0, 0), Point2D::new(0, 1)];
+        Isometry2D::new(shear).apply_to_slice(&mut points);
+    }
+
+    #[test]
+    fn test_isometry2d_apply_to_slice_floats() {
+        // a 30° rotation only preserves quadrance up to rounding
+        let (c, s) = (0.75_f64.sqrt(), 0.5);
+        let iso = Isometry2D::new(Affine2D::new([[c, -s], [s, c]], Vector2D::new(0.1, 0.2)));
+        let mut points = [
+            Point2D::new(0.3, 0.7),
+            Point2D::new(1.1, -2.9),
+            Point2D::new(5.0, 0.1),
+        ];
+        iso.apply_to_slice(&mut points);
+        let d = (points[1].x - points[2].x, points[1].y - points[2].y);
+        assert!((quadrance(&d) - 24.21).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_isometry3d_apply_to_slice() {
+        let rot = Rotation3D::new(q(1), q(2), q(3), q(4)).unwrap();
+        let iso = Isometry3D::from_rotation(&rot, Vector3D::new(q(1), q(0), q(-1)));
+        assert!(Isometry3D::try_new(iso.matrix, iso.translation).is_ok());
+        let mut points = [
+            Point3D::new(q(0), q(0), q(0)),
+            Point3D::new(q(1), q(2), q(3)),
+            Point3D::new(q(-1), Q::new(1, 2), q(0)),
+        ];
+        assert_eq!(iso.apply_to_slice_checked(&mut points), Ok(()));
+        assert_eq!(points[0], Point3D::new(q(1), q(0), q(-1)));
+
+        let m = [[q(1), q(0), q(0)], [q(0), q(1), q(1)], [q(0), q(0), q(1)]];
+        let t = Vector3D::new(q(0), q(0), q(0));
+        assert_eq!(Isometry3D::try_new(m, t), Err(MathError::InvalidInput));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_find_isometry() {
+        let p = |x: i32, y: i32| Point2D::new(q(x), q(y));
+        let src = [p(0, 0), p(2, 0), p(2, 1), p(0, 3), p(2, 0)];
+        let motion = rotation_3_4_5();
+        let mut dst = src;
+        Isometry2D::new(motion).apply_to_slice(&mut dst);
+        dst.reverse();
+        let iso = find_isometry(&src, &dst).unwrap();
+        assert!(iso.affine.is_isometry());
+        assert!(iso.affine.is_orientation_preserving());
+        assert_eq!(iso.affine, motion);
+
+        // a mirror image is found as an improper isometry
+        let mirrored = src.map(|pt| Point2D::new(-pt.x, pt.y));
+        let iso = find_isometry(&src, &mirrored).unwrap();
+        assert!(!iso.affine.is_orientation_preserving());
+
+        let other = [p(0, 0), p(2, 0), p(2, 1), p(0, 4), p(2, 0)];
+        assert_eq!(find_isometry(&src, &other), Err(MathError::InvalidInput));
+        assert_eq!(find_isometry(&src, &dst[1..]), Err(MathError::InvalidInput));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_find_isometry_degenerate() {
+        let empty: [Point2D<Q>; 0] = [];
+        assert!(find_isometry(&empty, &empty).is_ok());
+        let src = [Point2D::new(q(1), q(1)); 2];
+        let dst = [Point2D::new(q(3), q(0)); 2];
+        let iso = find_isometry(&src, &dst).unwrap();
+        assert_eq!(iso.apply(&src[0]), dst[0]);
+    }
+}
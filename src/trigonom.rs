@@ -1,4 +1,3 @@
-use core::convert::From;
 /// Rational Trigonometry is a new approach to classical trigonometry, developed by Norman
 /// Wildberger, that aims to simplify and clarify the subject by using only rational numbers
 /// and operations, rather than irrational numbers and limits.
@@ -21,7 +20,11 @@ use core::convert::From;
 /// In summary, Rational Trigonometry is a new approach to classical trigonometry that uses
 /// rational numbers and operations, rather than irrational numbers and limits, making it a more
 /// straightforward and intuitive subject to understand and work with.
-use core::ops::{Add, Mul, Sub};
+use crate::error::MathError;
+use crate::logging::{degenerate, targets, Loggable};
+use crate::quadext::QuadExt;
+use core::convert::From;
+use core::ops::{Add, Div, Mul, Sub};
 
 /// The function `archimedes` calculates the area of a triangle using Archimedes' formula with the
 /// lengths of the three sides provided as `Fraction<i64>` values.
@@ -30,7 +33,7 @@ use core::ops::{Add, Mul, Sub};
 ///
 /// * `q_1`: Represents the length of the first side of the triangle.
 /// * `q_2`: The parameters `q_1`, `q_2`, and `q_3` represent the lengths of the sides of a triangle. In
-///          the context of Archimedes' formula for the area of a triangle, `q_1`, `q_2`, and `q_3`
+///   the context of Archimedes' formula for the area of a triangle, `q_1`, `q_2`, and `q_3`
 /// * `q_3`: The parameter `q_3` represents the length of the third side of the triangle.
 ///
 /// Returns:
@@ -52,47 +55,1657 @@ use core::ops::{Add, Mul, Sub};
 #[inline]
 pub fn archimedes<T>(q_1: &T, q_2: &T, q_3: &T) -> T
 where
-    T: std::marker::Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<i32>,
+    T: core::marker::Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<i32>,
 {
     let temp = *q_1 + *q_2 - *q_3;
     T::from(4) * *q_1 * *q_2 - temp * temp
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use num_rational::Ratio;
-    // use fractions::Fraction;
+/// The function `quadrume` calculates the quadrume of a tetrahedron from its
+/// six quadrances: the Cayley–Menger determinant, equal to `288·V²`. It is
+/// the 3D analogue of [`archimedes`], computed as the determinant of the
+/// matrix `Mᵢⱼ = q₀ᵢ + q₀ⱼ − qᵢⱼ` (with `qᵢᵢ = 0`) seen from vertex 0.
+///
+/// Arguments:
+///
+/// * `q_01`, `q_02`, `q_03`: The quadrances from vertex 0 to the others.
+/// * `q_12`, `q_13`, `q_23`: The quadrances between the other three vertices.
+///
+/// Returns:
+///
+/// The quadrume, which is zero exactly when the four vertices are coplanar.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::trigonom::quadrume;
+/// // the corner of the unit cube: V = 1/6
+/// assert_eq!(quadrume(&1, &1, &1, &2, &2, &2), 8);
+/// ```
+pub fn quadrume<T>(q_01: &T, q_02: &T, q_03: &T, q_12: &T, q_13: &T, q_23: &T) -> T
+where
+    T: core::marker::Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<i32>,
+{
+    let two = T::from(2);
+    let (a, b, c) = (two * *q_01, two * *q_02, two * *q_03);
+    let d = *q_01 + *q_02 - *q_12;
+    let e = *q_01 + *q_03 - *q_13;
+    let f = *q_02 + *q_03 - *q_23;
+    a * (b * c - f * f) - d * (d * c - f * e) + e * (d * f - b * e)
+}
 
-    #[test]
-    fn test_archimedes2() {
-        let q_1: i64 = 1;
-        let q_2: i64 = 2;
-        let q_3: i64 = 3;
-        assert_eq!(archimedes(&q_1, &q_2, &q_3), 8);
+/// The function `quadrea_from_qqs` calculates the quadrea of a triangle from
+/// two quadrances and the spread between those sides, `4·q₁·q₂·s₃`.
+///
+/// Arguments:
+///
+/// * `q_1`, `q_2`: The quadrances of two sides.
+/// * `s_3`: The spread at the vertex where those sides meet.
+///
+/// Returns:
+///
+/// The quadrea, which agrees with Archimedes' formula on the three
+/// quadrances.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::trigonom::{archimedes, quadrea_from_qqs};
+/// let (q_1, q_2, q_3) = (Rational32::from(25), Rational32::from(9), Rational32::from(16));
+/// let s_3 = Rational32::new(16, 25);
+/// assert_eq!(quadrea_from_qqs(&q_1, &q_2, &s_3), archimedes(&q_1, &q_2, &q_3));
+/// ```
+#[inline]
+pub fn quadrea_from_qqs<T>(q_1: &T, q_2: &T, s_3: &T) -> T
+where
+    T: core::marker::Copy + Mul<Output = T> + From<i32>,
+{
+    T::from(4) * *q_1 * *q_2 * *s_3
+}
+
+/// The function `spread_from_quadrea` solves `A = 4·q₁·q₂·s₃` for the spread
+/// `s₃` between two sides of a triangle with quadrea `A`.
+///
+/// Arguments:
+///
+/// * `quadrea`: The quadrea `A` of the triangle.
+/// * `q_1`, `q_2`: The quadrances of the two sides meeting at the vertex.
+///
+/// Returns:
+///
+/// The spread `A / (4·q₁·q₂)`, or `MathError::DivisionByZero` if either
+/// quadrance is zero.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::error::MathError;
+/// use rat_trig_rs::trigonom::spread_from_quadrea;
+/// let r = Rational32::from;
+/// assert_eq!(spread_from_quadrea(&r(576), &r(25), &r(9)), Ok(Rational32::new(16, 25)));
+/// assert_eq!(spread_from_quadrea(&r(0), &r(0), &r(9)), Err(MathError::DivisionByZero));
+/// ```
+pub fn spread_from_quadrea<T>(quadrea: &T, q_1: &T, q_2: &T) -> Result<T, MathError>
+where
+    T: core::marker::Copy + Mul<Output = T> + Div<Output = T> + PartialEq + From<i32> + Loggable,
+{
+    let denom = T::from(4) * *q_1 * *q_2;
+    if denom == T::from(0) {
+        return Err(degenerate(
+            targets::TRIGONOM,
+            "spread_from_quadrea",
+            MathError::DivisionByZero,
+            &(quadrea, q_1, q_2),
+        ));
     }
+    Ok(*quadrea / denom)
+}
 
-    #[test]
-    fn test_archimedes3() {
-        let q_1 = 1.0;
-        let q_2 = 2.0;
-        let q_3 = 3.0;
-        assert_eq!(archimedes(&q_1, &q_2, &q_3), 8.0);
+/// The function `median_quadrances` calculates the quadrances of the
+/// medians of a triangle from its side quadrances.
+///
+/// By Apollonius' theorem the median from the vertex opposite side `i` has
+/// quadrance `mᵢ = (2qⱼ + 2qₖ − qᵢ)/4`.
+///
+/// Arguments:
+///
+/// * `q_1`, `q_2`, `q_3`: The quadrances of the sides.
+///
+/// Returns:
+///
+/// The median quadrances `(m₁, m₂, m₃)`, where `mᵢ` belongs to the median
+/// that bisects the side of quadrance `qᵢ`.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::trigonom::median_quadrances;
+/// let r = Rational32::from;
+/// // the 3-4-5 triangle: the median to the hypotenuse is half of it
+/// let (m_1, m_2, m_3) = median_quadrances(&r(25), &r(9), &r(16));
+/// assert_eq!(m_1, Rational32::new(25, 4));
+/// assert_eq!((m_2, m_3), (Rational32::new(73, 4), r(13)));
+/// ```
+#[inline]
+pub fn median_quadrances<T>(q_1: &T, q_2: &T, q_3: &T) -> (T, T, T)
+where
+    T: core::marker::Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + From<i32>,
+{
+    let (two, four) = (T::from(2), T::from(4));
+    let median = |q_i: T, q_j: T, q_k: T| (two * (q_j + q_k) - q_i) / four;
+    (
+        median(*q_1, *q_2, *q_3),
+        median(*q_2, *q_1, *q_3),
+        median(*q_3, *q_1, *q_2),
+    )
+}
+
+/// The function `altitude_quadrance` calculates the quadrances of the
+/// altitudes of a triangle.
+///
+/// The altitude onto the side of quadrance `qᵢ` has quadrance
+/// `hᵢ = A / (4·qᵢ)`, where `A` is the quadrea, since both sides equal four
+/// times the squared area divided by `qᵢ`.
+///
+/// Arguments:
+///
+/// * `q_1`, `q_2`, `q_3`: The quadrances of the sides.
+/// * `quadrea`: The quadrea of the triangle, e.g. from [`archimedes`].
+///
+/// Returns:
+///
+/// The altitude quadrances `(h₁, h₂, h₃)`, where `hᵢ` belongs to the
+/// altitude onto the side of quadrance `qᵢ`, or `MathError::DivisionByZero`
+/// if a side has quadrance zero.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::trigonom::{altitude_quadrance, archimedes};
+/// let r = Rational32::from;
+/// let (q_1, q_2, q_3) = (r(25), r(9), r(16));
+/// let a = archimedes(&q_1, &q_2, &q_3);
+/// // the altitude onto the hypotenuse of the 3-4-5 triangle is 12/5
+/// assert_eq!(
+///     altitude_quadrance(&q_1, &q_2, &q_3, &a),
+///     Ok((Rational32::new(144, 25), r(16), r(9)))
+/// );
+/// ```
+pub fn altitude_quadrance<T>(q_1: &T, q_2: &T, q_3: &T, quadrea: &T) -> Result<(T, T, T), MathError>
+where
+    T: core::marker::Copy + Mul<Output = T> + Div<Output = T> + PartialEq + From<i32> + Loggable,
+{
+    let zero = T::from(0);
+    if *q_1 == zero || *q_2 == zero || *q_3 == zero {
+        return Err(degenerate(
+            targets::TRIGONOM,
+            "altitude_quadrance",
+            MathError::DivisionByZero,
+            &(q_1, q_2, q_3, quadrea),
+        ));
     }
+    let four = T::from(4);
+    Ok((
+        *quadrea / (four * *q_1),
+        *quadrea / (four * *q_2),
+        *quadrea / (four * *q_3),
+    ))
+}
 
-    #[test]
-    fn test_archimedes() {
-        let q_1 = Ratio::<i32>::new(1, 2);
-        let q_2 = Ratio::<i32>::new(1, 4);
-        let q_3 = Ratio::<i32>::new(1, 6);
-        assert_eq!(archimedes(&q_1, &q_2, &q_3), Ratio::<i32>::new(23, 144));
+/// The function `brahmagupta` calculates the quadrea `16·K²` of a convex
+/// cyclic quadrilateral from its side quadrances, the rational form of
+/// Brahmagupta's formula.
+///
+/// With `B = 2·Σ qᵢ·qⱼ − Σ qᵢ²` (Archimedes' formula extended to four
+/// quadrances) and `P = q₁·q₂·q₃·q₄`, the quadrea satisfies
+/// `(A − B)² = 64·P`. A convex cyclic quadrilateral takes the root
+/// `B + 8·√P`; a self-crossing one with the same sides takes the conjugate.
+///
+/// Arguments:
+///
+/// * `q_1`, `q_2`, `q_3`, `q_4`: The quadrances of the sides, in order.
+///
+/// Returns:
+///
+/// The quadrea as the surd `B + 8·√P`, rational when `P` is a square. The
+/// result is only meaningful if the quadrilateral is cyclic, which the side
+/// quadrances cannot tell; see `circle::cyclic_quadrea` for a checked
+/// version on points.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::trigonom::brahmagupta;
+/// let r = Rational32::from;
+/// // the 3×4 rectangle: P = 144², so A = 1152 + 8·144 = 16·12²
+/// let a = brahmagupta(&r(9), &r(16), &r(9), &r(16));
+/// assert_eq!((a.a, a.b, a.d), (r(1152), r(8), r(20736)));
+/// assert_eq!(a.a + a.b * r(144), r(16 * 144));
+/// ```
+pub fn brahmagupta<T>(q_1: &T, q_2: &T, q_3: &T, q_4: &T) -> QuadExt<T>
+where
+    T: core::marker::Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<i32>,
+{
+    let (q_1, q_2, q_3, q_4) = (*q_1, *q_2, *q_3, *q_4);
+    let pairs = q_1 * q_2 + q_1 * q_3 + q_1 * q_4 + q_2 * q_3 + q_2 * q_4 + q_3 * q_4;
+    let squares = q_1 * q_1 + q_2 * q_2 + q_3 * q_3 + q_4 * q_4;
+    QuadExt::new(
+        T::from(2) * pairs - squares,
+        T::from(8),
+        q_1 * q_2 * q_3 * q_4,
+    )
+}
+
+/// The function `ptolemy` evaluates Ptolemy's relation in quadrance form,
+/// `(q₁·q₃ + q₂·q₄ − q₅·q₆)² − 4·q₁·q₂·q₃·q₄`, which vanishes exactly when
+/// the lengths satisfy `d₁·d₂ = ℓ₁·ℓ₃ ± ℓ₂·ℓ₄` (or a signed variant), e.g.
+/// for four concyclic points.
+///
+/// Arguments:
+///
+/// * `q_1`, `q_2`, `q_3`, `q_4`: The quadrances of the sides `A₁A₂`,
+///   `A₂A₃`, `A₃A₄` and `A₄A₁`.
+/// * `q_5`, `q_6`: The quadrances of the diagonals `A₁A₃` and `A₂A₄`.
+///
+/// Returns:
+///
+/// The residual of the relation; zero for a cyclic quadrilateral.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::trigonom::ptolemy;
+/// // the 3×4 rectangle, and a kite that is not cyclic
+/// assert_eq!(ptolemy(&9, &16, &9, &16, &25, &25), 0);
+/// assert_ne!(ptolemy(&2, &5, &5, &2, &9, &4), 0);
+/// ```
+#[inline]
+pub fn ptolemy<T>(q_1: &T, q_2: &T, q_3: &T, q_4: &T, q_5: &T, q_6: &T) -> T
+where
+    T: core::marker::Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<i32>,
+{
+    let temp = *q_1 * *q_3 + *q_2 * *q_4 - *q_5 * *q_6;
+    temp * temp - T::from(4) * *q_1 * *q_2 * *q_3 * *q_4
+}
+
+/// The function `satisfies_ptolemy` checks Ptolemy's relation exactly; see
+/// [`ptolemy`].
+///
+/// Returns:
+///
+/// Whether the residual is zero. Over floats, compare [`ptolemy`] with a
+/// tolerance instead.
+#[inline]
+pub fn satisfies_ptolemy<T>(q_1: &T, q_2: &T, q_3: &T, q_4: &T, q_5: &T, q_6: &T) -> bool
+where
+    T: core::marker::Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    ptolemy(q_1, q_2, q_3, q_4, q_5, q_6) == T::from(0)
+}
+
+/// The function `quadrilateral_quadrea` calculates the quadrea `16·K²` of a
+/// quadrilateral from its sides and diagonals,
+/// `4·q₅·q₆ − (q₁ − q₂ + q₃ − q₄)²` (Bretschneider's formula in quadrance
+/// form). Unlike [`brahmagupta`] it is always rational; for a cyclic
+/// quadrilateral it picks out the right root of Brahmagupta's formula.
+///
+/// Arguments:
+///
+/// * `q_1`, `q_2`, `q_3`, `q_4`: The quadrances of the sides `A₁A₂`,
+///   `A₂A₃`, `A₃A₄` and `A₄A₁`.
+/// * `q_5`, `q_6`: The quadrances of the diagonals `A₁A₃` and `A₂A₄`.
+///
+/// Returns:
+///
+/// The quadrea, where `K` is the signed area (the sum of the twists of
+/// `A₁A₂A₃` and `A₁A₃A₄`, halved).
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::trigonom::quadrilateral_quadrea;
+/// // the 3×4 rectangle has area 12
+/// assert_eq!(quadrilateral_quadrea(&9, &16, &9, &16, &25, &25), 16 * 144);
+/// ```
+#[inline]
+pub fn quadrilateral_quadrea<T>(q_1: &T, q_2: &T, q_3: &T, q_4: &T, q_5: &T, q_6: &T) -> T
+where
+    T: core::marker::Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<i32>,
+{
+    let temp = *q_1 - *q_2 + *q_3 - *q_4;
+    T::from(4) * *q_5 * *q_6 - temp * temp
+}
+
+/// The function `midpoint` calculates the midpoint of two points.
+///
+/// Arguments:
+///
+/// * `p1`, `p2`: The points as `(x, y)` pairs.
+///
+/// Returns:
+///
+/// `((x₁ + x₂) / 2, (y₁ + y₂) / 2)`. This is exact for rationals; for
+/// integers the division truncates.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::trigonom::midpoint;
+/// let r = Rational32::from;
+/// assert_eq!(midpoint(&(r(1), r(2)), &(r(4), r(2))), (Rational32::new(5, 2), r(2)));
+/// ```
+#[inline]
+pub fn midpoint<T>(p1: &(T, T), p2: &(T, T)) -> (T, T)
+where
+    T: core::marker::Copy + Add<Output = T> + Div<Output = T> + From<i32>,
+{
+    let two = T::from(2);
+    ((p1.0 + p2.0) / two, (p1.1 + p2.1) / two)
+}
+
+/// The function `affine_combination` calculates the point
+/// `p1 + t·(p2 − p1)` on the line through two points.
+///
+/// Arguments:
+///
+/// * `p1`, `p2`: The points as `(x, y)` pairs.
+/// * `t`: The affine parameter; `0` gives `p1`, `1` gives `p2`.
+///
+/// Returns:
+///
+/// The combination `(1 − t)·p1 + t·p2`.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::trigonom::affine_combination;
+/// let r = Rational32::from;
+/// let t = Rational32::new(1, 3);
+/// assert_eq!(affine_combination(&(r(0), r(3)), &(r(3), r(0)), t), (r(1), r(2)));
+/// ```
+#[inline]
+pub fn affine_combination<T>(p1: &(T, T), p2: &(T, T), t: T) -> (T, T)
+where
+    T: core::marker::Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    (p1.0 + t * (p2.0 - p1.0), p1.1 + t * (p2.1 - p1.1))
+}
+
+/// The function `line_from_points` calculates the line `a·x + b·y + c = 0`
+/// through two points.
+///
+/// Arguments:
+///
+/// * `p1`, `p2`: The points as `(x, y)` pairs.
+///
+/// Returns:
+///
+/// The coefficients `(y₁ − y₂, x₂ − x₁, x₁·y₂ − x₂·y₁)`, so that `(b, −a)`
+/// points from `p1` to `p2`, or `MathError::InvalidInput` if the points
+/// coincide.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::error::MathError;
+/// use rat_trig_rs::trigonom::line_from_points;
+/// assert_eq!(line_from_points(&(1, 1), &(3, 2)), Ok((-1, 2, -1)));
+/// assert_eq!(line_from_points(&(0.5, 0.0), &(0.5, 1.0)), Ok((-1.0, 0.0, 0.5)));
+/// assert_eq!(line_from_points(&(2, 2), &(2, 2)), Err(MathError::InvalidInput));
+/// ```
+pub fn line_from_points<T>(p1: &(T, T), p2: &(T, T)) -> Result<(T, T, T), MathError>
+where
+    T: core::marker::Copy + Sub<Output = T> + Mul<Output = T> + PartialEq + Loggable,
+{
+    if p1 == p2 {
+        return Err(degenerate(
+            targets::TRIGONOM,
+            "line_from_points",
+            MathError::InvalidInput,
+            &(p1, p2),
+        ));
     }
+    Ok((p1.1 - p2.1, p2.0 - p1.0, p1.0 * p2.1 - p2.0 * p1.1))
+}
 
-    // #[test]
-    // fn test_archimedes4() {
-    //     let q_1 = Fraction::<i64>::new(1, 2);
-    //     let q_2 = Fraction::<i64>::new(1, 4);
-    //     let q_3 = Fraction::<i64>::new(1, 6);
-    //     assert_eq!(archimedes(&q_1, &q_2, &q_3), Fraction::<i64>::new(23, 144));
-    // }
+/// The function `perpendicular_bisector` calculates the line of points
+/// equidistant from two points.
+///
+/// Expanding `Q(X, p₁) = Q(X, p₂)` gives a linear equation, so the bisector
+/// is found with ring operations only and stays exact over the integers.
+///
+/// Arguments:
+///
+/// * `p1`, `p2`: The points as `(x, y)` pairs.
+///
+/// Returns:
+///
+/// The coefficients `(2(x₂ − x₁), 2(y₂ − y₁), x₁² + y₁² − x₂² − y₂²)`, so
+/// that `p2` lies on the positive side, or `MathError::InvalidInput` if the
+/// points coincide.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::error::MathError;
+/// use rat_trig_rs::trigonom::perpendicular_bisector;
+/// // x = 1
+/// assert_eq!(perpendicular_bisector(&(0, 0), &(2, 0)), Ok((4, 0, -4)));
+/// assert_eq!(perpendicular_bisector(&(1, 2), &(3, 4)), Ok((4, 4, -20)));
+/// assert_eq!(perpendicular_bisector(&(2, 2), &(2, 2)), Err(MathError::InvalidInput));
+/// ```
+pub fn perpendicular_bisector<T>(p1: &(T, T), p2: &(T, T)) -> Result<(T, T, T), MathError>
+where
+    T: core::marker::Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + PartialEq
+        + Loggable,
+{
+    if p1 == p2 {
+        return Err(degenerate(
+            targets::TRIGONOM,
+            "perpendicular_bisector",
+            MathError::InvalidInput,
+            &(p1, p2),
+        ));
+    }
+    let (dx, dy) = (p2.0 - p1.0, p2.1 - p1.1);
+    Ok((
+        dx + dx,
+        dy + dy,
+        p1.0 * p1.0 + p1.1 * p1.1 - p2.0 * p2.0 - p2.1 * p2.1,
+    ))
+}
+
+/// The function `line_intersection` calculates the meet of two lines
+/// `a·x + b·y + c = 0`, by Cramer's rule.
+///
+/// Arguments:
+///
+/// * `l1`, `l2`: The lines as coefficient triples `(a, b, c)`.
+///
+/// Returns:
+///
+/// The intersection point `(x, y)`, or `None` if the lines are parallel,
+/// coincident or not lines at all. See [`safe_line_intersection`] to tell
+/// these cases apart.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::trigonom::line_intersection;
+/// let r = Rational32::from;
+/// // x + y = 1 and x - 2y = 0
+/// let l1 = (r(1), r(1), r(-1));
+/// let l2 = (r(1), r(-2), r(0));
+/// assert_eq!(line_intersection(&l1, &l2), Some((Rational32::new(2, 3), Rational32::new(1, 3))));
+/// assert_eq!(line_intersection(&l1, &(r(2), r(2), r(5))), None);
+/// ```
+#[inline]
+pub fn line_intersection<T>(l1: &(T, T, T), l2: &(T, T, T)) -> Option<(T, T)>
+where
+    T: core::marker::Copy
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    let det = l1.0 * l2.1 - l2.0 * l1.1;
+    if det == T::from(0) {
+        return None;
+    }
+    Some((
+        (l1.1 * l2.2 - l2.1 * l1.2) / det,
+        (l1.2 * l2.0 - l2.2 * l1.0) / det,
+    ))
+}
+
+/// The function `safe_line_intersection` calculates the meet of two lines
+/// like [`line_intersection`], reporting why there is none.
+///
+/// Returns:
+///
+/// The intersection point, or `MathError::NullLine` if `a = b = 0` for
+/// either line, `MathError::NonIntersecting` if the lines are parallel and
+/// distinct, and `MathError::InvalidInput` if they coincide.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::error::MathError;
+/// use rat_trig_rs::trigonom::safe_line_intersection;
+/// assert_eq!(safe_line_intersection(&(1, 0, -2), &(0, 1, -3)), Ok((2, 3)));
+/// assert_eq!(safe_line_intersection(&(1, 1, 0), &(2, 2, 1)), Err(MathError::NonIntersecting));
+/// assert_eq!(safe_line_intersection(&(1, 1, 1), &(2, 2, 2)), Err(MathError::InvalidInput));
+/// assert_eq!(safe_line_intersection(&(0, 0, 1), &(1, 1, 0)), Err(MathError::NullLine));
+/// ```
+pub fn safe_line_intersection<T>(l1: &(T, T, T), l2: &(T, T, T)) -> Result<(T, T), MathError>
+where
+    T: core::marker::Copy
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>
+        + Loggable,
+{
+    let zero = T::from(0);
+    if (l1.0 == zero && l1.1 == zero) || (l2.0 == zero && l2.1 == zero) {
+        return Err(degenerate(
+            targets::TRIGONOM,
+            "safe_line_intersection",
+            MathError::NullLine,
+            &(l1, l2),
+        ));
+    }
+    line_intersection(l1, l2).ok_or_else(|| {
+        // parallel lines coincide when their coefficient triples are
+        // proportional, i.e. when c matches up as well
+        let coincident = l1.0 * l2.2 - l2.0 * l1.2 == zero && l1.1 * l2.2 - l2.1 * l1.2 == zero;
+        let kind = if coincident {
+            MathError::InvalidInput
+        } else {
+            MathError::NonIntersecting
+        };
+        degenerate(targets::TRIGONOM, "safe_line_intersection", kind, &(l1, l2))
+    })
+}
+
+/// The function `bilines` calculates the bilines of two meeting lines, the
+/// rational analogue of their angle bisectors.
+///
+/// The bilines are the two lines through the vertex that make equal spreads
+/// with `l1` and `l2`. With `Qᵢ = aᵢ² + bᵢ²` and `r = √(Q₁·Q₂)` they are
+/// `Q₂·l₁ + r·l₂` and `Q₂·l₁ − r·l₂`, so they are rational exactly when
+/// `Q₁·Q₂` is a square.
+///
+/// Arguments:
+///
+/// * `l1`, `l2`: The lines as coefficient triples `(a, b, c)`.
+/// * `sqrt`: A square root that returns `None` when its argument has no
+///   exact root in `T`.
+///
+/// Returns:
+///
+/// The two bilines, which are perpendicular to each other. Errors are
+/// `MathError::NullLine` if either line has `a = b = 0`,
+/// `MathError::NonIntersecting` if the lines are parallel or coincide, and
+/// `MathError::InvalidInput` if `sqrt` finds no root of `Q₁·Q₂`.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::error::MathError;
+/// use rat_trig_rs::trigonom::bilines;
+/// let sqrt = |q: i64| (0..100).find(|&n| n * n == q);
+/// // the x axis and 3x − 4y = 0: Q₁·Q₂ = 25
+/// let [b1, b2] = bilines(&(0, 1, 0), &(3, -4, 0), sqrt).unwrap();
+/// assert_eq!((b1, b2), ((15, 5, 0), (-15, 45, 0)));
+/// // the x axis and y = x meet at a spread 1/2, whose bilines are irrational
+/// assert_eq!(bilines(&(0, 1, 0), &(1, -1, 0), sqrt), Err(MathError::InvalidInput));
+/// ```
+pub fn bilines<T, F>(l1: &(T, T, T), l2: &(T, T, T), sqrt: F) -> Result<[(T, T, T); 2], MathError>
+where
+    T: core::marker::Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + PartialEq
+        + From<i32>
+        + Loggable,
+    F: FnOnce(T) -> Option<T>,
+{
+    let zero = T::from(0);
+    let q_1 = l1.0 * l1.0 + l1.1 * l1.1;
+    let q_2 = l2.0 * l2.0 + l2.1 * l2.1;
+    if q_1 == zero || q_2 == zero {
+        return Err(degenerate(
+            targets::TRIGONOM,
+            "bilines",
+            MathError::NullLine,
+            &(l1, l2),
+        ));
+    }
+    if l1.0 * l2.1 - l2.0 * l1.1 == zero {
+        return Err(degenerate(
+            targets::TRIGONOM,
+            "bilines",
+            MathError::NonIntersecting,
+            &(l1, l2),
+        ));
+    }
+    let r = sqrt(q_1 * q_2).ok_or_else(|| {
+        degenerate(
+            targets::TRIGONOM,
+            "bilines",
+            MathError::InvalidInput,
+            &(l1, l2),
+        )
+    })?;
+    let combine = |k: T| {
+        (
+            q_2 * l1.0 + k * l2.0,
+            q_2 * l1.1 + k * l2.1,
+            q_2 * l1.2 + k * l2.2,
+        )
+    };
+    Ok([combine(r), combine(zero - r)])
+}
+
+/// The function `triple_spread` evaluates the triple spread formula
+/// `(s₁ + s₂ + s₃)² − 2·(s₁² + s₂² + s₃²) − 4·s₁·s₂·s₃`, which vanishes for
+/// the spreads of any triangle (indeed of any three lines).
+///
+/// Arguments:
+///
+/// * `s_1`, `s_2`, `s_3`: The three spreads.
+///
+/// Returns:
+///
+/// The residual of the formula; zero when the triple is consistent.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::trigonom::triple_spread;
+/// let r = Rational32::new;
+/// // the equilateral triangle, and the 3-4-5 triangle
+/// assert_eq!(triple_spread(&r(3, 4), &r(3, 4), &r(3, 4)), r(0, 1));
+/// assert_eq!(triple_spread(&r(9, 25), &r(16, 25), &r(1, 1)), r(0, 1));
+/// ```
+#[inline]
+pub fn triple_spread<T>(s_1: &T, s_2: &T, s_3: &T) -> T
+where
+    T: core::marker::Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<i32>,
+{
+    let (s_1, s_2, s_3) = (*s_1, *s_2, *s_3);
+    let sum = s_1 + s_2 + s_3;
+    sum * sum - T::from(2) * (s_1 * s_1 + s_2 * s_2 + s_3 * s_3) - T::from(4) * s_1 * s_2 * s_3
+}
+
+/// The function `satisfies_triple_spread` checks whether three values can be
+/// the spreads of a triangle.
+///
+/// Arguments:
+///
+/// * `s_1`, `s_2`, `s_3`: The candidate spreads.
+///
+/// Returns:
+///
+/// Whether each lies in `[0, 1]` and together they satisfy the triple spread
+/// formula exactly. Over floats, compare [`triple_spread`] with a tolerance
+/// instead.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::trigonom::satisfies_triple_spread;
+/// let r = Rational32::new;
+/// assert!(satisfies_triple_spread(&r(1, 2), &r(1, 2), &r(1, 1)));
+/// assert!(!satisfies_triple_spread(&r(1, 2), &r(1, 2), &r(1, 2)));
+/// ```
+#[inline]
+pub fn satisfies_triple_spread<T>(s_1: &T, s_2: &T, s_3: &T) -> bool
+where
+    T: core::marker::Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + PartialOrd
+        + From<i32>,
+{
+    let (zero, one) = (T::from(0), T::from(1));
+    [s_1, s_2, s_3].iter().all(|&&s| zero <= s && s <= one) && triple_spread(s_1, s_2, s_3) == zero
+}
+
+/// The function `cross_law_solve` solves the cross law
+/// `(q₁ + q₂ − q₃)² = 4·q₁·q₂·(1 − s₃)` for the third quadrance.
+///
+/// Given two sides and the spread between them (the rational analogue of
+/// SAS), the third quadrance is `q₃ = q₁ + q₂ ± √D` with
+/// `D = 4·q₁·q₂·(1 − s₃)`. The two signs belong to the two supplementary
+/// angles with spread `s₃`.
+///
+/// Arguments:
+///
+/// * `q_1`, `q_2`: The quadrances of two sides.
+/// * `s_3`: The spread between them.
+///
+/// Returns:
+///
+/// Both solutions as surds over `√D`, the smaller first; they coincide when
+/// `D = 0`. Use [`QuadExt::try_rational`] to get them in `T` when `D` is a
+/// square. Returns `MathError::NegativeQuadrance` if `q_1` or `q_2` is
+/// negative and `MathError::InvalidInput` unless `0 <= s_3 <= 1`.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::trigonom::cross_law_solve;
+/// let r = Rational32::from;
+/// let sqrt = |d: Rational32| (0..100).map(r).find(|&x| x * x == d);
+/// // sides 5 and 5 with spread 16/25: D = 900, so q₃ = 50 ± 30
+/// let [near, far] = cross_law_solve(&r(25), &r(25), &Rational32::new(16, 25)).unwrap();
+/// assert_eq!(near.try_rational(sqrt), Some(r(20)));
+/// assert_eq!(far.try_rational(sqrt), Some(r(80)));
+/// // a spread of 1/2 between unit sides leaves the rationals: q₃ = 2 ± √2
+/// let [near, _] = cross_law_solve(&r(1), &r(1), &Rational32::new(1, 2)).unwrap();
+/// assert_eq!((near.a, near.b, near.d), (r(2), r(-1), r(2)));
+/// ```
+pub fn cross_law_solve<T>(q_1: &T, q_2: &T, s_3: &T) -> Result<[QuadExt<T>; 2], MathError>
+where
+    T: core::marker::Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + PartialOrd
+        + From<i32>
+        + Loggable,
+{
+    let zero = T::from(0);
+    if *q_1 < zero || *q_2 < zero {
+        return Err(degenerate(
+            targets::TRIGONOM,
+            "cross_law_solve",
+            MathError::NegativeQuadrance,
+            &(q_1, q_2, s_3),
+        ));
+    }
+    if *s_3 < zero || *s_3 > T::from(1) {
+        return Err(degenerate(
+            targets::TRIGONOM,
+            "cross_law_solve",
+            MathError::InvalidInput,
+            &(q_1, q_2, s_3),
+        ));
+    }
+    let disc = T::from(4) * *q_1 * *q_2 * (T::from(1) - *s_3);
+    let sum = *q_1 + *q_2;
+    if disc == zero {
+        return Ok([QuadExt::rational(sum, zero); 2]);
+    }
+    Ok([
+        QuadExt::new(sum, T::from(-1), disc),
+        QuadExt::new(sum, T::from(1), disc),
+    ])
+}
+
+/// The spread of a regular polygon, see [`regular_polygon_spread`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegularSpread<T> {
+    /// The spread is rational.
+    Rational(T),
+    /// The spread lies in a quadratic extension of the rationals.
+    Quadratic(QuadExt<T>),
+}
+
+/// The function `regular_polygon_spread` calculates the spread of a regular
+/// `n`-gon: the spread between adjacent sides, which equals the spread
+/// subtended by a side at the center, `sin²(2π/n)`.
+///
+/// Arguments:
+///
+/// * `n`: The number of vertices.
+///
+/// Returns:
+///
+/// The spread exactly, as a rational for `n = 3, 4, 6, 8, 12` and as a
+/// surd `a + b·√d` for `n = 5, 10, 16, 20, 24`. It returns
+/// `MathError::InvalidInput` if `n < 3` and `MathError::NotRepresentable`
+/// for every other `n`, whose spreads have degree three or more over the
+/// rationals.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::quadext::QuadExt;
+/// use rat_trig_rs::trigonom::{regular_polygon_spread, RegularSpread};
+/// let r = Rational32::new;
+/// assert_eq!(regular_polygon_spread(6), Ok(RegularSpread::Rational(r(3, 4))));
+/// assert_eq!(
+///     regular_polygon_spread(5),
+///     Ok(RegularSpread::Quadratic(QuadExt::new(r(5, 8), r(1, 8), r(5, 1))))
+/// );
+/// assert!(regular_polygon_spread::<Rational32>(7).is_err());
+/// ```
+pub fn regular_polygon_spread<T>(n: u32) -> Result<RegularSpread<T>, MathError>
+where
+    T: Div<Output = T> + From<i32>,
+{
+    let frac = |a: i32, b: i32| T::from(a) / T::from(b);
+    let surd = |a: i32, b: i32, den: i32, d: i32| {
+        RegularSpread::Quadratic(QuadExt::new(frac(a, den), frac(b, den), T::from(d)))
+    };
+    match n {
+        0..=2 => Err(degenerate(
+            targets::TRIGONOM,
+            "regular_polygon_spread",
+            MathError::InvalidInput,
+            &n,
+        )),
+        3 | 6 => Ok(RegularSpread::Rational(frac(3, 4))),
+        4 => Ok(RegularSpread::Rational(T::from(1))),
+        8 => Ok(RegularSpread::Rational(frac(1, 2))),
+        12 => Ok(RegularSpread::Rational(frac(1, 4))),
+        5 => Ok(surd(5, 1, 8, 5)),
+        10 => Ok(surd(5, -1, 8, 5)),
+        16 => Ok(surd(2, -1, 4, 2)),
+        20 => Ok(surd(3, -1, 8, 5)),
+        24 => Ok(surd(2, -1, 4, 3)),
+        _ => Err(degenerate(
+            targets::TRIGONOM,
+            "regular_polygon_spread",
+            MathError::NotRepresentable,
+            &n,
+        )),
+    }
+}
+
+/// The function `quadrance` calculates the quadrance of a vector, i.e. the sum of the squares of
+/// its components.
+///
+/// Arguments:
+///
+/// * `v`: A tuple `(x, y)` representing the vector.
+///
+/// Returns:
+///
+/// The quadrance `x² + y²` of the vector.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::trigonom::quadrance;
+/// assert_eq!(quadrance(&(3, 4)), 25);
+/// ```
+#[inline]
+pub fn quadrance<T>(v: &(T, T)) -> T
+where
+    T: core::marker::Copy + Add<Output = T> + Mul<Output = T>,
+{
+    v.0 * v.0 + v.1 * v.1
+}
+
+/// The function `quadrance3d` calculates the quadrance of a vector in three dimensions.
+///
+/// Arguments:
+///
+/// * `v`: A tuple `(x, y, z)` representing the vector.
+///
+/// Returns:
+///
+/// The quadrance `x² + y² + z²` of the vector.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::trigonom::quadrance3d;
+/// assert_eq!(quadrance3d(&(1, 2, 2)), 9);
+/// ```
+#[inline]
+pub fn quadrance3d<T>(v: &(T, T, T)) -> T
+where
+    T: core::marker::Copy + Add<Output = T> + Mul<Output = T>,
+{
+    v.0 * v.0 + v.1 * v.1 + v.2 * v.2
+}
+
+/// The function `solid_spread` calculates the solid spread of the trihedral
+/// corner spanned by three vectors, `det(v₁, v₂, v₃)² / (Q(v₁)·Q(v₂)·Q(v₃))`,
+/// the rational analogue of a solid angle.
+///
+/// Arguments:
+///
+/// * `v_1`, `v_2`, `v_3`: The three vectors from the corner.
+///
+/// Returns:
+///
+/// The solid spread, between 0 (coplanar vectors) and 1 (mutually
+/// perpendicular vectors), or `MathError::NullVector` if any vector is zero.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::trigonom::solid_spread;
+/// let r = Rational32::from;
+/// // the corner of a cube
+/// let s = solid_spread(&(r(1), r(0), r(0)), &(r(0), r(1), r(0)), &(r(0), r(0), r(1)));
+/// assert_eq!(s, Ok(r(1)));
+/// // a corner of the regular tetrahedron
+/// let s = solid_spread(&(r(1), r(1), r(0)), &(r(1), r(0), r(1)), &(r(0), r(1), r(1)));
+/// assert_eq!(s, Ok(Rational32::new(1, 2)));
+/// ```
+pub fn solid_spread<T>(v_1: &(T, T, T), v_2: &(T, T, T), v_3: &(T, T, T)) -> Result<T, MathError>
+where
+    T: core::marker::Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>
+        + Loggable,
+{
+    let denom = quadrance3d(v_1) * quadrance3d(v_2) * quadrance3d(v_3);
+    if denom == T::from(0) {
+        return Err(degenerate(
+            targets::TRIGONOM,
+            "solid_spread",
+            MathError::NullVector,
+            &(v_1, v_2, v_3),
+        ));
+    }
+    let det = v_1.0 * (v_2.1 * v_3.2 - v_2.2 * v_3.1) - v_1.1 * (v_2.0 * v_3.2 - v_2.2 * v_3.0)
+        + v_1.2 * (v_2.0 * v_3.1 - v_2.1 * v_3.0);
+    Ok(det * det / denom)
+}
+
+/// The function `area_from_lengths` calculates the area of a triangle from
+/// its side lengths, by squaring them into quadrances and applying
+/// Archimedes' formula (Heron's formula in disguise).
+///
+/// Arguments:
+///
+/// * `a`, `b`, `c`: The side lengths.
+///
+/// Returns:
+///
+/// The area `√A / 4`, where `A = archimedes(a², b², c²)` is the quadrea, or
+/// `MathError::InvalidInput` if a length is negative or not finite, or the
+/// lengths violate the triangle inequality. A flat triangle has area zero.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::error::MathError;
+/// use rat_trig_rs::trigonom::area_from_lengths;
+/// assert_eq!(area_from_lengths(3.0, 4.0, 5.0), Ok(6.0));
+/// assert_eq!(area_from_lengths(1.0, 2.0, 3.0), Ok(0.0));
+/// assert_eq!(area_from_lengths(1.0, 2.0, 4.0), Err(MathError::InvalidInput));
+/// ```
+#[cfg(feature = "std")]
+pub fn area_from_lengths(a: f64, b: f64, c: f64) -> Result<f64, MathError> {
+    let valid = |x: f64| x.is_finite() && x >= 0.0;
+    if !(valid(a) && valid(b) && valid(c)) || a > b + c || b > a + c || c > a + b {
+        return Err(degenerate(
+            targets::TRIGONOM,
+            "area_from_lengths",
+            MathError::InvalidInput,
+            &(a, b, c),
+        ));
+    }
+    // rounding can push the quadrea of a flat triangle slightly below zero
+    let quadrea = archimedes(&(a * a), &(b * b), &(c * c)).max(0.0);
+    Ok(quadrea.sqrt() / 4.0)
+}
+
+/// The squared tangent lengths of a triangle, see [`tangent_quadrances`].
+///
+/// With side lengths `a₁, a₂, a₃` opposite the vertices and semiperimeter
+/// `s`, the incircle touches the sides at distance `s − aᵢ` from vertex `i`.
+/// The excircle opposite vertex `j` is at distance `s` from vertex `j` and at
+/// distance `s − aₖ` from vertex `i`, where `k` is the remaining index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TangentQuadrances<T> {
+    /// `(s − aᵢ)²`, from vertex `i` to the incircle.
+    pub incircle: [T; 3],
+    /// `s²`, from each vertex to the excircle opposite it.
+    pub semiperimeter: T,
+}
+
+impl<T: Copy> TangentQuadrances<T> {
+    /// The squared tangent lengths from the three vertices to the excircle
+    /// opposite vertex `j` (`0`, `1` or `2`).
+    ///
+    /// Panics if `j > 2`.
+    #[inline]
+    pub fn excircle(&self, j: usize) -> [T; 3] {
+        assert!(j < 3, "the vertex index must be 0, 1 or 2");
+        let mut t = [self.semiperimeter; 3];
+        t[(j + 1) % 3] = self.incircle[(j + 2) % 3];
+        t[(j + 2) % 3] = self.incircle[(j + 1) % 3];
+        t
+    }
+}
+
+/// The function `tangent_quadrances` calculates the squared tangent lengths
+/// from the vertices of a triangle to its incircle and excircles.
+///
+/// These are rational in the side lengths, but in general not in the
+/// quadrances: `(s − a₁)²` involves products such as `a₂·a₃ = √(q₂·q₃)`. The
+/// caller therefore supplies the square root, which decides whether the
+/// result is exact.
+///
+/// Arguments:
+///
+/// * `q_1`, `q_2`, `q_3`: The quadrances of the sides opposite the vertices.
+/// * `sqrt`: The square root in `T`, or `None` where it does not exist.
+///
+/// Returns:
+///
+/// The tangent quadrances, or `MathError::NegativeQuadrance` if a quadrance
+/// is negative, `MathError::DegenerateTriangle` if the quadrea is zero, and
+/// `MathError::NotRepresentable` if `sqrt` fails.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::error::MathError;
+/// use rat_trig_rs::trigonom::tangent_quadrances;
+/// let r = Rational32::from;
+/// let sqrt = |q: Rational32| (0..100).map(r).find(|&n| n * n == q);
+/// // the 3-4-5 triangle: s = 6, tangent lengths 3, 2 and 1
+/// let t = tangent_quadrances(&r(9), &r(16), &r(25), sqrt).unwrap();
+/// assert_eq!(t.incircle, [r(9), r(4), r(1)]);
+/// assert_eq!(t.excircle(2), [r(4), r(9), r(36)]);
+/// assert_eq!(
+///     tangent_quadrances(&r(2), &r(2), &r(4), sqrt),
+///     Err(MathError::NotRepresentable)
+/// );
+/// ```
+pub fn tangent_quadrances<T, F>(
+    q_1: &T,
+    q_2: &T,
+    q_3: &T,
+    sqrt: F,
+) -> Result<TangentQuadrances<T>, MathError>
+where
+    T: core::marker::Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>
+        + Loggable,
+    F: Fn(T) -> Option<T>,
+{
+    let zero = T::from(0);
+    if *q_1 < zero || *q_2 < zero || *q_3 < zero {
+        return Err(degenerate(
+            targets::TRIGONOM,
+            "tangent_quadrances",
+            MathError::NegativeQuadrance,
+            &(q_1, q_2, q_3),
+        ));
+    }
+    if archimedes(q_1, q_2, q_3) == zero {
+        return Err(degenerate(
+            targets::TRIGONOM,
+            "tangent_quadrances",
+            MathError::DegenerateTriangle,
+            &(q_1, q_2, q_3),
+        ));
+    }
+    let root = |q: &T| {
+        sqrt(*q).ok_or_else(|| {
+            degenerate(
+                targets::TRIGONOM,
+                "tangent_quadrances",
+                MathError::NotRepresentable,
+                q,
+            )
+        })
+    };
+    let (a_1, a_2, a_3) = (root(q_1)?, root(q_2)?, root(q_3)?);
+    let s = (a_1 + a_2 + a_3) / T::from(2);
+    let sq = |x: T| x * x;
+    Ok(TangentQuadrances {
+        incircle: [sq(s - a_1), sq(s - a_2), sq(s - a_3)],
+        semiperimeter: sq(s),
+    })
+}
+
+/// The function `in_quadrance` calculates the quadrance of the inradius,
+/// which is the quadrance from the incenter to each side.
+///
+/// With quadrea `A` and semiperimeter `s`, the inradius is `K / s` where
+/// `16·K² = A`, so the in-quadrance is `A / (16·s²)`. Like `s²` itself this
+/// needs the side lengths, which `sqrt` must supply.
+///
+/// Arguments:
+///
+/// * `q_1`, `q_2`, `q_3`: The quadrances of the sides.
+/// * `sqrt`: A square root that returns `None` when its argument has no
+///   exact root in `T`.
+///
+/// Returns:
+///
+/// The in-quadrance, `MathError::NegativeQuadrance` or
+/// `MathError::DegenerateTriangle` as for [`tangent_quadrances`], or
+/// `MathError::InvalidInput` if a side length is not exact.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::error::MathError;
+/// use rat_trig_rs::trigonom::in_quadrance;
+/// let r = Rational32::from;
+/// let sqrt = |q: Rational32| (0..100).map(r).find(|&n| n * n == q);
+/// // the 5-12-13 triangle has inradius 2
+/// assert_eq!(in_quadrance(&r(25), &r(144), &r(169), sqrt), Ok(r(4)));
+/// assert_eq!(in_quadrance(&r(1), &r(1), &r(2), sqrt), Err(MathError::InvalidInput));
+/// ```
+pub fn in_quadrance<T, F>(q_1: &T, q_2: &T, q_3: &T, sqrt: F) -> Result<T, MathError>
+where
+    T: core::marker::Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>
+        + Loggable,
+    F: Fn(T) -> Option<T>,
+{
+    // `tangent_quadrances` has already logged the rejection
+    let tangents = tangent_quadrances(q_1, q_2, q_3, sqrt).map_err(|kind| match kind {
+        MathError::NotRepresentable => MathError::InvalidInput,
+        kind => kind,
+    })?;
+    Ok(archimedes(q_1, q_2, q_3) / (T::from(16) * tangents.semiperimeter))
+}
+
+/// The function `incenter_quadrances` calculates the quadrances from the
+/// incenter to the vertices of a triangle.
+///
+/// The incenter, a vertex and the nearby touching point of the incircle form
+/// a right triangle, so the quadrance to vertex `i` is `(s − aᵢ)² + r²` by
+/// Pythagoras' theorem, where `r²` is the [`in_quadrance`].
+///
+/// Arguments:
+///
+/// * `q_1`, `q_2`, `q_3`: The quadrances of the sides.
+/// * `sqrt`: A square root that returns `None` when its argument has no
+///   exact root in `T`.
+///
+/// Returns:
+///
+/// The quadrances to the vertices opposite the sides `q_1`, `q_2` and
+/// `q_3`, with the errors of [`in_quadrance`].
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::trigonom::incenter_quadrances;
+/// let r = Rational32::from;
+/// let sqrt = |q: Rational32| (0..100).map(r).find(|&n| n * n == q);
+/// // the 3-4-5 triangle at (0, 3), (4, 0), (0, 0) has its incenter at (1, 1)
+/// assert_eq!(incenter_quadrances(&r(16), &r(9), &r(25), sqrt), Ok([r(5), r(10), r(2)]));
+/// ```
+pub fn incenter_quadrances<T, F>(q_1: &T, q_2: &T, q_3: &T, sqrt: F) -> Result<[T; 3], MathError>
+where
+    T: core::marker::Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + From<i32>
+        + Loggable,
+    F: Fn(T) -> Option<T>,
+{
+    // `tangent_quadrances` has already logged the rejection
+    let tangents = tangent_quadrances(q_1, q_2, q_3, sqrt).map_err(|kind| match kind {
+        MathError::NotRepresentable => MathError::InvalidInput,
+        kind => kind,
+    })?;
+    let r_sq = archimedes(q_1, q_2, q_3) / (T::from(16) * tangents.semiperimeter);
+    Ok(tangents.incircle.map(|t| t + r_sq))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+    // use fractions::Fraction;
+
+    #[test]
+    fn test_archimedes2() {
+        let q_1: i64 = 1;
+        let q_2: i64 = 2;
+        let q_3: i64 = 3;
+        assert_eq!(archimedes(&q_1, &q_2, &q_3), 8);
+    }
+
+    #[test]
+    fn test_solid_spread() {
+        type Q = Ratio<i32>;
+        let v = |x: i32, y: i32, z: i32| (Q::from(x), Q::from(y), Q::from(z));
+        // det = 3, quadrances 1, 2, 9
+        assert_eq!(
+            solid_spread(&v(1, 0, 0), &v(1, 1, 0), &v(0, 0, 3)),
+            Ok(Q::new(1, 2))
+        );
+        // invariant under order and scaling
+        assert_eq!(
+            solid_spread(&v(0, 0, -1), &v(2, 2, 0), &v(1, 0, 0)),
+            Ok(Q::new(1, 2))
+        );
+        assert_eq!(
+            solid_spread(&v(1, 0, 0), &v(0, 1, 0), &v(1, 1, 0)),
+            Ok(Q::from(0))
+        );
+        assert_eq!(
+            solid_spread(&v(1, 0, 0), &v(0, 0, 0), &v(0, 0, 1)),
+            Err(MathError::NullVector)
+        );
+    }
+
+    #[test]
+    fn test_ptolemy_and_quadrilateral_quadrea() {
+        // the cyclic quadrilateral (5, 0), (3, 4), (-4, 3), (0, -5)
+        let p = [(5, 0), (3, 4), (-4, 3), (0, -5)];
+        let q = |i: usize, j: usize| quadrance(&(p[j].0 - p[i].0, p[j].1 - p[i].1));
+        let qs = [q(0, 1), q(1, 2), q(2, 3), q(3, 0), q(0, 2), q(1, 3)];
+        assert!(satisfies_ptolemy(
+            &qs[0], &qs[1], &qs[2], &qs[3], &qs[4], &qs[5]
+        ));
+        let quadrea = quadrilateral_quadrea(&qs[0], &qs[1], &qs[2], &qs[3], &qs[4], &qs[5]);
+        assert_eq!(quadrea, 16 * 45 * 45);
+        let b = brahmagupta(&qs[0], &qs[1], &qs[2], &qs[3]);
+        assert_eq!(quadrea, b.a + b.b * 2000);
+        // a convex quadrilateral that is not cyclic: area 6
+        let p = [(0, 0), (3, 0), (2, 2), (0, 3)];
+        let q = |i: usize, j: usize| quadrance(&(p[j].0 - p[i].0, p[j].1 - p[i].1));
+        let qs = [q(0, 1), q(1, 2), q(2, 3), q(3, 0), q(0, 2), q(1, 3)];
+        assert!(!satisfies_ptolemy(
+            &qs[0], &qs[1], &qs[2], &qs[3], &qs[4], &qs[5]
+        ));
+        let quadrea = quadrilateral_quadrea(&qs[0], &qs[1], &qs[2], &qs[3], &qs[4], &qs[5]);
+        assert_eq!(quadrea, 16 * 36);
+    }
+
+    #[test]
+    fn test_quadrume() {
+        // the regular tetrahedron of unit quadrance: V² = 1/72
+        assert_eq!(quadrume(&1, &1, &1, &1, &1, &1), 4);
+        // a triangle plus its centroid is flat
+        let q = Ratio::<i32>::new(1, 3);
+        let one = Ratio::<i32>::from(1);
+        assert_eq!(quadrume(&q, &q, &q, &one, &one, &one), Ratio::from(0));
+    }
+
+    #[test]
+    fn test_archimedes3() {
+        let q_1 = 1.0;
+        let q_2 = 2.0;
+        let q_3 = 3.0;
+        assert_eq!(archimedes(&q_1, &q_2, &q_3), 8.0);
+    }
+
+    #[test]
+    fn test_archimedes() {
+        let q_1 = Ratio::<i32>::new(1, 2);
+        let q_2 = Ratio::<i32>::new(1, 4);
+        let q_3 = Ratio::<i32>::new(1, 6);
+        assert_eq!(archimedes(&q_1, &q_2, &q_3), Ratio::<i32>::new(23, 144));
+    }
+
+    #[test]
+    fn test_quadrance() {
+        assert_eq!(quadrance(&(3, -4)), 25);
+        assert_eq!(quadrance(&(0.5, 1.5)), 2.5);
+        assert_eq!(
+            quadrance(&(Ratio::<i32>::new(1, 2), Ratio::<i32>::new(1, 3))),
+            Ratio::<i32>::new(13, 36)
+        );
+        assert_eq!(quadrance3d(&(2, 3, 6)), 49);
+    }
+
+    #[test]
+    fn test_quadrea_spread() {
+        type Q = Ratio<i32>;
+        // the triangle (0, 0), (3, 1), (1, 2)
+        let (q_1, q_2, q_3) = (Q::from(5), Q::from(5), Q::from(10));
+        let quadrea = archimedes(&q_1, &q_2, &q_3);
+        for (qa, qb, qc) in [(q_1, q_2, q_3), (q_2, q_3, q_1), (q_3, q_1, q_2)] {
+            let s = spread_from_quadrea(&quadrea, &qa, &qb).unwrap();
+            assert_eq!(quadrea_from_qqs(&qa, &qb, &s), quadrea);
+            assert_eq!(archimedes(&qa, &qb, &qc), quadrea);
+        }
+        assert_eq!(spread_from_quadrea(&quadrea, &q_1, &q_2), Ok(Q::from(1)));
+        assert_eq!(quadrea_from_qqs(&2.0, &3.0, &0.5), 12.0);
+    }
+
+    #[test]
+    fn test_regular_polygon_spread() {
+        for n in 3..=30 {
+            let expected = (2.0 * core::f64::consts::PI / n as f64).sin().powi(2);
+            match regular_polygon_spread::<f64>(n) {
+                Ok(RegularSpread::Rational(s)) => assert!((s - expected).abs() < 1e-12),
+                Ok(RegularSpread::Quadratic(x)) => {
+                    assert!((x.a + x.b * x.d.sqrt() - expected).abs() < 1e-12)
+                }
+                Err(err) => assert_eq!(err, MathError::NotRepresentable),
+            }
+        }
+        assert_eq!(
+            regular_polygon_spread::<Ratio<i32>>(2),
+            Err(MathError::InvalidInput)
+        );
+        let rational = (3..=30)
+            .filter(|&n| {
+                matches!(
+                    regular_polygon_spread::<f64>(n),
+                    Ok(RegularSpread::Rational(_))
+                )
+            })
+            .count();
+        assert_eq!(rational, 5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_area_from_lengths() {
+        let area = area_from_lengths(2.0, 2.0, 2.0).unwrap();
+        assert!((area - 3.0_f64.sqrt()).abs() < 1e-12);
+        assert_eq!(area_from_lengths(0.0, 0.0, 0.0), Ok(0.0));
+        assert!(area_from_lengths(0.1, 0.2, 0.3).unwrap() < 1e-9);
+        assert_eq!(
+            area_from_lengths(-1.0, 1.0, 1.0),
+            Err(MathError::InvalidInput)
+        );
+        assert_eq!(
+            area_from_lengths(f64::NAN, 1.0, 1.0),
+            Err(MathError::InvalidInput)
+        );
+        assert_eq!(
+            area_from_lengths(f64::INFINITY, 1.0, 1.0),
+            Err(MathError::InvalidInput)
+        );
+    }
+
+    // #[test]
+    // fn test_archimedes4() {
+    //     let q_1 = Fraction::<i64>::new(1, 2);
+    //     let q_2 = Fraction::<i64>::new(1, 4);
+    //     let q_3 = Fraction::<i64>::new(1, 6);
+    //     assert_eq!(archimedes(&q_1, &q_2, &q_3), Fraction::<i64>::new(23, 144));
+    // }
+
+    #[test]
+    #[should_panic(expected = "the vertex index must be 0, 1 or 2")]
+    fn test_excircle_index() {
+        let t = TangentQuadrances {
+            incircle: [9, 9, 4],
+            semiperimeter: 64,
+        };
+        t.excircle(3);
+    }
+
+    #[test]
+    fn test_tangent_quadrances() {
+        type Q = Ratio<i32>;
+        let sqrt = |q: Q| (0..100).map(Q::from).find(|&n| n * n == q);
+        // the 5-5-6 triangle: s = 8, tangent lengths 3, 3 and 2
+        let (q_1, q_2, q_3) = (Q::from(25), Q::from(25), Q::from(36));
+        let t = tangent_quadrances(&q_1, &q_2, &q_3, sqrt).unwrap();
+        assert_eq!(t.incircle, [Q::from(9), Q::from(9), Q::from(4)]);
+        assert_eq!(t.semiperimeter, Q::from(64));
+        assert_eq!(t.excircle(0), [Q::from(64), Q::from(4), Q::from(9)]);
+        assert_eq!(t.excircle(1), [Q::from(4), Q::from(64), Q::from(9)]);
+        // irrational side lengths are fine in floating point
+        let f = tangent_quadrances(&3.0, &4.0, &5.0, |q: f64| Some(q.sqrt())).unwrap();
+        let s = (3.0_f64.sqrt() + 2.0 + 5.0_f64.sqrt()) / 2.0;
+        assert!((f.semiperimeter - s * s).abs() < 1e-12);
+        assert!((f.incircle[1] - (s - 2.0) * (s - 2.0)).abs() < 1e-12);
+        assert_eq!(
+            tangent_quadrances(&Q::from(1), &Q::from(4), &Q::from(9), sqrt),
+            Err(MathError::DegenerateTriangle)
+        );
+        assert_eq!(
+            tangent_quadrances(&Q::from(-1), &Q::from(4), &Q::from(4), sqrt),
+            Err(MathError::NegativeQuadrance)
+        );
+    }
+
+    #[test]
+    fn test_brahmagupta() {
+        // a cyclic quadrilateral reduces to Archimedes' formula when a side
+        // collapses
+        let a = brahmagupta(&5, &5, &10, &0);
+        assert_eq!((a.a, a.d), (archimedes(&5, &5, &10), 0));
+        // the unit square, and the same vertices taken as a bowtie
+        let a = brahmagupta(&1, &1, &1, &1);
+        assert_eq!((a.a, a.b, a.d), (8, 8, 1));
+        let bowtie = brahmagupta(&1, &2, &1, &2).conjugate();
+        assert_eq!(bowtie.a + bowtie.b * 2, 0);
+    }
+
+    #[test]
+    fn test_line_intersection() {
+        type Q = Ratio<i32>;
+        let (l1, l2) = (
+            (Q::from(3), Q::from(-1), Q::from(2)),
+            (Q::from(1), Q::from(4), Q::from(-5)),
+        );
+        let (x, y) = line_intersection(&l1, &l2).unwrap();
+        assert_eq!(l1.0 * x + l1.1 * y + l1.2, Q::from(0));
+        assert_eq!(l2.0 * x + l2.1 * y + l2.2, Q::from(0));
+        assert_eq!((x, y), (Q::new(-3, 13), Q::new(17, 13)));
+        assert_eq!(safe_line_intersection(&l1, &l2), Ok((x, y)));
+        assert_eq!(line_intersection(&(2.0, 4.0, 1.0), &(1.0, 2.0, 0.0)), None);
+        assert_eq!(
+            safe_line_intersection(&(0, 2, 1), &(0, -4, -2)),
+            Err(MathError::InvalidInput)
+        );
+        assert_eq!(
+            safe_line_intersection(&(0, 2, 1), &(0, -4, 1)),
+            Err(MathError::NonIntersecting)
+        );
+        assert_eq!(
+            safe_line_intersection(&(1, 2, 3), &(0, 0, 0)),
+            Err(MathError::NullLine)
+        );
+    }
+
+    #[test]
+    fn test_line_from_points() {
+        type Q = Ratio<i32>;
+        let (p1, p2) = ((Q::new(1, 2), Q::from(3)), (Q::from(-2), Q::new(1, 3)));
+        let l = line_from_points(&p1, &p2).unwrap();
+        for p in [p1, p2] {
+            assert_eq!(l.0 * p.0 + l.1 * p.1 + l.2, Q::from(0));
+        }
+        // the line through two points meets another at the expected place
+        let l2 = line_from_points(&(0, 0), &(0, 5)).unwrap();
+        let l1 = line_from_points(&(-1, 1), &(2, 1)).unwrap();
+        assert_eq!(safe_line_intersection(&l1, &l2), Ok((0, 1)));
+        assert_eq!(line_from_points(&p1, &p1), Err(MathError::InvalidInput));
+    }
+
+    #[test]
+    fn test_midpoint() {
+        type Q = Ratio<i32>;
+        let (p1, p2) = ((Q::new(1, 3), Q::from(-1)), (Q::from(2), Q::new(1, 2)));
+        let m = midpoint(&p1, &p2);
+        assert_eq!(m, (Q::new(7, 6), Q::new(-1, 4)));
+        assert_eq!(affine_combination(&p1, &p2, Q::new(1, 2)), m);
+        assert_eq!(affine_combination(&p1, &p2, Q::from(0)), p1);
+        assert_eq!(affine_combination(&p1, &p2, Q::from(1)), p2);
+        assert_eq!(affine_combination(&(0, 0), &(1, 2), 3), (3, 6));
+        assert_eq!(midpoint(&(1.0, 2.0), &(2.0, 4.0)), (1.5, 3.0));
+    }
+
+    #[test]
+    fn test_cross_law_solve() {
+        type Q = Ratio<i32>;
+        let sqrt = |d: Q| (0..1000).map(Q::from).find(|&x| x * x == d);
+        // the triangle (0, 0), (3, 1), (1, 2) has q = 5, 5, 10 and a right
+        // spread between the two short sides
+        let [near, far] = cross_law_solve(&Q::from(5), &Q::from(5), &Q::from(1)).unwrap();
+        assert_eq!(near, far);
+        assert_eq!(near.try_rational(sqrt), Some(Q::from(10)));
+        // each root satisfies the cross law
+        let (q_1, q_2, s_3) = (Q::from(9), Q::from(4), Q::new(3, 4));
+        for root in cross_law_solve(&q_1, &q_2, &s_3).unwrap() {
+            let q_3 = root.try_rational(sqrt).unwrap();
+            let lhs = q_1 + q_2 - q_3;
+            assert_eq!(lhs * lhs, Q::from(4) * q_1 * q_2 * (Q::from(1) - s_3));
+        }
+        assert_eq!(
+            cross_law_solve(&Q::from(1), &Q::from(1), &Q::from(2)),
+            Err(MathError::InvalidInput)
+        );
+        // no triangle has a negative spread, even though D > 0
+        assert_eq!(
+            cross_law_solve(&Q::from(1), &Q::from(1), &Q::from(-1)),
+            Err(MathError::InvalidInput)
+        );
+        assert_eq!(
+            cross_law_solve(&Q::from(-1), &Q::from(1), &Q::from(0)),
+            Err(MathError::NegativeQuadrance)
+        );
+    }
+
+    #[test]
+    fn test_triple_spread() {
+        type Q = Ratio<i32>;
+        // the spreads of the triangle (0, 0), (3, 1), (1, 2)
+        let (s_1, s_2, s_3) = (Q::new(1, 2), Q::new(1, 2), Q::from(1));
+        assert!(satisfies_triple_spread(&s_1, &s_2, &s_3));
+        // a degenerate triangle has spreads (0, 0, 0), or (0, s, s)
+        assert!(satisfies_triple_spread(
+            &Q::from(0),
+            &Q::new(1, 3),
+            &Q::new(1, 3)
+        ));
+        // the identity holds outside [0, 1] too, e.g. for spreads in the red
+        // geometry, but those are not Euclidean triangles
+        assert_eq!(
+            triple_spread(&Q::from(4), &Q::from(4), &Q::from(0)),
+            Q::from(0)
+        );
+        assert!(!satisfies_triple_spread(
+            &Q::from(4),
+            &Q::from(4),
+            &Q::from(0)
+        ));
+        assert!(triple_spread(&0.3_f64, &0.4, &0.5).abs() > 1e-3);
+    }
+
+    #[test]
+    fn test_perpendicular_bisector() {
+        type Q = Ratio<i32>;
+        let (p1, p2) = ((Q::from(1), Q::new(1, 2)), (Q::from(-2), Q::from(3)));
+        let (a, b, c) = perpendicular_bisector(&p1, &p2).unwrap();
+        // the midpoint lies on it and the direction is perpendicular to p1p2
+        let m = midpoint(&p1, &p2);
+        assert_eq!(a * m.0 + b * m.1 + c, Q::from(0));
+        assert_eq!(a * (p2.1 - p1.1) - b * (p2.0 - p1.0), Q::from(0));
+        assert!(a * p2.0 + b * p2.1 + c > Q::from(0));
+        assert_eq!(
+            perpendicular_bisector(&p1, &p1),
+            Err(MathError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_bilines() {
+        type Q = Ratio<i32>;
+        let sqrt = |q: Q| (0..200).map(Q::from).find(|&n| n * n == q);
+        // 5x + 12y − 12 = 0 and y = 1 meet at (0, 1)
+        let (l1, l2) = (
+            (Q::from(5), Q::from(12), Q::from(-12)),
+            (Q::from(0), Q::from(1), Q::from(-1)),
+        );
+        let spread = |l: &(Q, Q, Q), m: &(Q, Q, Q)| {
+            let cross = l.0 * m.1 - m.0 * l.1;
+            cross * cross / ((l.0 * l.0 + l.1 * l.1) * (m.0 * m.0 + m.1 * m.1))
+        };
+        let [b1, b2] = bilines(&l1, &l2, sqrt).unwrap();
+        for b in [b1, b2] {
+            assert_eq!(b.1 + b.2, Q::from(0));
+            assert_eq!(spread(&b, &l1), spread(&b, &l2));
+        }
+        assert_eq!(b1.0 * b2.0 + b1.1 * b2.1, Q::from(0));
+        assert_eq!(
+            bilines(&l1, &(Q::from(0), Q::from(0), Q::from(1)), sqrt),
+            Err(MathError::NullLine)
+        );
+        assert_eq!(
+            bilines(&l2, &(Q::from(0), Q::from(2), Q::from(3)), sqrt),
+            Err(MathError::NonIntersecting)
+        );
+    }
+
+    #[test]
+    fn test_median_quadrances() {
+        let (m_1, m_2, m_3) = median_quadrances(&4.0, &4.0, &4.0);
+        assert_eq!((m_1, m_2, m_3), (3.0, 3.0, 3.0));
+        // a degenerate triangle with vertices 0, 1 and 3 on a line
+        let (m_1, m_2, m_3) = median_quadrances(&4.0, &9.0, &1.0);
+        assert_eq!((m_1, m_2, m_3), (4.0, 0.25, 6.25));
+    }
+
+    #[test]
+    fn test_altitude_quadrance() {
+        type Q = Ratio<i32>;
+        // the equilateral triangle of quadrance 4 has altitude √3
+        let q = Q::from(4);
+        let a = archimedes(&q, &q, &q);
+        assert_eq!(
+            altitude_quadrance(&q, &q, &q, &a),
+            Ok((Q::from(3), Q::from(3), Q::from(3)))
+        );
+        assert_eq!(
+            altitude_quadrance(&q, &q, &Q::from(0), &a),
+            Err(MathError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_in_quadrance() {
+        type Q = Ratio<i32>;
+        let sqrt = |q: Q| (0..100).map(Q::from).find(|&n| n * n == q);
+        // the 3-4-5 triangle scaled by 1/2 has inradius 1/2
+        let (q_1, q_2, q_3) = (Q::new(9, 4), Q::from(4), Q::new(25, 4));
+        let sqrt_q = |q: Q| {
+            let (n, d) = (sqrt(Q::from(*q.numer())), sqrt(Q::from(*q.denom())));
+            n.zip(d).map(|(n, d)| n / d)
+        };
+        assert_eq!(in_quadrance(&q_1, &q_2, &q_3, sqrt_q), Ok(Q::new(1, 4)));
+        assert_eq!(
+            incenter_quadrances(&q_1, &q_2, &q_3, sqrt_q),
+            Ok([Q::new(5, 2), Q::new(5, 4), Q::new(1, 2)])
+        );
+        assert_eq!(
+            in_quadrance(&Q::from(1), &Q::from(4), &Q::from(9), sqrt),
+            Err(MathError::DegenerateTriangle)
+        );
+        assert_eq!(
+            incenter_quadrances(&Q::from(4), &Q::from(4), &Q::from(5), sqrt),
+            Err(MathError::InvalidInput)
+        );
+    }
 }
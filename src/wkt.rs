@@ -0,0 +1,362 @@
+//! Well-known text (WKT) parsing and serialization.
+//!
+//! Points are written as `POINT (x y)`, segments as two-point
+//! `LINESTRING (x1 y1, x2 y2)`, and triangles and polygons as single-ring
+//! `POLYGON ((x1 y1, ..., x1 y1))` with the closing vertex repeated.
+//!
+//! Coordinates are parsed with `T::from_str` first. If that fails, a plain
+//! decimal such as `-12.375` is converted exactly as `-12375 / 1000`, so GIS
+//! data can be read straight into rational coordinates. Coordinates are
+//! written with `T`'s `Display` implementation; for rational types that
+//! produces fractions like `3/2`, which this module reads back but other WKT
+//! consumers may not.
+//!
+//! Malformed text yields `MathError::InvalidInput`; a decimal the coordinate
+//! type cannot hold exactly, such as `1.5` for an integer type, yields
+//! `MathError::NotRepresentable`.
+use crate::error::MathError;
+use crate::geometry::{Point2D, Triangle2D};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::From;
+use core::fmt::Display;
+use core::ops::{Add, Div, Mul, Sub};
+use core::str::FromStr;
+
+/// Parses a single coordinate, falling back to exact decimal conversion.
+pub(crate) fn parse_number<T>(token: &str) -> Result<T, MathError>
+where
+    T: Copy
+        + FromStr
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    if let Ok(value) = token.parse::<T>() {
+        return Ok(value);
+    }
+    let (negative, digits) = match token.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, token.strip_prefix('+').unwrap_or(token)),
+    };
+    let (int_part, frac_part) = digits.split_once('.').ok_or(MathError::InvalidInput)?;
+    if int_part.is_empty() && frac_part.is_empty() {
+        return Err(MathError::InvalidInput);
+    }
+    // accumulate in u64 so long decimals that reduce to small fractions do
+    // not overflow a narrow `T`
+    let frac_part = frac_part.trim_end_matches('0');
+    let mut mantissa: u64 = 0;
+    for ch in int_part.chars().chain(frac_part.chars()) {
+        let digit = ch.to_digit(10).ok_or(MathError::InvalidInput)?;
+        mantissa = mantissa
+            .checked_mul(10)
+            .and_then(|m| m.checked_add(u64::from(digit)))
+            .ok_or(MathError::NotRepresentable)?;
+    }
+    let scale = u32::try_from(frac_part.len())
+        .ok()
+        .and_then(|len| 10u64.checked_pow(len))
+        .ok_or(MathError::NotRepresentable)?;
+    let divisor = gcd(mantissa, scale);
+    // small values go through `From<i32>`; wider ones through `T`'s own
+    // integer parsing, which reports overflow instead of panicking
+    let to_t = |n: u64| match i32::try_from(n / divisor) {
+        Ok(n) => Ok(T::from(n)),
+        Err(_) => format!("{}", n / divisor)
+            .parse::<T>()
+            .map_err(|_| MathError::NotRepresentable),
+    };
+    let (mantissa, scale) = (to_t(mantissa)?, to_t(scale)?);
+    let value = mantissa / scale;
+    // reject types that cannot represent the decimal exactly (integers)
+    if value * scale != mantissa {
+        return Err(MathError::NotRepresentable);
+    }
+    Ok(if negative { T::from(0) - value } else { value })
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Strips `KEYWORD` and one level of parentheses, case-insensitively.
+fn strip_tagged<'a>(text: &'a str, keyword: &str) -> Result<&'a str, MathError> {
+    let text = text.trim();
+    let head = text.get(..keyword.len()).ok_or(MathError::InvalidInput)?;
+    if !head.eq_ignore_ascii_case(keyword) {
+        return Err(MathError::InvalidInput);
+    }
+    strip_parens(&text[keyword.len()..])
+}
+
+fn strip_parens(text: &str) -> Result<&str, MathError> {
+    text.trim()
+        .strip_prefix('(')
+        .and_then(|rest| rest.strip_suffix(')'))
+        .ok_or(MathError::InvalidInput)
+}
+
+fn parse_coords<T>(text: &str) -> Result<Vec<Point2D<T>>, MathError>
+where
+    T: Copy
+        + FromStr
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    text.split(',')
+        .map(|pair| {
+            let mut tokens = pair.split_whitespace();
+            match (tokens.next(), tokens.next(), tokens.next()) {
+                (Some(x), Some(y), None) => Ok(Point2D::new(parse_number(x)?, parse_number(y)?)),
+                _ => Err(MathError::InvalidInput),
+            }
+        })
+        .collect()
+}
+
+fn format_coords<T: Display>(points: &[Point2D<T>]) -> String {
+    let coords: Vec<String> = points.iter().map(|p| format!("{} {}", p.x, p.y)).collect();
+    coords.join(", ")
+}
+
+/// Parses a single-ring polygon, returning the ring without the repeated
+/// closing vertex.
+fn parse_ring<T>(text: &str) -> Result<Vec<Point2D<T>>, MathError>
+where
+    T: Copy
+        + FromStr
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    let mut ring = parse_coords(strip_parens(strip_tagged(text, "POLYGON")?)?)?;
+    if ring.len() < 4 || ring.first() != ring.last() {
+        return Err(MathError::InvalidInput);
+    }
+    ring.pop();
+    Ok(ring)
+}
+
+/// The function `point_to_wkt` writes a point as `POINT (x y)`.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::geometry::Point2D;
+/// use rat_trig_rs::wkt::point_to_wkt;
+/// assert_eq!(point_to_wkt(&Point2D::new(3, -4)), "POINT (3 -4)");
+/// ```
+pub fn point_to_wkt<T: Display>(p: &Point2D<T>) -> String {
+    format!("POINT ({} {})", p.x, p.y)
+}
+
+/// The function `point_from_wkt` parses `POINT (x y)`.
+///
+/// Returns `MathError::InvalidInput` on malformed text.
+///
+/// Example:
+///
+/// ```rust
+/// use num_rational::Rational32;
+/// use rat_trig_rs::geometry::Point2D;
+/// use rat_trig_rs::wkt::point_from_wkt;
+/// let p = point_from_wkt::<Rational32>("POINT (1.25 -3)").unwrap();
+/// assert_eq!(p, Point2D::new(Rational32::new(5, 4), Rational32::from(-3)));
+/// ```
+pub fn point_from_wkt<T>(text: &str) -> Result<Point2D<T>, MathError>
+where
+    T: Copy
+        + FromStr
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    match parse_coords(strip_tagged(text, "POINT")?)?.as_slice() {
+        [p] => Ok(*p),
+        _ => Err(MathError::InvalidInput),
+    }
+}
+
+/// The function `segment_to_wkt` writes a segment as a two-point
+/// `LINESTRING (x1 y1, x2 y2)`.
+pub fn segment_to_wkt<T: Display>(p_1: &Point2D<T>, p_2: &Point2D<T>) -> String {
+    format!("LINESTRING ({} {}, {} {})", p_1.x, p_1.y, p_2.x, p_2.y)
+}
+
+/// The function `segment_from_wkt` parses a two-point `LINESTRING`.
+///
+/// Returns `MathError::InvalidInput` on malformed text or a different number
+/// of points.
+pub fn segment_from_wkt<T>(text: &str) -> Result<(Point2D<T>, Point2D<T>), MathError>
+where
+    T: Copy
+        + FromStr
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    match parse_coords(strip_tagged(text, "LINESTRING")?)?.as_slice() {
+        [p_1, p_2] => Ok((*p_1, *p_2)),
+        _ => Err(MathError::InvalidInput),
+    }
+}
+
+/// The function `triangle_to_wkt` writes a triangle as a closed `POLYGON`.
+///
+/// Example:
+///
+/// ```rust
+/// use rat_trig_rs::geometry::{Point2D, Triangle2D};
+/// use rat_trig_rs::wkt::triangle_to_wkt;
+/// let tri = Triangle2D::new(Point2D::new(0, 0), Point2D::new(4, 0), Point2D::new(0, 3));
+/// assert_eq!(triangle_to_wkt(&tri), "POLYGON ((0 0, 4 0, 0 3, 0 0))");
+/// ```
+pub fn triangle_to_wkt<T: Display + Copy>(tri: &Triangle2D<T>) -> String {
+    polygon_to_wkt(&[tri.p1, tri.p2, tri.p3])
+}
+
+/// The function `triangle_from_wkt` parses a closed `POLYGON` with exactly
+/// three distinct vertices.
+///
+/// Returns `MathError::InvalidInput` on malformed text.
+pub fn triangle_from_wkt<T>(text: &str) -> Result<Triangle2D<T>, MathError>
+where
+    T: Copy
+        + FromStr
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    match parse_ring(text)?.as_slice() {
+        [p_1, p_2, p_3] => Ok(Triangle2D::new(*p_1, *p_2, *p_3)),
+        _ => Err(MathError::InvalidInput),
+    }
+}
+
+/// The function `polygon_to_wkt` writes the vertices of a polygon as a
+/// closed single-ring `POLYGON`, repeating the first vertex at the end.
+pub fn polygon_to_wkt<T: Display + Copy>(vertices: &[Point2D<T>]) -> String {
+    let mut ring = vertices.to_vec();
+    if let Some(first) = vertices.first() {
+        ring.push(*first);
+    }
+    format!("POLYGON (({}))", format_coords(&ring))
+}
+
+/// The function `polygon_from_wkt` parses a closed single-ring `POLYGON`.
+///
+/// Returns:
+///
+/// The vertices without the repeated closing vertex, or
+/// `MathError::InvalidInput` on malformed text, an unclosed ring, or a ring
+/// with fewer than three vertices. Polygons with holes are not supported.
+pub fn polygon_from_wkt<T>(text: &str) -> Result<Vec<Point2D<T>>, MathError>
+where
+    T: Copy
+        + FromStr
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialEq
+        + From<i32>,
+{
+    parse_ring(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+
+    type Q = Ratio<i32>;
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number::<Q>("3/4"), Ok(Q::new(3, 4)));
+        assert_eq!(parse_number::<Q>("-0.125"), Ok(Q::new(-1, 8)));
+        assert_eq!(parse_number::<Q>(".5"), Ok(Q::new(1, 2)));
+        assert_eq!(parse_number::<f64>("2.5"), Ok(2.5));
+        assert_eq!(parse_number::<i32>("7"), Ok(7));
+        assert_eq!(parse_number::<i32>("1.5"), Err(MathError::NotRepresentable));
+        assert_eq!(parse_number::<Q>("x"), Err(MathError::InvalidInput));
+        // long decimals reduce before they reach `T`
+        assert_eq!(
+            parse_number::<Q>("37.77492950"),
+            Ok(Q::new(75549859, 2000000))
+        );
+        assert_eq!(parse_number::<i32>("4.000000000000"), Ok(4));
+        // wide types aren't limited to what fits in an i32
+        assert_eq!(parse_number::<i64>("12345678901.0"), Ok(12345678901));
+        assert_eq!(
+            parse_number::<i64>("3.14159265358979"),
+            Err(MathError::NotRepresentable)
+        );
+        assert_eq!(
+            parse_number::<Q>("3.14159265358979"),
+            Err(MathError::NotRepresentable)
+        );
+        assert_eq!(
+            parse_number::<Q>("123456789012345678901.5"),
+            Err(MathError::NotRepresentable)
+        );
+    }
+
+    #[test]
+    fn test_round_trips() {
+        let p = Point2D::new(Q::new(3, 2), Q::from(5));
+        assert_eq!(point_to_wkt(&p), "POINT (3/2 5)");
+        assert_eq!(point_from_wkt(&point_to_wkt(&p)), Ok(p));
+
+        let (a, b) = (Point2D::new(1, 2), Point2D::new(-3, 4));
+        assert_eq!(segment_to_wkt(&a, &b), "LINESTRING (1 2, -3 4)");
+        assert_eq!(segment_from_wkt("linestring(1 2,-3 4)"), Ok((a, b)));
+
+        let tri = Triangle2D::new(a, b, Point2D::new(0, 0));
+        assert_eq!(triangle_from_wkt(&triangle_to_wkt(&tri)), Ok(tri));
+
+        let square = vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.0, 0.0),
+            Point2D::new(1.0, 1.0),
+            Point2D::new(0.0, 1.0),
+        ];
+        let text = polygon_to_wkt(&square);
+        assert_eq!(text, "POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))");
+        assert_eq!(polygon_from_wkt(&text), Ok(square));
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert!(point_from_wkt::<i32>("POINT (1 2 3)").is_err());
+        assert!(point_from_wkt::<i32>("LINESTRING (1 2)").is_err());
+        assert!(point_from_wkt::<i32>("POINT 1 2").is_err());
+        assert!(triangle_from_wkt::<i32>("POLYGON ((0 0, 1 0, 0 1))").is_err());
+        assert!(triangle_from_wkt::<i32>("POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))").is_err());
+        assert!(polygon_from_wkt::<i32>("POLYGON ((0 0, 1 0, 0 0))").is_err());
+    }
+}